@@ -1,6 +1,6 @@
 use crate::property::HasDecimalDigits;
 use bigdecimal::BigDecimal;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 impl HasDecimalDigits for BigDecimal {
     fn integer_digits(&self) -> u64 {
@@ -9,7 +9,7 @@ impl HasDecimalDigits for BigDecimal {
         match 0.cmp(&exponent) {
             Ordering::Less => num_digits - exponent as u64,
             Ordering::Equal => num_digits,
-            Ordering::Greater => num_digits + exponent.abs() as u64,
+            Ordering::Greater => num_digits + exponent.unsigned_abs(),
         }
     }
 
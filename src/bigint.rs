@@ -0,0 +1,12 @@
+use crate::property::HasDecimalDigits;
+use num_bigint::BigInt;
+
+impl HasDecimalDigits for BigInt {
+    fn integer_digits(&self) -> u64 {
+        self.magnitude().to_str_radix(10).len() as u64
+    }
+
+    fn fraction_digits(&self) -> u64 {
+        0
+    }
+}
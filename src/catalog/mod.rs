@@ -0,0 +1,653 @@
+//! A catalog of error codes
+//!
+//! The error codes of this crate are defined as constants in the
+//! [`constraint`] module. This module collects them in an [`ErrorCatalog`]
+//! together with some metadata about each code: the name of the constraint
+//! that reports it, the kind of constraint violation, the values that are
+//! filled in and a short description.
+//!
+//! Applications register the error codes of their own constraints in the same
+//! catalog. Registering a code that is already known fails with a
+//! [`DuplicateErrorCode`] error, which reveals collisions of custom codes with
+//! the codes of this crate or of other custom constraints.
+//!
+//! The catalog can be exported as JSON or as a skeleton of a [_fluent_]
+//! resource file, which serves as the starting point for translating the
+//! error messages.
+//!
+//! # Examples
+//!
+//! ```
+//! use valid::catalog::{ErrorCatalog, ErrorCodeInfo, ViolationKind};
+//! use valid::constraint::INVALID_LENGTH_MAX;
+//!
+//! const INVALID_UNIQUE_USERNAME: &str = "invalid-unique-username";
+//!
+//! let mut catalog = ErrorCatalog::builtin();
+//!
+//! catalog
+//!     .register(ErrorCodeInfo {
+//!         code: INVALID_UNIQUE_USERNAME,
+//!         constraint: "IsUniqueUsername",
+//!         violation: ViolationKind::State,
+//!         fields: &[],
+//!         params: &["username"],
+//!         description: "the username is already taken",
+//!     })
+//!     .unwrap();
+//!
+//! let duplicate = catalog.register(ErrorCodeInfo {
+//!     code: INVALID_LENGTH_MAX,
+//!     constraint: "MyLength",
+//!     violation: ViolationKind::Field,
+//!     fields: &["field"],
+//!     params: &[],
+//!     description: "the value is too long",
+//! });
+//! assert!(duplicate.is_err());
+//!
+//! let ftl = catalog.to_fluent();
+//!
+//! assert!(ftl.contains("invalid-unique-username = the username is already taken\n"));
+//! ```
+//!
+//! [`constraint`]: ../constraint/index.html
+//! [`ErrorCatalog`]: struct.ErrorCatalog.html
+//! [`DuplicateErrorCode`]: struct.DuplicateErrorCode.html
+//! [_fluent_]: https://projectfluent.org/
+
+use crate::constraint::*;
+#[cfg(feature = "dynamic")]
+use crate::dynamic::{INVALID_ALLOWED_VALUES, INVALID_REQUIRED, INVALID_TYPE};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+use core::fmt::{Display, Write};
+#[cfg(feature = "serde1")]
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// The kind of constraint violation an error code is reported with.
+///
+/// The kind determines the names of the values that can be filled in for an
+/// error code:
+///
+/// | kind       | variant                         | values                                 |
+/// |------------|---------------------------------|----------------------------------------|
+/// | `Field`    | `ConstraintViolation::Field`    | `field`, `actual`, `expected`          |
+/// | `Relation` | `ConstraintViolation::Relation` | `field1`, `value1`, `field2`, `value2` |
+/// | `FieldSet` | `ConstraintViolation::FieldSet` | `fields`, `values`                     |
+/// | `State`    | `ConstraintViolation::State`    | the names of the parameters            |
+#[cfg_attr(feature = "serde1", derive(Serialize))]
+#[cfg_attr(feature = "serde1", serde(rename_all = "snake_case"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// reported as `ConstraintViolation::Field`
+    Field,
+    /// reported as `ConstraintViolation::Relation`
+    Relation,
+    /// reported as `ConstraintViolation::FieldSet`
+    FieldSet,
+    /// reported as `ConstraintViolation::State`
+    State,
+}
+
+impl Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::Field => write!(f, "field"),
+            ViolationKind::Relation => write!(f, "relation"),
+            ViolationKind::FieldSet => write!(f, "field_set"),
+            ViolationKind::State => write!(f, "state"),
+        }
+    }
+}
+
+/// Metadata about an error code.
+#[cfg_attr(feature = "serde1", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCodeInfo {
+    /// The error code
+    pub code: &'static str,
+    /// The name of the constraint that reports the error code
+    pub constraint: &'static str,
+    /// The kind of constraint violation the error code is reported with
+    pub violation: ViolationKind,
+    /// The values of the field(s) that are filled in, like `actual` or
+    /// `expected` (see [`ViolationKind`] for the possible names)
+    ///
+    /// [`ViolationKind`]: enum.ViolationKind.html
+    pub fields: &'static [&'static str],
+    /// The names of the parameters that are filled in
+    pub params: &'static [&'static str],
+    /// A short description of the error
+    pub description: &'static str,
+}
+
+impl ErrorCodeInfo {
+    /// Returns the names of all values that can be used as variables in a
+    /// message for this error code, which are the filled in fields followed
+    /// by the parameters.
+    pub fn variables(&self) -> impl Iterator<Item = &'static str> {
+        self.fields.iter().chain(self.params.iter()).copied()
+    }
+}
+
+/// The error returned when registering an error code that is already
+/// registered in an [`ErrorCatalog`].
+///
+/// [`ErrorCatalog`]: struct.ErrorCatalog.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateErrorCode {
+    /// The error code
+    pub code: &'static str,
+    /// The name of the constraint the error code is already registered for
+    pub registered: &'static str,
+    /// The name of the constraint of the rejected registration
+    pub rejected: &'static str,
+}
+
+impl Display for DuplicateErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error code `{}` of constraint `{}` is already registered for constraint `{}`",
+            self.code, self.rejected, self.registered
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DuplicateErrorCode {}
+
+/// A catalog of error codes and their metadata.
+///
+/// The entries are kept in the order they have been registered. See the
+/// [module level documentation] for an example.
+///
+/// [module level documentation]: index.html
+#[cfg_attr(feature = "serde1", derive(Serialize))]
+#[cfg_attr(feature = "serde1", serde(transparent))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorCatalog {
+    entries: Vec<ErrorCodeInfo>,
+}
+
+impl ErrorCatalog {
+    /// Constructs an empty catalog.
+    pub fn new() -> Self {
+        ErrorCatalog {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Constructs a catalog containing all error codes defined by this crate
+    /// including those of enabled optional crate features.
+    pub fn builtin() -> Self {
+        ErrorCatalog {
+            entries: builtin_entries(),
+        }
+    }
+
+    /// Registers the given error code.
+    ///
+    /// Returns an error if the code is already registered. The catalog
+    /// remains unchanged in this case.
+    pub fn register(&mut self, info: ErrorCodeInfo) -> Result<(), DuplicateErrorCode> {
+        match self.get(info.code) {
+            Some(registered) => Err(DuplicateErrorCode {
+                code: info.code,
+                registered: registered.constraint,
+                rejected: info.constraint,
+            }),
+            None => {
+                self.entries.push(info);
+                Ok(())
+            }
+        }
+    }
+
+    /// Registers all the given error codes.
+    ///
+    /// All duplicates are reported in the returned error. The codes that are
+    /// not duplicates are registered in any case.
+    pub fn register_all(
+        &mut self,
+        infos: impl IntoIterator<Item = ErrorCodeInfo>,
+    ) -> Result<(), Vec<DuplicateErrorCode>> {
+        let duplicates = infos
+            .into_iter()
+            .filter_map(|info| self.register(info).err())
+            .collect::<Vec<_>>();
+        if duplicates.is_empty() {
+            Ok(())
+        } else {
+            Err(duplicates)
+        }
+    }
+
+    /// Returns the metadata of the given error code if it is registered.
+    pub fn get(&self, code: &str) -> Option<&ErrorCodeInfo> {
+        self.entries.iter().find(|info| info.code == code)
+    }
+
+    /// Returns whether the given error code is registered.
+    pub fn contains(&self, code: &str) -> bool {
+        self.get(code).is_some()
+    }
+
+    /// Returns an iterator over the entries in the order of registration.
+    pub fn iter(&self) -> impl Iterator<Item = &ErrorCodeInfo> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of registered error codes.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no error code is registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Exports the catalog as a JSON array with one object per error code.
+    ///
+    /// ```
+    /// use valid::catalog::{ErrorCatalog, ErrorCodeInfo, ViolationKind};
+    ///
+    /// let mut catalog = ErrorCatalog::new();
+    /// catalog.register(ErrorCodeInfo {
+    ///     code: "invalid-non-zero",
+    ///     constraint: "NonZero",
+    ///     violation: ViolationKind::Field,
+    ///     fields: &["field", "actual"],
+    ///     params: &[],
+    ///     description: "the value is zero",
+    /// }).unwrap();
+    ///
+    /// assert_eq!(catalog.to_json(), r#"[
+    ///   {
+    ///     "code": "invalid-non-zero",
+    ///     "constraint": "NonZero",
+    ///     "violation": "field",
+    ///     "fields": ["field", "actual"],
+    ///     "params": [],
+    ///     "description": "the value is zero"
+    ///   }
+    /// ]
+    /// "#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (index, info) in self.entries.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("\n  {\n");
+            let _ = writeln!(json, "    \"code\": {},", json_string(info.code));
+            let _ = writeln!(
+                json,
+                "    \"constraint\": {},",
+                json_string(info.constraint)
+            );
+            let _ = writeln!(json, "    \"violation\": \"{}\",", info.violation);
+            let _ = writeln!(json, "    \"fields\": {},", json_array(info.fields));
+            let _ = writeln!(json, "    \"params\": {},", json_array(info.params));
+            let _ = writeln!(
+                json,
+                "    \"description\": {}",
+                json_string(info.description)
+            );
+            json.push_str("  }");
+        }
+        if !self.entries.is_empty() {
+            json.push('\n');
+        }
+        json.push_str("]\n");
+        json
+    }
+
+    /// Exports the catalog as the skeleton of a [_fluent_] resource file.
+    ///
+    /// There is one message per error code. The message identifier is the
+    /// error code and the description is used as preliminary message text.
+    /// A comment preceding each message names the constraint and lists the
+    /// variables available for the message.
+    ///
+    /// [_fluent_]: https://projectfluent.org/
+    pub fn to_fluent(&self) -> String {
+        let mut ftl = String::new();
+        for (index, info) in self.entries.iter().enumerate() {
+            if index > 0 {
+                ftl.push('\n');
+            }
+            let _ = writeln!(ftl, "# {}: {}", info.constraint, info.description);
+            let variables = info
+                .variables()
+                .map(|name| format!("${}", name))
+                .collect::<Vec<_>>();
+            if !variables.is_empty() {
+                let _ = writeln!(ftl, "# Variables: {}", variables.join(", "));
+            }
+            let _ = writeln!(ftl, "{} = {}", info.code, info.description);
+        }
+        ftl
+    }
+}
+
+impl<'a> IntoIterator for &'a ErrorCatalog {
+    type Item = &'a ErrorCodeInfo;
+    type IntoIter = core::slice::Iter<'a, ErrorCodeInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_array(values: &[&str]) -> String {
+    let elements = values
+        .iter()
+        .map(|value| json_string(value))
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(", "))
+}
+
+const FIELD: &[&str] = &["field"];
+const FIELD_ACTUAL: &[&str] = &["field", "actual"];
+const FIELD_ACTUAL_EXPECTED: &[&str] = &["field", "actual", "expected"];
+const RELATION: &[&str] = &["field1", "value1", "field2", "value2"];
+const FIELD_SET: &[&str] = &["fields", "values"];
+
+const fn field(
+    code: &'static str,
+    constraint: &'static str,
+    fields: &'static [&'static str],
+    description: &'static str,
+) -> ErrorCodeInfo {
+    ErrorCodeInfo {
+        code,
+        constraint,
+        violation: ViolationKind::Field,
+        fields,
+        params: &[],
+        description,
+    }
+}
+
+const fn relation(
+    code: &'static str,
+    constraint: &'static str,
+    fields: &'static [&'static str],
+    description: &'static str,
+) -> ErrorCodeInfo {
+    ErrorCodeInfo {
+        code,
+        constraint,
+        violation: ViolationKind::Relation,
+        fields,
+        params: &[],
+        description,
+    }
+}
+
+const fn field_set(
+    code: &'static str,
+    constraint: &'static str,
+    description: &'static str,
+) -> ErrorCodeInfo {
+    ErrorCodeInfo {
+        code,
+        constraint,
+        violation: ViolationKind::FieldSet,
+        fields: FIELD_SET,
+        params: &[],
+        description,
+    }
+}
+
+/// Lists the error codes of this crate. The tests check that each `INVALID_*`
+/// constant of the `constraint` and `dynamic` modules has an entry.
+fn builtin_entries() -> Vec<ErrorCodeInfo> {
+    #[allow(unused_mut)]
+    let mut entries = vec![
+        field(
+            INVALID_ASSERT_TRUE,
+            "AssertTrue",
+            FIELD_ACTUAL_EXPECTED,
+            "the value does not assert to true",
+        ),
+        field(
+            INVALID_ASSERT_FALSE,
+            "AssertFalse",
+            FIELD_ACTUAL_EXPECTED,
+            "the value does not assert to false",
+        ),
+        field(INVALID_NOT_EMPTY, "NotEmpty", FIELD, "the value is empty"),
+        field(
+            INVALID_LENGTH_EXACT,
+            "Length",
+            FIELD_ACTUAL_EXPECTED,
+            "the length is not exactly the specified value",
+        ),
+        field(
+            INVALID_LENGTH_MAX,
+            "Length",
+            FIELD_ACTUAL_EXPECTED,
+            "the length is not less or equal the specified maximum",
+        ),
+        field(
+            INVALID_LENGTH_MIN,
+            "Length",
+            FIELD_ACTUAL_EXPECTED,
+            "the length is not greater or equal the specified minimum",
+        ),
+        field(
+            INVALID_CHAR_COUNT_EXACT,
+            "CharCount",
+            FIELD_ACTUAL_EXPECTED,
+            "the number of characters is not exactly the specified value",
+        ),
+        field(
+            INVALID_CHAR_COUNT_MAX,
+            "CharCount",
+            FIELD_ACTUAL_EXPECTED,
+            "the number of characters is not less or equal the specified maximum",
+        ),
+        field(
+            INVALID_CHAR_COUNT_MIN,
+            "CharCount",
+            FIELD_ACTUAL_EXPECTED,
+            "the number of characters is not greater or equal the specified minimum",
+        ),
+        field(
+            INVALID_BOUND_EXACT,
+            "Bound",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is not exactly the specified value",
+        ),
+        field(
+            INVALID_BOUND_CLOSED_MAX,
+            "Bound",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is not less than or equal to the specified maximum",
+        ),
+        field(
+            INVALID_BOUND_CLOSED_MIN,
+            "Bound",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is not greater than or equal to the specified minimum",
+        ),
+        field(
+            INVALID_BOUND_OPEN_MAX,
+            "Bound",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is not less than the specified maximum",
+        ),
+        field(
+            INVALID_BOUND_OPEN_MIN,
+            "Bound",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is not greater than the specified minimum",
+        ),
+        field(
+            INVALID_NON_ZERO,
+            "NonZero",
+            FIELD_ACTUAL,
+            "the value is zero",
+        ),
+        field(
+            INVALID_DIGITS_INTEGER,
+            "Digits",
+            FIELD_ACTUAL_EXPECTED,
+            "the number of integer digits is not less than or equal to the specified maximum",
+        ),
+        field(
+            INVALID_DIGITS_FRACTION,
+            "Digits",
+            FIELD_ACTUAL_EXPECTED,
+            "the number of fraction digits is not less than or equal to the specified maximum",
+        ),
+        field(
+            INVALID_DIGITS_NUMBER,
+            "Digits",
+            FIELD,
+            "the value is not a well-formed decimal number",
+        ),
+        field(
+            INVALID_CONTAINS_ELEMENT,
+            "Contains",
+            FIELD_ACTUAL_EXPECTED,
+            "the value does not contain the specified member element",
+        ),
+        relation(
+            INVALID_MUST_MATCH,
+            "MustMatch",
+            RELATION,
+            "the two values do not match",
+        ),
+        relation(
+            INVALID_MUST_NOT_MATCH,
+            "MustNotMatch",
+            RELATION,
+            "the two values do match",
+        ),
+        relation(
+            INVALID_MUST_COMPARE_LESS_THAN,
+            "MustCompare",
+            RELATION,
+            "the first value is not less than the second value",
+        ),
+        relation(
+            INVALID_MUST_COMPARE_LESS_OR_EQUAL,
+            "MustCompare",
+            RELATION,
+            "the first value is not less than or equal to the second value",
+        ),
+        relation(
+            INVALID_MUST_COMPARE_GREATER_THAN,
+            "MustCompare",
+            RELATION,
+            "the first value is not greater than the second value",
+        ),
+        relation(
+            INVALID_MUST_COMPARE_GREATER_OR_EQUAL,
+            "MustCompare",
+            RELATION,
+            "the first value is not greater than or equal to the second value",
+        ),
+        relation(
+            INVALID_MUST_DEFINE_RANGE_INCLUSIVE,
+            "MustDefineRange",
+            RELATION,
+            "the first value is not less than or equal to the second value",
+        ),
+        relation(
+            INVALID_MUST_DEFINE_RANGE_EXCLUSIVE,
+            "MustDefineRange",
+            RELATION,
+            "the first value is not less than the second value",
+        ),
+        relation(
+            INVALID_REQUIRED_IF,
+            "RequiredIf",
+            &["field1", "value1", "field2"],
+            "the second value is missing while the first value is present",
+        ),
+        relation(
+            INVALID_EXCLUSIVE_OR,
+            "ExclusiveOr",
+            RELATION,
+            "not exactly one of the two values is present",
+        ),
+        field_set(
+            INVALID_AT_LEAST_ONE_OF,
+            "AtLeastOneOf",
+            "none of the values is present",
+        ),
+        field_set(
+            INVALID_AT_MOST_ONE_OF,
+            "AtMostOneOf",
+            "more than one of the values is present",
+        ),
+        field_set(
+            INVALID_ALL_OR_NONE,
+            "AllOrNone",
+            "some but not all of the values are present",
+        ),
+    ];
+    #[cfg(feature = "regex")]
+    entries.push(field(
+        INVALID_PATTERN,
+        "Pattern",
+        FIELD_ACTUAL_EXPECTED,
+        "the value does not match the specified pattern",
+    ));
+    #[cfg(feature = "dynamic")]
+    entries.extend(vec![
+        field(
+            INVALID_REQUIRED,
+            "required",
+            FIELD,
+            "the value of a required field is missing",
+        ),
+        field(
+            INVALID_ALLOWED_VALUES,
+            "allowed_values",
+            FIELD_ACTUAL,
+            "the value is not one of the allowed values",
+        ),
+        field(
+            INVALID_TYPE,
+            "RuleSet",
+            FIELD_ACTUAL_EXPECTED,
+            "the value is of a type the constraint can not be applied to",
+        ),
+    ]);
+    entries
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,326 @@
+use super::*;
+
+const INVALID_UNIQUE_USERNAME: &str = "invalid-unique-username";
+
+fn unique_username() -> ErrorCodeInfo {
+    ErrorCodeInfo {
+        code: INVALID_UNIQUE_USERNAME,
+        constraint: "IsUniqueUsername",
+        violation: ViolationKind::State,
+        fields: &[],
+        params: &["username"],
+        description: "the username is already taken",
+    }
+}
+
+mod builtin {
+    use super::*;
+
+    /// Returns the error codes of all `INVALID_*` constants declared in the
+    /// given source file.
+    fn declared_error_codes(source: &'static str) -> Vec<&'static str> {
+        source
+            .lines()
+            .filter(|line| line.trim_start().starts_with("pub const INVALID_"))
+            .filter_map(|line| line.split('"').nth(1))
+            .collect()
+    }
+
+    #[test]
+    fn builtin_catalog_contains_all_error_codes_of_the_constraint_module() {
+        let catalog = ErrorCatalog::builtin();
+        let mut codes = declared_error_codes(include_str!("../constraint/mod.rs"));
+        if cfg!(not(feature = "regex")) {
+            codes.retain(|code| *code != "invalid-pattern");
+        }
+        #[cfg(feature = "dynamic")]
+        codes.extend(declared_error_codes(include_str!("../dynamic/mod.rs")));
+
+        assert!(codes.contains(&INVALID_ASSERT_TRUE));
+        for code in &codes {
+            assert!(catalog.contains(code), "missing error code {}", code);
+        }
+        assert_eq!(catalog.len(), codes.len());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn builtin_catalog_contains_the_error_code_of_the_pattern_constraint() {
+        let catalog = ErrorCatalog::builtin();
+
+        assert_eq!(catalog.get(INVALID_PATTERN).unwrap().constraint, "Pattern");
+    }
+
+    #[cfg(feature = "dynamic")]
+    #[test]
+    fn builtin_catalog_contains_the_error_codes_of_the_dynamic_module() {
+        let catalog = ErrorCatalog::builtin();
+
+        assert!(catalog.contains(INVALID_REQUIRED));
+        assert!(catalog.contains(INVALID_ALLOWED_VALUES));
+        assert!(catalog.contains(INVALID_TYPE));
+    }
+
+    #[test]
+    fn builtin_catalog_has_no_duplicate_error_codes() {
+        let mut catalog = ErrorCatalog::new();
+
+        let result = catalog.register_all(ErrorCatalog::builtin().iter().copied());
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn builtin_error_codes_follow_the_naming_convention() {
+        for info in &ErrorCatalog::builtin() {
+            assert!(info.code.starts_with("invalid-"), "{}", info.code);
+            assert!(
+                info.code
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c == '-'),
+                "{}",
+                info.code
+            );
+        }
+    }
+
+    #[test]
+    fn metadata_of_a_field_error_code() {
+        let catalog = ErrorCatalog::builtin();
+
+        assert_eq!(
+            catalog.get(INVALID_LENGTH_MAX),
+            Some(&ErrorCodeInfo {
+                code: INVALID_LENGTH_MAX,
+                constraint: "Length",
+                violation: ViolationKind::Field,
+                fields: &["field", "actual", "expected"],
+                params: &[],
+                description: "the length is not less or equal the specified maximum",
+            })
+        );
+    }
+
+    #[test]
+    fn metadata_of_a_relation_error_code() {
+        let catalog = ErrorCatalog::builtin();
+
+        let info = catalog.get(INVALID_REQUIRED_IF).unwrap();
+
+        assert_eq!(info.violation, ViolationKind::Relation);
+        assert_eq!(info.fields, &["field1", "value1", "field2"]);
+    }
+
+    #[test]
+    fn metadata_of_a_field_set_error_code() {
+        let catalog = ErrorCatalog::builtin();
+
+        let info = catalog.get(INVALID_AT_MOST_ONE_OF).unwrap();
+
+        assert_eq!(info.violation, ViolationKind::FieldSet);
+        assert_eq!(info.fields, &["fields", "values"]);
+    }
+}
+
+mod register {
+    use super::*;
+
+    #[test]
+    fn register_a_custom_error_code() {
+        let mut catalog = ErrorCatalog::builtin();
+        let builtin_len = catalog.len();
+
+        let result = catalog.register(unique_username());
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(catalog.len(), builtin_len + 1);
+        assert_eq!(
+            catalog.get(INVALID_UNIQUE_USERNAME),
+            Some(&unique_username())
+        );
+        assert_eq!(catalog.iter().last(), Some(&unique_username()));
+    }
+
+    #[test]
+    fn register_an_error_code_that_collides_with_a_builtin_code() {
+        let mut catalog = ErrorCatalog::builtin();
+        let builtin_len = catalog.len();
+        let custom = ErrorCodeInfo {
+            code: INVALID_NOT_EMPTY,
+            constraint: "NotBlank",
+            ..unique_username()
+        };
+
+        let result = catalog.register(custom);
+
+        assert_eq!(
+            result,
+            Err(DuplicateErrorCode {
+                code: INVALID_NOT_EMPTY,
+                registered: "NotEmpty",
+                rejected: "NotBlank",
+            })
+        );
+        assert_eq!(catalog.len(), builtin_len);
+        assert_eq!(
+            catalog.get(INVALID_NOT_EMPTY).unwrap().constraint,
+            "NotEmpty"
+        );
+    }
+
+    #[test]
+    fn register_all_reports_all_duplicates() {
+        let mut catalog = ErrorCatalog::new();
+        let other = ErrorCodeInfo {
+            code: "invalid-unique-email",
+            ..unique_username()
+        };
+
+        let result = catalog.register_all(vec![unique_username(), other, unique_username(), other]);
+
+        assert_eq!(
+            result,
+            Err(vec![
+                DuplicateErrorCode {
+                    code: INVALID_UNIQUE_USERNAME,
+                    registered: "IsUniqueUsername",
+                    rejected: "IsUniqueUsername",
+                },
+                DuplicateErrorCode {
+                    code: "invalid-unique-email",
+                    registered: "IsUniqueUsername",
+                    rejected: "IsUniqueUsername",
+                },
+            ])
+        );
+        assert_eq!(catalog.len(), 2);
+    }
+
+    #[test]
+    fn display_format_a_duplicate_error_code() {
+        let error = DuplicateErrorCode {
+            code: INVALID_NOT_EMPTY,
+            registered: "NotEmpty",
+            rejected: "NotBlank",
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "error code `invalid-not-empty` of constraint `NotBlank` is already registered for constraint `NotEmpty`"
+        );
+    }
+}
+
+mod export {
+    use super::*;
+
+    #[test]
+    fn export_an_empty_catalog_as_json() {
+        assert_eq!(ErrorCatalog::new().to_json(), "[]\n");
+    }
+
+    #[test]
+    fn export_a_catalog_as_json() {
+        let mut catalog = ErrorCatalog::new();
+        catalog.register(unique_username()).unwrap();
+        catalog
+            .register(*ErrorCatalog::builtin().get(INVALID_MUST_MATCH).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            catalog.to_json(),
+            r#"[
+  {
+    "code": "invalid-unique-username",
+    "constraint": "IsUniqueUsername",
+    "violation": "state",
+    "fields": [],
+    "params": ["username"],
+    "description": "the username is already taken"
+  },
+  {
+    "code": "invalid-must-match",
+    "constraint": "MustMatch",
+    "violation": "relation",
+    "fields": ["field1", "value1", "field2", "value2"],
+    "params": [],
+    "description": "the two values do not match"
+  }
+]
+"#
+        );
+    }
+
+    #[test]
+    fn special_characters_are_escaped_in_json() {
+        let mut catalog = ErrorCatalog::new();
+        catalog
+            .register(ErrorCodeInfo {
+                description: "the \"name\" is\tinvalid\\\u{1}",
+                ..unique_username()
+            })
+            .unwrap();
+
+        let json = catalog.to_json();
+
+        assert!(json.contains(r#""description": "the \"name\" is\tinvalid\\\u0001""#));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["description"], "the \"name\" is\tinvalid\\\u{1}");
+    }
+
+    #[test]
+    fn builtin_catalog_exported_as_json_is_well_formed() {
+        let json = ErrorCatalog::builtin().to_json();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            parsed.as_array().unwrap().len(),
+            ErrorCatalog::builtin().len()
+        );
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn serialized_catalog_equals_json_export() {
+        let catalog = ErrorCatalog::builtin();
+
+        let serialized = serde_json::to_value(&catalog).unwrap();
+        let exported: serde_json::Value = serde_json::from_str(&catalog.to_json()).unwrap();
+
+        assert_eq!(serialized, exported);
+    }
+
+    #[test]
+    fn export_a_catalog_as_fluent_skeleton() {
+        let mut catalog = ErrorCatalog::new();
+        catalog.register(unique_username()).unwrap();
+        catalog
+            .register(*ErrorCatalog::builtin().get(INVALID_NOT_EMPTY).unwrap())
+            .unwrap();
+        catalog
+            .register(ErrorCodeInfo {
+                code: "invalid-maintenance",
+                constraint: "NotInMaintenance",
+                violation: ViolationKind::State,
+                fields: &[],
+                params: &[],
+                description: "the service is in maintenance",
+            })
+            .unwrap();
+
+        assert_eq!(
+            catalog.to_fluent(),
+            "# IsUniqueUsername: the username is already taken\n\
+             # Variables: $username\n\
+             invalid-unique-username = the username is already taken\n\
+             \n\
+             # NotEmpty: the value is empty\n\
+             # Variables: $field\n\
+             invalid-not-empty = the value is empty\n\
+             \n\
+             # NotInMaintenance: the service is in maintenance\n\
+             invalid-maintenance = the service is in maintenance\n"
+        );
+    }
+}
@@ -0,0 +1,492 @@
+//! Constraints with parameters given as const generics
+//!
+//! The constraints in this module are zero-sized types that carry their
+//! parameters in the type, like `MaxLen<20>`. This way a constraint with
+//! parameters can be used as type parameter of [`Validated`] and its
+//! parameters are known at compile time. The validation is delegated to the
+//! equivalent constraint with runtime parameters. Thus the same error codes
+//! are reported.
+//!
+//! [`Validated`]: ../struct.Validated.html
+
+use super::{Bound, CharCount, Digits, Length, INVALID_BOUND_CLOSED_MAX, INVALID_BOUND_CLOSED_MIN};
+use crate::property::{HasCharCount, HasDecimalDigits, HasLength};
+use crate::{
+    invalid_value, ConstraintViolation, FieldName, Validate, ValidateRef, Validation, Value,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+/// The length of a value must be less than or equal to `N`.
+///
+/// It is the const generic equivalent of `Length::Max(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// all types `T` that implement the [`HasLength`] property trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasLength`]: ../property/trait.HasLength.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MaxLen<const N: usize>;
+
+impl<const N: usize> From<MaxLen<N>> for Length {
+    fn from(_: MaxLen<N>) -> Self {
+        Length::Max(N)
+    }
+}
+
+impl<const N: usize, T> ValidateRef<MaxLen<N>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MaxLen<N>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &Length::from(*constraint))
+    }
+}
+
+impl<const N: usize, T> Validate<MaxLen<N>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MaxLen<N>,
+    ) -> Validation<MaxLen<N>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The length of a value must be between `MIN` and `MAX` (inclusive).
+///
+/// It is the const generic equivalent of `Length::MinMax(MIN, MAX)`. The
+/// validation function can be applied in the [`FieldName`] context. It is
+/// implemented for all types `T` that implement the [`HasLength`] property
+/// trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasLength`]: ../property/trait.HasLength.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinMaxLen<const MIN: usize, const MAX: usize>;
+
+impl<const MIN: usize, const MAX: usize> From<MinMaxLen<MIN, MAX>> for Length {
+    fn from(_: MinMaxLen<MIN, MAX>) -> Self {
+        Length::MinMax(MIN, MAX)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, T> ValidateRef<MinMaxLen<MIN, MAX>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MinMaxLen<MIN, MAX>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &Length::from(*constraint))
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, T> Validate<MinMaxLen<MIN, MAX>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MinMaxLen<MIN, MAX>,
+    ) -> Validation<MinMaxLen<MIN, MAX>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The length of a value must be greater than or equal to `N`.
+///
+/// It is the const generic equivalent of `Length::Min(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// all types `T` that implement the [`HasLength`] property trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasLength`]: ../property/trait.HasLength.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinLen<const N: usize>;
+
+impl<const N: usize> From<MinLen<N>> for Length {
+    fn from(_: MinLen<N>) -> Self {
+        Length::Min(N)
+    }
+}
+
+impl<const N: usize, T> ValidateRef<MinLen<N>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MinLen<N>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &Length::from(*constraint))
+    }
+}
+
+impl<const N: usize, T> Validate<MinLen<N>, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MinLen<N>,
+    ) -> Validation<MinLen<N>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The number of characters must be less than or equal to `N`.
+///
+/// It is the const generic equivalent of `CharCount::Max(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// all types `T` that implement the [`HasCharCount`] property trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasCharCount`]: ../property/trait.HasCharCount.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MaxChars<const N: usize>;
+
+impl<const N: usize> From<MaxChars<N>> for CharCount {
+    fn from(_: MaxChars<N>) -> Self {
+        CharCount::Max(N)
+    }
+}
+
+impl<const N: usize, T> ValidateRef<MaxChars<N>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MaxChars<N>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &CharCount::from(*constraint))
+    }
+}
+
+impl<const N: usize, T> Validate<MaxChars<N>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MaxChars<N>,
+    ) -> Validation<MaxChars<N>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The number of characters must be greater than or equal to `N`.
+///
+/// It is the const generic equivalent of `CharCount::Min(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// all types `T` that implement the [`HasCharCount`] property trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasCharCount`]: ../property/trait.HasCharCount.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinChars<const N: usize>;
+
+impl<const N: usize> From<MinChars<N>> for CharCount {
+    fn from(_: MinChars<N>) -> Self {
+        CharCount::Min(N)
+    }
+}
+
+impl<const N: usize, T> ValidateRef<MinChars<N>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MinChars<N>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &CharCount::from(*constraint))
+    }
+}
+
+impl<const N: usize, T> Validate<MinChars<N>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MinChars<N>,
+    ) -> Validation<MinChars<N>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The number of characters must be between `MIN` and `MAX` (inclusive).
+///
+/// It is the const generic equivalent of `CharCount::MinMax(MIN, MAX)`. The
+/// validation function can be applied in the [`FieldName`] context. It is
+/// implemented for all types `T` that implement the [`HasCharCount`] property
+/// trait.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasCharCount`]: ../property/trait.HasCharCount.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinMaxChars<const MIN: usize, const MAX: usize>;
+
+impl<const MIN: usize, const MAX: usize> From<MinMaxChars<MIN, MAX>> for CharCount {
+    fn from(_: MinMaxChars<MIN, MAX>) -> Self {
+        CharCount::MinMax(MIN, MAX)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, T> ValidateRef<MinMaxChars<MIN, MAX>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MinMaxChars<MIN, MAX>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &CharCount::from(*constraint))
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, T> Validate<MinMaxChars<MIN, MAX>, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MinMaxChars<MIN, MAX>,
+    ) -> Validation<MinMaxChars<MIN, MAX>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The value must be between `MIN` (inclusive) and `MAX` (inclusive).
+///
+/// It is the const generic equivalent of `Bound::ClosedRange(MIN, MAX)`. The
+/// validation function can be applied in the [`FieldName`] context. It is
+/// implemented for the primitive integer types up to 64 bits and for `f32`
+/// and `f64`.
+///
+/// The bounds are given as `i64`. Values are compared with the bounds as
+/// declared without loss of precision. A range that can not be satisfied by
+/// any value of the type, like `ClosedRange<300, 400>` for `u8`, is reported
+/// as violation for every value. The bounds are reported as value of the
+/// validated type if they are represented exactly by that type and as `Long`
+/// otherwise.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClosedRange<const MIN: i64, const MAX: i64>;
+
+impl<const MIN: i64, const MAX: i64> From<ClosedRange<MIN, MAX>> for Bound<i64> {
+    fn from(_: ClosedRange<MIN, MAX>) -> Self {
+        Bound::ClosedRange(MIN, MAX)
+    }
+}
+
+/// The value must be greater than or equal to `N`.
+///
+/// It is the const generic equivalent of `Bound::Min(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// the same types as [`ClosedRange`] and compares the values the same way.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`ClosedRange`]: struct.ClosedRange.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Min<const N: i64>;
+
+impl<const N: i64> From<Min<N>> for Bound<i64> {
+    fn from(_: Min<N>) -> Self {
+        Bound::Min(N)
+    }
+}
+
+/// The value must be less than or equal to `N`.
+///
+/// It is the const generic equivalent of `Bound::Max(N)`. The validation
+/// function can be applied in the [`FieldName`] context. It is implemented for
+/// the same types as [`ClosedRange`] and compares the values the same way.
+///
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`ClosedRange`]: struct.ClosedRange.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Max<const N: i64>;
+
+impl<const N: i64> From<Max<N>> for Bound<i64> {
+    fn from(_: Max<N>) -> Self {
+        Bound::Max(N)
+    }
+}
+
+/// Validates a number against the declared minimum and maximum of a const
+/// generic bound, where `None` means unbounded.
+fn validate_bounds<T>(
+    value: &T,
+    name: impl Into<FieldName>,
+    (min, max): (Option<i64>, Option<i64>),
+    compare: impl Fn(&T, i64) -> Option<Ordering>,
+    bound_value: impl Fn(i64) -> Value,
+) -> Vec<ConstraintViolation>
+where
+    T: Clone + Into<Value>,
+{
+    let (code, bound) = match (min, max) {
+        (Some(min), _) if compare(value, min) == Some(Ordering::Less) => {
+            (INVALID_BOUND_CLOSED_MIN, min)
+        }
+        (_, Some(max)) if compare(value, max) == Some(Ordering::Greater) => {
+            (INVALID_BOUND_CLOSED_MAX, max)
+        }
+        _ => return vec![],
+    };
+    vec![invalid_value(code, name, value.clone(), bound_value(bound))]
+}
+
+macro_rules! impl_bound_constraint {
+    ( $constraint:ident $params:tt => $limits:expr ) => {
+        impl_bound_constraint!(
+            @integer $constraint $params => $limits;
+            i8, i16, i32, i64, u8, u16, u32, u64
+        );
+        impl_bound_constraint!(@float $constraint $params => $limits; f32, f64);
+        impl_bound_constraint!(@validate $constraint $params);
+    };
+    (@integer $constraint:ident $params:tt => $limits:expr; $( $type:ty ),+ ) => {
+        $(
+            impl_bound_constraint!(
+                @validate_ref $constraint $params => $limits;
+                $type;
+                |value: &$type, bound| Some(i128::from(*value).cmp(&i128::from(bound)));
+                |bound| <$type>::try_from(bound).map_or(Value::Long(bound), Value::from)
+            );
+        )+
+    };
+    (@float $constraint:ident $params:tt => $limits:expr; $( $type:ty ),+ ) => {
+        $(
+            impl_bound_constraint!(
+                @validate_ref $constraint $params => $limits;
+                $type;
+                |value: &$type, bound| Value::from(*value).numeric_cmp(&Value::Long(bound));
+                |bound| {
+                    let float = bound as $type;
+                    if float as i128 == i128::from(bound) {
+                        Value::from(float)
+                    } else {
+                        Value::Long(bound)
+                    }
+                }
+            );
+        )+
+    };
+    (
+        @validate_ref $constraint:ident [ $( $param:ident ),+ ] => $limits:expr;
+        $type:ty; $compare:expr; $bound_value:expr
+    ) => {
+        impl<$( const $param: i64 ),+> ValidateRef<$constraint<$( $param ),+>, FieldName>
+            for $type
+        {
+            fn validate_ref(
+                &self,
+                name: impl Into<FieldName>,
+                _constraint: &$constraint<$( $param ),+>,
+            ) -> Vec<ConstraintViolation> {
+                validate_bounds(self, name, $limits, $compare, $bound_value)
+            }
+        }
+    };
+    (@validate $constraint:ident [ $( $param:ident ),+ ] ) => {
+        impl<$( const $param: i64, )+ T> Validate<$constraint<$( $param ),+>, FieldName> for T
+        where
+            T: ValidateRef<$constraint<$( $param ),+>, FieldName>,
+        {
+            fn validate(
+                self,
+                name: impl Into<FieldName>,
+                constraint: &$constraint<$( $param ),+>,
+            ) -> Validation<$constraint<$( $param ),+>, Self> {
+                let violations = self.validate_ref(name, constraint);
+                Validation::checked(self, violations)
+            }
+        }
+    };
+}
+
+impl_bound_constraint!(ClosedRange [MIN, MAX] => (Some(MIN), Some(MAX)));
+impl_bound_constraint!(Min [N] => (Some(N), None));
+impl_bound_constraint!(Max [N] => (None, Some(N)));
+
+/// Maximum number of `INTEGER` digits and `FRACTION` digits.
+///
+/// It is the const generic equivalent of the [`Digits`] constraint with the
+/// same limits. The validation function can be applied in the [`FieldName`]
+/// context. It is implemented for all types `T` that implement the
+/// [`HasDecimalDigits`] property trait.
+///
+/// [`Digits`]: struct.Digits.html
+/// [`FieldName`]: ../core/struct.FieldName.html
+/// [`HasDecimalDigits`]: ../property/trait.HasDecimalDigits.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MaxDigits<const INTEGER: u64, const FRACTION: u64>;
+
+impl<const INTEGER: u64, const FRACTION: u64> From<MaxDigits<INTEGER, FRACTION>> for Digits {
+    fn from(_: MaxDigits<INTEGER, FRACTION>) -> Self {
+        Digits {
+            integer: INTEGER,
+            fraction: FRACTION,
+        }
+    }
+}
+
+impl<const INTEGER: u64, const FRACTION: u64, T>
+    ValidateRef<MaxDigits<INTEGER, FRACTION>, FieldName> for T
+where
+    T: HasDecimalDigits,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &MaxDigits<INTEGER, FRACTION>,
+    ) -> Vec<ConstraintViolation> {
+        self.validate_ref(name, &Digits::from(*constraint))
+    }
+}
+
+impl<const INTEGER: u64, const FRACTION: u64, T> Validate<MaxDigits<INTEGER, FRACTION>, FieldName>
+    for T
+where
+    T: HasDecimalDigits,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &MaxDigits<INTEGER, FRACTION>,
+    ) -> Validation<MaxDigits<INTEGER, FRACTION>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
@@ -14,17 +14,28 @@
 //! terms. Thus the codes are compatible with the convention used in the
 //! [_fluent_] project.
 //!
+//! All constraints implement the [`Validate`] trait as well as the
+//! [`ValidateRef`] trait for validating a value by reference. As the
+//! constraint violations hold the actual value, the implementations of
+//! [`ValidateRef`] for `NonZero`, `Contains` and the constraints on related
+//! fields additionally require the validated type to implement `Clone`.
+//!
 //! [_fluent_]: https://projectfluent.org/
+//! [`Validate`]: ../trait.Validate.html
+//! [`ValidateRef`]: ../trait.ValidateRef.html
 
 use crate::property::{
     HasCharCount, HasCheckedValue, HasDecimalDigits, HasEmptyValue, HasLength, HasMember,
-    HasZeroValue,
+    HasOptionalValues, HasZeroValue,
 };
 use crate::{
-    invalid_optional_value, invalid_relation, invalid_value, FieldName, RelatedFields, Validate,
-    Validation, Value,
+    invalid_field_set, invalid_optional_relation, invalid_optional_value, invalid_relation,
+    invalid_value, ConstraintViolation, FieldName, RelatedFieldSet, RelatedFields, Validate,
+    ValidateRef, Validation, Value,
 };
-use std::convert::TryFrom;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 /// Error code: the value does not assert to true (`AssertTrue` constraint)
 pub const INVALID_ASSERT_TRUE: &str = "invalid-assert-true";
@@ -64,19 +75,21 @@ pub const INVALID_CHAR_COUNT_MIN: &str = "invalid-char-count-min";
 pub const INVALID_BOUND_EXACT: &str = "invalid-bound-exact";
 
 /// Error code: the value is not less than or equal to the specified maximum
-/// (`Bound::ClosedRange` or `Bound::OpenClosedRange` constraint)
+/// (`Bound::ClosedRange`, `Bound::OpenClosedRange` or `Bound::Max` constraint)
 pub const INVALID_BOUND_CLOSED_MAX: &str = "invalid-bound-closed-max";
 
 /// Error code: the value is not greater than or equal to the specified minimum
-/// (`Bound::ClosedRange` or `Bound::ClosedOpenRange` constraint)
+/// (`Bound::ClosedRange`, `Bound::ClosedOpenRange` or `Bound::Min` constraint)
 pub const INVALID_BOUND_CLOSED_MIN: &str = "invalid-bound-closed-min";
 
 /// Error code: the value is not less than the specified maximum
-/// (`Bound::OpenRange` or `Bound::ClosedOpenRange` constraint)
+/// (`Bound::OpenRange`, `Bound::ClosedOpenRange` or `Bound::LessThan`
+/// constraint)
 pub const INVALID_BOUND_OPEN_MAX: &str = "invalid-bound-open-max";
 
 /// Error code: the value is not greater than the specified minimum
-/// (`Bound::OpenRange` or `Bound::OpenClosedRange` constraint)
+/// (`Bound::OpenRange`, `Bound::OpenClosedRange` or `Bound::GreaterThan`
+/// constraint)
 pub const INVALID_BOUND_OPEN_MIN: &str = "invalid-bound-open-min";
 
 /// Error code: the value is zero (`NonZero` constraint)
@@ -90,6 +103,10 @@ pub const INVALID_DIGITS_INTEGER: &str = "invalid-digits-integer";
 /// specified maximum (`Digits::fraction` constraint)
 pub const INVALID_DIGITS_FRACTION: &str = "invalid-digits-fraction";
 
+/// Error code: the value is not a well-formed decimal number
+/// (`Digits` constraint)
+pub const INVALID_DIGITS_NUMBER: &str = "invalid-digits-number";
+
 /// Error code: the value does not contain the specified member element
 /// (`Contains` constraint)
 pub const INVALID_CONTAINS_ELEMENT: &str = "invalid-contains-element";
@@ -97,6 +114,44 @@ pub const INVALID_CONTAINS_ELEMENT: &str = "invalid-contains-element";
 /// Error code: the two values do not match (`MustMatch` constraint)
 pub const INVALID_MUST_MATCH: &str = "invalid-must-match";
 
+/// Error code: the two values do match (`MustNotMatch` constraint)
+pub const INVALID_MUST_NOT_MATCH: &str = "invalid-must-not-match";
+
+/// Error code: the first value is not less than the second value
+/// (`MustCompare::LessThan` constraint)
+pub const INVALID_MUST_COMPARE_LESS_THAN: &str = "invalid-must-compare-less-than";
+
+/// Error code: the first value is not less than or equal to the second value
+/// (`MustCompare::LessOrEqual` constraint)
+pub const INVALID_MUST_COMPARE_LESS_OR_EQUAL: &str = "invalid-must-compare-less-or-equal";
+
+/// Error code: the first value is not greater than the second value
+/// (`MustCompare::GreaterThan` constraint)
+pub const INVALID_MUST_COMPARE_GREATER_THAN: &str = "invalid-must-compare-greater-than";
+
+/// Error code: the first value is not greater than or equal to the second
+/// value (`MustCompare::GreaterOrEqual` constraint)
+pub const INVALID_MUST_COMPARE_GREATER_OR_EQUAL: &str = "invalid-must-compare-greater-or-equal";
+
+/// Error code: the second value is missing while the first value is present
+/// (`RequiredIf` constraint)
+pub const INVALID_REQUIRED_IF: &str = "invalid-required-if";
+
+/// Error code: not exactly one of the two values is present
+/// (`ExclusiveOr` constraint)
+pub const INVALID_EXCLUSIVE_OR: &str = "invalid-exclusive-or";
+
+/// Error code: none of the values is present (`AtLeastOneOf` constraint)
+pub const INVALID_AT_LEAST_ONE_OF: &str = "invalid-at-least-one-of";
+
+/// Error code: more than one of the values is present (`AtMostOneOf`
+/// constraint)
+pub const INVALID_AT_MOST_ONE_OF: &str = "invalid-at-most-one-of";
+
+/// Error code: some but not all of the values are present (`AllOrNone`
+/// constraint)
+pub const INVALID_ALL_OR_NONE: &str = "invalid-all-or-none";
+
 /// Error code: the first value is not less than or equal to the second value
 /// (`MustDefineRange::Inclusive` constraint)
 pub const INVALID_MUST_DEFINE_RANGE_INCLUSIVE: &str = "invalid-must-define-range-inclusive";
@@ -116,23 +171,37 @@ pub const INVALID_MUST_DEFINE_RANGE_EXCLUSIVE: &str = "invalid-must-define-range
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AssertTrue;
 
-impl<T> Validate<AssertTrue, FieldName> for T
+impl<T> ValidateRef<AssertTrue, FieldName> for T
 where
     T: HasCheckedValue,
 {
-    fn validate(
-        self,
+    fn validate_ref(
+        &self,
         name: impl Into<FieldName>,
         _constraint: &AssertTrue,
-    ) -> Validation<AssertTrue, Self> {
+    ) -> Vec<ConstraintViolation> {
         if self.is_checked_value() {
-            Validation::success(self)
+            vec![]
         } else {
-            Validation::failure(vec![invalid_value(INVALID_ASSERT_TRUE, name, false, true)])
+            vec![invalid_value(INVALID_ASSERT_TRUE, name, false, true)]
         }
     }
 }
 
+impl<T> Validate<AssertTrue, FieldName> for T
+where
+    T: HasCheckedValue,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &AssertTrue,
+    ) -> Validation<AssertTrue, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The value must be false.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -144,23 +213,37 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AssertFalse;
 
-impl<T> Validate<AssertFalse, FieldName> for T
+impl<T> ValidateRef<AssertFalse, FieldName> for T
 where
     T: HasCheckedValue,
 {
-    fn validate(
-        self,
+    fn validate_ref(
+        &self,
         name: impl Into<FieldName>,
         _constraint: &AssertFalse,
-    ) -> Validation<AssertFalse, Self> {
+    ) -> Vec<ConstraintViolation> {
         if self.is_checked_value() {
-            Validation::failure(vec![invalid_value(INVALID_ASSERT_FALSE, name, true, false)])
+            vec![invalid_value(INVALID_ASSERT_FALSE, name, true, false)]
         } else {
-            Validation::success(self)
+            vec![]
         }
     }
 }
 
+impl<T> Validate<AssertFalse, FieldName> for T
+where
+    T: HasCheckedValue,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &AssertFalse,
+    ) -> Validation<AssertFalse, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The value must not be empty.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -172,28 +255,37 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NotEmpty;
 
-impl<T> Validate<NotEmpty, FieldName> for T
+impl<T> ValidateRef<NotEmpty, FieldName> for T
 where
     T: HasEmptyValue,
 {
-    fn validate(
-        self,
+    fn validate_ref(
+        &self,
         name: impl Into<FieldName>,
         _constraint: &NotEmpty,
-    ) -> Validation<NotEmpty, Self> {
+    ) -> Vec<ConstraintViolation> {
         if self.is_empty_value() {
-            Validation::failure(vec![invalid_optional_value(
-                INVALID_NOT_EMPTY,
-                name,
-                None,
-                None,
-            )])
+            vec![invalid_optional_value(INVALID_NOT_EMPTY, name, None, None)]
         } else {
-            Validation::success(self)
+            vec![]
         }
     }
 }
 
+impl<T> Validate<NotEmpty, FieldName> for T
+where
+    T: HasEmptyValue,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &NotEmpty,
+    ) -> Validation<NotEmpty, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The length of a value must be within some bounds.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -217,11 +309,15 @@ pub enum Length {
     Exact(usize),
 }
 
-impl<T> Validate<Length, FieldName> for T
+impl<T> ValidateRef<Length, FieldName> for T
 where
     T: HasLength,
 {
-    fn validate(self, name: impl Into<FieldName>, constraint: &Length) -> Validation<Length, Self> {
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &Length,
+    ) -> Vec<ConstraintViolation> {
         let length = self.length();
         if let Some((code, expected)) = match *constraint {
             Length::Max(max) => {
@@ -257,13 +353,23 @@ where
         } {
             let actual = Value::try_from(length).ok();
             let expected = Value::try_from(expected).ok();
-            Validation::failure(vec![invalid_optional_value(code, name, actual, expected)])
+            vec![invalid_optional_value(code, name, actual, expected)]
         } else {
-            Validation::success(self)
+            vec![]
         }
     }
 }
 
+impl<T> Validate<Length, FieldName> for T
+where
+    T: HasLength,
+{
+    fn validate(self, name: impl Into<FieldName>, constraint: &Length) -> Validation<Length, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The number of characters must be within some bounds.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -287,15 +393,15 @@ pub enum CharCount {
     Exact(usize),
 }
 
-impl<T> Validate<CharCount, FieldName> for T
+impl<T> ValidateRef<CharCount, FieldName> for T
 where
     T: HasCharCount,
 {
-    fn validate(
-        self,
+    fn validate_ref(
+        &self,
         name: impl Into<FieldName>,
         constraint: &CharCount,
-    ) -> Validation<CharCount, Self> {
+    ) -> Vec<ConstraintViolation> {
         let char_count = self.char_count();
         if let Some((code, expected)) = match *constraint {
             CharCount::Max(max) => {
@@ -331,13 +437,27 @@ where
         } {
             let actual = Value::try_from(char_count).ok();
             let expected = Value::try_from(expected).ok();
-            Validation::failure(vec![invalid_optional_value(code, name, actual, expected)])
+            vec![invalid_optional_value(code, name, actual, expected)]
         } else {
-            Validation::success(self)
+            vec![]
         }
     }
 }
 
+impl<T> Validate<CharCount, FieldName> for T
+where
+    T: HasCharCount,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &CharCount,
+    ) -> Validation<CharCount, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The value must be within some bounds.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -361,69 +481,119 @@ pub enum Bound<T> {
     OpenRange(T, T),
     /// The value must have the specified value
     Exact(T),
+    /// The value must be greater than or equal to the specified minimum
+    Min(T),
+    /// The value must be less than or equal to the specified maximum
+    Max(T),
+    /// The value must be greater than the specified minimum
+    GreaterThan(T),
+    /// The value must be less than the specified maximum
+    LessThan(T),
 }
 
-impl<T> Validate<Bound<T>, FieldName> for T
+impl<T> ValidateRef<Bound<T>, FieldName> for T
 where
     T: PartialOrd + Clone + Into<Value>,
 {
-    fn validate(
-        self,
+    fn validate_ref(
+        &self,
         name: impl Into<FieldName>,
         constraint: &Bound<T>,
-    ) -> Validation<Bound<T>, Self> {
+    ) -> Vec<ConstraintViolation> {
         if let Some((code, expected)) = match constraint {
             Bound::ClosedRange(min, max) => {
-                if self < *min {
+                if *self < *min {
                     Some((INVALID_BOUND_CLOSED_MIN, min.clone()))
-                } else if self > *max {
+                } else if *self > *max {
                     Some((INVALID_BOUND_CLOSED_MAX, max.clone()))
                 } else {
                     None
                 }
             }
             Bound::ClosedOpenRange(min, max) => {
-                if self < *min {
+                if *self < *min {
                     Some((INVALID_BOUND_CLOSED_MIN, min.clone()))
-                } else if self >= *max {
+                } else if *self >= *max {
                     Some((INVALID_BOUND_OPEN_MAX, max.clone()))
                 } else {
                     None
                 }
             }
             Bound::OpenClosedRange(min, max) => {
-                if self <= *min {
+                if *self <= *min {
                     Some((INVALID_BOUND_OPEN_MIN, min.clone()))
-                } else if self > *max {
+                } else if *self > *max {
                     Some((INVALID_BOUND_CLOSED_MAX, max.clone()))
                 } else {
                     None
                 }
             }
             Bound::OpenRange(min, max) => {
-                if self <= *min {
+                if *self <= *min {
                     Some((INVALID_BOUND_OPEN_MIN, min.clone()))
-                } else if self >= *max {
+                } else if *self >= *max {
                     Some((INVALID_BOUND_OPEN_MAX, max.clone()))
                 } else {
                     None
                 }
             }
             Bound::Exact(bound) => {
-                if *bound != self {
+                if bound != self {
                     Some((INVALID_BOUND_EXACT, bound.clone()))
                 } else {
                     None
                 }
             }
+            Bound::Min(min) => {
+                if *self < *min {
+                    Some((INVALID_BOUND_CLOSED_MIN, min.clone()))
+                } else {
+                    None
+                }
+            }
+            Bound::Max(max) => {
+                if *self > *max {
+                    Some((INVALID_BOUND_CLOSED_MAX, max.clone()))
+                } else {
+                    None
+                }
+            }
+            Bound::GreaterThan(min) => {
+                if *self <= *min {
+                    Some((INVALID_BOUND_OPEN_MIN, min.clone()))
+                } else {
+                    None
+                }
+            }
+            Bound::LessThan(max) => {
+                if *self >= *max {
+                    Some((INVALID_BOUND_OPEN_MAX, max.clone()))
+                } else {
+                    None
+                }
+            }
         } {
-            Validation::failure(vec![invalid_value(code, name, self, expected)])
+            vec![invalid_value(code, name, self.clone(), expected)]
         } else {
-            Validation::success(self)
+            vec![]
         }
     }
 }
 
+impl<T> Validate<Bound<T>, FieldName> for T
+where
+    T: PartialOrd + Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &Bound<T>,
+    ) -> Validation<Bound<T>, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// Values of zero are not allowed.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
@@ -435,6 +605,27 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NonZero;
 
+impl<T> ValidateRef<NonZero, FieldName> for T
+where
+    T: HasZeroValue + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        _constraint: &NonZero,
+    ) -> Vec<ConstraintViolation> {
+        match check_non_zero(self) {
+            None => vec![],
+            Some(code) => vec![invalid_optional_value(
+                code,
+                name,
+                Some(self.clone().into()),
+                None,
+            )],
+        }
+    }
+}
+
 impl<T> Validate<NonZero, FieldName> for T
 where
     T: HasZeroValue + Into<Value>,
@@ -444,27 +635,46 @@ where
         name: impl Into<FieldName>,
         _constraint: &NonZero,
     ) -> Validation<NonZero, Self> {
-        if self.is_zero_value() {
-            Validation::failure(vec![invalid_optional_value(
-                INVALID_NON_ZERO,
+        match check_non_zero(&self) {
+            None => Validation::success(self),
+            Some(code) => Validation::failure(vec![invalid_optional_value(
+                code,
                 name,
                 Some(self.into()),
                 None,
-            )])
-        } else {
-            Validation::success(self)
+            )]),
         }
     }
 }
 
+/// Returns the error code of the violation if the given value is zero.
+fn check_non_zero<T>(value: &T) -> Option<&'static str>
+where
+    T: HasZeroValue,
+{
+    if value.is_zero_value() {
+        Some(INVALID_NON_ZERO)
+    } else {
+        None
+    }
+}
+
 /// Maximum number of allowed integer digits and fraction digits.
 ///
 /// The validation function can be applied in the [`FieldName`] context.
 /// It is implemented for all types `T` that implement the [`HasDecimalDigits`]
 /// property trait.
 ///
+/// This crate implements [`HasDecimalDigits`] for all primitive integer and
+/// float types, for `String` and `&str` holding a plain decimal literal like
+/// `"-1234.50"` and, with the according crate features enabled, for
+/// `BigDecimal` and `BigInt`. A value that is not a well-formed decimal
+/// number, e.g. a malformed string or a float that is `NaN`, is reported with
+/// the error code [`INVALID_DIGITS_NUMBER`].
+///
 /// [`FieldName`]: ../core/struct.FieldName.html
 /// [`HasDecimalDigits`]: ../property/trait.HasDecimalDigits.html
+/// [`INVALID_DIGITS_NUMBER`]: constant.INVALID_DIGITS_NUMBER.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Digits {
     /// Maximum number of allowed integer digits (digits to the left of the
@@ -475,34 +685,46 @@ pub struct Digits {
     pub fraction: u64,
 }
 
-impl<T> Validate<Digits, FieldName> for T
+impl<T> ValidateRef<Digits, FieldName> for T
 where
     T: HasDecimalDigits,
 {
-    fn validate(self, name: impl Into<FieldName>, constraint: &Digits) -> Validation<Digits, Self> {
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &Digits,
+    ) -> Vec<ConstraintViolation> {
+        if !self.is_decimal_number() {
+            return vec![invalid_optional_value(
+                INVALID_DIGITS_NUMBER,
+                name,
+                None,
+                None,
+            )];
+        }
         let integer = self.integer_digits();
         let fraction = self.fraction_digits();
         if integer <= constraint.integer {
             if fraction <= constraint.fraction {
-                Validation::success(self)
+                vec![]
             } else {
-                Validation::failure(vec![invalid_value(
+                vec![invalid_value(
                     INVALID_DIGITS_FRACTION,
                     name,
                     fraction,
                     constraint.fraction,
-                )])
+                )]
             }
         } else if fraction <= constraint.fraction {
-            Validation::failure(vec![invalid_value(
+            vec![invalid_value(
                 INVALID_DIGITS_INTEGER,
                 name,
                 integer,
                 constraint.integer,
-            )])
+            )]
         } else {
             let name = name.into();
-            Validation::failure(vec![
+            vec![
                 invalid_value(
                     INVALID_DIGITS_INTEGER,
                     name.clone(),
@@ -510,11 +732,21 @@ where
                     constraint.integer,
                 ),
                 invalid_value(INVALID_DIGITS_FRACTION, name, fraction, constraint.fraction),
-            ])
+            ]
         }
     }
 }
 
+impl<T> Validate<Digits, FieldName> for T
+where
+    T: HasDecimalDigits,
+{
+    fn validate(self, name: impl Into<FieldName>, constraint: &Digits) -> Validation<Digits, Self> {
+        let violations = self.validate_ref(name, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
 /// The value must contain the specified member or the specified member must be
 /// part of the value.
 ///
@@ -527,6 +759,28 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Contains<'a, A>(pub &'a A);
 
+impl<'a, T, A> ValidateRef<Contains<'a, A>, FieldName> for T
+where
+    T: HasMember<A> + Clone + Into<Value>,
+    A: Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &Contains<'a, A>,
+    ) -> Vec<ConstraintViolation> {
+        match check_contains(self, constraint) {
+            None => vec![],
+            Some(code) => vec![invalid_value(
+                code,
+                name,
+                self.clone(),
+                constraint.0.clone(),
+            )],
+        }
+    }
+}
+
 impl<'a, T, A> Validate<Contains<'a, A>, FieldName> for T
 where
     T: HasMember<A> + Into<Value>,
@@ -537,19 +791,28 @@ where
         name: impl Into<FieldName>,
         constraint: &Contains<'a, A>,
     ) -> Validation<Contains<'a, A>, Self> {
-        if self.has_member(&constraint.0) {
-            Validation::success(self)
-        } else {
-            Validation::failure(vec![invalid_value(
-                INVALID_CONTAINS_ELEMENT,
-                name,
-                self,
-                constraint.0.clone(),
-            )])
+        match check_contains(&self, constraint) {
+            None => Validation::success(self),
+            Some(code) => {
+                Validation::failure(vec![invalid_value(code, name, self, constraint.0.clone())])
+            }
         }
     }
 }
 
+/// Returns the error code of the violation if the given value does not
+/// contain the member of the constraint.
+fn check_contains<T, A>(value: &T, constraint: &Contains<'_, A>) -> Option<&'static str>
+where
+    T: HasMember<A>,
+{
+    if value.has_member(constraint.0) {
+        None
+    } else {
+        Some(INVALID_CONTAINS_ELEMENT)
+    }
+}
+
 /// Two related fields must be equal.
 ///
 /// The validation function can be applied in the [`RelatedFields`] context.
@@ -559,6 +822,29 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MustMatch;
 
+impl<T> ValidateRef<MustMatch, RelatedFields> for (T, T)
+where
+    T: PartialEq + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &MustMatch,
+    ) -> Vec<ConstraintViolation> {
+        let RelatedFields(name1, name2) = fields.into();
+        match check_must_match(self) {
+            None => vec![],
+            Some(code) => vec![invalid_relation(
+                code,
+                name1,
+                self.0.clone(),
+                name2,
+                self.1.clone(),
+            )],
+        }
+    }
+}
+
 impl<T> Validate<MustMatch, RelatedFields> for (T, T)
 where
     T: PartialEq + Into<Value>,
@@ -569,20 +855,28 @@ where
         _constraint: &MustMatch,
     ) -> Validation<MustMatch, Self> {
         let RelatedFields(name1, name2) = fields.into();
-        if self.0 == self.1 {
-            Validation::success(self)
-        } else {
-            Validation::failure(vec![invalid_relation(
-                INVALID_MUST_MATCH,
-                name1,
-                self.0,
-                name2,
-                self.1,
-            )])
+        match check_must_match(&self) {
+            None => Validation::success(self),
+            Some(code) => {
+                Validation::failure(vec![invalid_relation(code, name1, self.0, name2, self.1)])
+            }
         }
     }
 }
 
+/// Returns the error code of the violation if the given pair of values does
+/// not match.
+fn check_must_match<T>(values: &(T, T)) -> Option<&'static str>
+where
+    T: PartialEq,
+{
+    if values.0 == values.1 {
+        None
+    } else {
+        Some(INVALID_MUST_MATCH)
+    }
+}
+
 /// Two related fields must define a range.
 ///
 /// This constraint is useful for structs with pairs of fields that define a
@@ -602,6 +896,29 @@ pub enum MustDefineRange {
     Exclusive,
 }
 
+impl<T> ValidateRef<MustDefineRange, RelatedFields> for (T, T)
+where
+    T: PartialOrd + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        constraint: &MustDefineRange,
+    ) -> Vec<ConstraintViolation> {
+        let RelatedFields(name1, name2) = fields.into();
+        match check_must_define_range(self, constraint) {
+            None => vec![],
+            Some(code) => vec![invalid_relation(
+                code,
+                name1,
+                self.0.clone(),
+                name2,
+                self.1.clone(),
+            )],
+        }
+    }
+}
+
 impl<T> Validate<MustDefineRange, RelatedFields> for (T, T)
 where
     T: PartialOrd + Into<Value>,
@@ -612,43 +929,491 @@ where
         constraint: &MustDefineRange,
     ) -> Validation<MustDefineRange, Self> {
         let RelatedFields(name1, name2) = fields.into();
-        match *constraint {
-            MustDefineRange::Inclusive => {
-                if self.0 <= self.1 {
-                    Validation::success(self)
-                } else {
-                    Validation::failure(vec![invalid_relation(
-                        INVALID_MUST_DEFINE_RANGE_INCLUSIVE,
-                        name1,
-                        self.0,
-                        name2,
-                        self.1,
-                    )])
-                }
+        match check_must_define_range(&self, constraint) {
+            None => Validation::success(self),
+            Some(code) => {
+                Validation::failure(vec![invalid_relation(code, name1, self.0, name2, self.1)])
             }
-            MustDefineRange::Exclusive => {
-                if self.0 < self.1 {
-                    Validation::success(self)
-                } else {
-                    Validation::failure(vec![invalid_relation(
-                        INVALID_MUST_DEFINE_RANGE_EXCLUSIVE,
-                        name1,
-                        self.0,
-                        name2,
-                        self.1,
-                    )])
-                }
+        }
+    }
+}
+
+/// Returns the error code of the violation if the given pair of values does
+/// not define a range.
+fn check_must_define_range<T>(values: &(T, T), constraint: &MustDefineRange) -> Option<&'static str>
+where
+    T: PartialOrd,
+{
+    let (is_range, code) = match *constraint {
+        MustDefineRange::Inclusive => (values.0 <= values.1, INVALID_MUST_DEFINE_RANGE_INCLUSIVE),
+        MustDefineRange::Exclusive => (values.0 < values.1, INVALID_MUST_DEFINE_RANGE_EXCLUSIVE),
+    };
+    if is_range {
+        None
+    } else {
+        Some(code)
+    }
+}
+
+/// Two related fields must not be equal.
+///
+/// This constraint is useful for rules like "the new password must be
+/// different from the old password".
+///
+/// The validation function can be applied in the [`RelatedFields`] context.
+/// It is implemented for all types `T` that implement the `PartialEq` and
+/// `Clone` traits and `Into<Value>`.
+///
+/// [`RelatedFields`]: ../core/struct.RelatedFields.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MustNotMatch;
+
+impl<T> ValidateRef<MustNotMatch, RelatedFields> for (T, T)
+where
+    T: PartialEq + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &MustNotMatch,
+    ) -> Vec<ConstraintViolation> {
+        let RelatedFields(name1, name2) = fields.into();
+        if self.0 != self.1 {
+            vec![]
+        } else {
+            vec![invalid_relation(
+                INVALID_MUST_NOT_MATCH,
+                name1,
+                self.0.clone(),
+                name2,
+                self.1.clone(),
+            )]
+        }
+    }
+}
+
+impl<T> Validate<MustNotMatch, RelatedFields> for (T, T)
+where
+    T: PartialEq + Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFields>,
+        constraint: &MustNotMatch,
+    ) -> Validation<MustNotMatch, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The first of two related fields must compare to the second field in the
+/// specified way.
+///
+/// This constraint is useful for rules like "the end must not be before the
+/// start" for types that do not define a range.
+///
+/// The validation function can be applied in the [`RelatedFields`] context.
+/// It is implemented for all types `T` that implement the `PartialOrd` and
+/// `Clone` traits and `Into<Value>`.
+///
+/// [`RelatedFields`]: ../core/struct.RelatedFields.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MustCompare {
+    /// The first value must be less than the second value
+    LessThan,
+    /// The first value must be less than or equal to the second value
+    LessOrEqual,
+    /// The first value must be greater than the second value
+    GreaterThan,
+    /// The first value must be greater than or equal to the second value
+    GreaterOrEqual,
+}
+
+impl MustCompare {
+    /// Returns the error code if the given values do not compare as required
+    fn check<T>(self, value1: &T, value2: &T) -> Option<&'static str>
+    where
+        T: PartialOrd,
+    {
+        let (is_compliant, code) = match self {
+            MustCompare::LessThan => (value1 < value2, INVALID_MUST_COMPARE_LESS_THAN),
+            MustCompare::LessOrEqual => (value1 <= value2, INVALID_MUST_COMPARE_LESS_OR_EQUAL),
+            MustCompare::GreaterThan => (value1 > value2, INVALID_MUST_COMPARE_GREATER_THAN),
+            MustCompare::GreaterOrEqual => {
+                (value1 >= value2, INVALID_MUST_COMPARE_GREATER_OR_EQUAL)
             }
+        };
+        if is_compliant {
+            None
+        } else {
+            Some(code)
+        }
+    }
+}
+
+impl<T> ValidateRef<MustCompare, RelatedFields> for (T, T)
+where
+    T: PartialOrd + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        constraint: &MustCompare,
+    ) -> Vec<ConstraintViolation> {
+        let RelatedFields(name1, name2) = fields.into();
+        if let Some(code) = constraint.check(&self.0, &self.1) {
+            vec![invalid_relation(
+                code,
+                name1,
+                self.0.clone(),
+                name2,
+                self.1.clone(),
+            )]
+        } else {
+            vec![]
         }
     }
 }
 
+impl<T> Validate<MustCompare, RelatedFields> for (T, T)
+where
+    T: PartialOrd + Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFields>,
+        constraint: &MustCompare,
+    ) -> Validation<MustCompare, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// The second of two related fields is required if the first field is
+/// present.
+///
+/// For example a field `state` may be required if the field `country` is
+/// given.
+///
+/// The validation function can be applied in the [`RelatedFields`] context.
+/// It is implemented for all pairs of `Option<A>` and `Option<B>` where `A`
+/// and `B` implement `Clone` and `Into<Value>`. The `ValidateRef` trait is
+/// also implemented for pairs of references to such options.
+///
+/// [`RelatedFields`]: ../core/struct.RelatedFields.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequiredIf;
+
+impl<A, B> ValidateRef<RequiredIf, RelatedFields> for (Option<A>, Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &RequiredIf,
+    ) -> Vec<ConstraintViolation> {
+        validate_required_if(self.0.as_ref(), self.1.as_ref(), fields)
+    }
+}
+
+impl<A, B> Validate<RequiredIf, RelatedFields> for (Option<A>, Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFields>,
+        constraint: &RequiredIf,
+    ) -> Validation<RequiredIf, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+impl<A, B> ValidateRef<RequiredIf, RelatedFields> for (&Option<A>, &Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &RequiredIf,
+    ) -> Vec<ConstraintViolation> {
+        validate_required_if(self.0.as_ref(), self.1.as_ref(), fields)
+    }
+}
+
+/// Validates that the second of two related fields is present if the first
+/// one is present.
+fn validate_required_if<A, B>(
+    value1: Option<&A>,
+    value2: Option<&B>,
+    fields: impl Into<RelatedFields>,
+) -> Vec<ConstraintViolation>
+where
+    A: Clone + Into<Value>,
+{
+    let RelatedFields(name1, name2) = fields.into();
+    match (value1, value2) {
+        (Some(value1), None) => vec![invalid_optional_relation(
+            INVALID_REQUIRED_IF,
+            name1,
+            Some(value1.clone().into()),
+            name2,
+            None,
+        )],
+        _ => vec![],
+    }
+}
+
+/// Exactly one of two related fields must be present.
+///
+/// The validation function can be applied in the [`RelatedFields`] context.
+/// It is implemented for all pairs of `Option<A>` and `Option<B>` where `A`
+/// and `B` implement `Clone` and `Into<Value>`. The `ValidateRef` trait is
+/// also implemented for pairs of references to such options.
+///
+/// [`RelatedFields`]: ../core/struct.RelatedFields.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExclusiveOr;
+
+impl<A, B> ValidateRef<ExclusiveOr, RelatedFields> for (Option<A>, Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &ExclusiveOr,
+    ) -> Vec<ConstraintViolation> {
+        validate_exclusive_or(self.0.as_ref(), self.1.as_ref(), fields)
+    }
+}
+
+impl<A, B> Validate<ExclusiveOr, RelatedFields> for (Option<A>, Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFields>,
+        constraint: &ExclusiveOr,
+    ) -> Validation<ExclusiveOr, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+impl<A, B> ValidateRef<ExclusiveOr, RelatedFields> for (&Option<A>, &Option<B>)
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFields>,
+        _constraint: &ExclusiveOr,
+    ) -> Vec<ConstraintViolation> {
+        validate_exclusive_or(self.0.as_ref(), self.1.as_ref(), fields)
+    }
+}
+
+/// Validates that exactly one of two related fields is present.
+fn validate_exclusive_or<A, B>(
+    value1: Option<&A>,
+    value2: Option<&B>,
+    fields: impl Into<RelatedFields>,
+) -> Vec<ConstraintViolation>
+where
+    A: Clone + Into<Value>,
+    B: Clone + Into<Value>,
+{
+    let RelatedFields(name1, name2) = fields.into();
+    if value1.is_some() != value2.is_some() {
+        vec![]
+    } else {
+        vec![invalid_optional_relation(
+            INVALID_EXCLUSIVE_OR,
+            name1,
+            value1.cloned().map(Into::into),
+            name2,
+            value2.cloned().map(Into::into),
+        )]
+    }
+}
+
+/// Validates the number of present values of a set of related fields.
+///
+/// Panics if the number of field names differs from the number of values.
+fn validate_present_values<T>(
+    values: &T,
+    fields: impl Into<RelatedFieldSet>,
+    code: &'static str,
+    is_compliant: impl FnOnce(usize, usize) -> bool,
+) -> Vec<ConstraintViolation>
+where
+    T: HasOptionalValues,
+{
+    let RelatedFieldSet(names) = fields.into();
+    let values = values.optional_values();
+    assert_eq!(
+        names.len(),
+        values.len(),
+        "the number of field names must be equal to the number of values"
+    );
+    let num_present = values.iter().filter(|value| value.is_some()).count();
+    if is_compliant(num_present, values.len()) {
+        vec![]
+    } else {
+        vec![invalid_field_set(code, names.into_iter().zip(values))]
+    }
+}
+
+/// At least one of a set of related fields must be present.
+///
+/// For example at least one of the fields phone, email or postal address must
+/// be given.
+///
+/// The validation function can be applied in the [`RelatedFieldSet`] context.
+/// It is implemented for all types `T` that implement the
+/// [`HasOptionalValues`] property trait.
+///
+/// [`RelatedFieldSet`]: ../core/struct.RelatedFieldSet.html
+/// [`HasOptionalValues`]: ../property/trait.HasOptionalValues.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtLeastOneOf;
+
+impl<T> ValidateRef<AtLeastOneOf, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFieldSet>,
+        _constraint: &AtLeastOneOf,
+    ) -> Vec<ConstraintViolation> {
+        validate_present_values(self, fields, INVALID_AT_LEAST_ONE_OF, |present, _| {
+            present >= 1
+        })
+    }
+}
+
+impl<T> Validate<AtLeastOneOf, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFieldSet>,
+        constraint: &AtLeastOneOf,
+    ) -> Validation<AtLeastOneOf, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// At most one of a set of related fields may be present.
+///
+/// The validation function can be applied in the [`RelatedFieldSet`] context.
+/// It is implemented for all types `T` that implement the
+/// [`HasOptionalValues`] property trait.
+///
+/// [`RelatedFieldSet`]: ../core/struct.RelatedFieldSet.html
+/// [`HasOptionalValues`]: ../property/trait.HasOptionalValues.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtMostOneOf;
+
+impl<T> ValidateRef<AtMostOneOf, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFieldSet>,
+        _constraint: &AtMostOneOf,
+    ) -> Vec<ConstraintViolation> {
+        validate_present_values(self, fields, INVALID_AT_MOST_ONE_OF, |present, _| {
+            present <= 1
+        })
+    }
+}
+
+impl<T> Validate<AtMostOneOf, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFieldSet>,
+        constraint: &AtMostOneOf,
+    ) -> Validation<AtMostOneOf, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+/// Either all or none of a set of related fields must be present.
+///
+/// For example the fields street, zip code and city of an optional address
+/// must be given all together.
+///
+/// The validation function can be applied in the [`RelatedFieldSet`] context.
+/// It is implemented for all types `T` that implement the
+/// [`HasOptionalValues`] property trait.
+///
+/// [`RelatedFieldSet`]: ../core/struct.RelatedFieldSet.html
+/// [`HasOptionalValues`]: ../property/trait.HasOptionalValues.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllOrNone;
+
+impl<T> ValidateRef<AllOrNone, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate_ref(
+        &self,
+        fields: impl Into<RelatedFieldSet>,
+        _constraint: &AllOrNone,
+    ) -> Vec<ConstraintViolation> {
+        validate_present_values(self, fields, INVALID_ALL_OR_NONE, |present, total| {
+            present == 0 || present == total
+        })
+    }
+}
+
+impl<T> Validate<AllOrNone, RelatedFieldSet> for T
+where
+    T: HasOptionalValues,
+{
+    fn validate(
+        self,
+        fields: impl Into<RelatedFieldSet>,
+        constraint: &AllOrNone,
+    ) -> Validation<AllOrNone, Self> {
+        let violations = self.validate_ref(fields, constraint);
+        Validation::checked(self, violations)
+    }
+}
+
+pub use self::const_generic::*;
+
+mod const_generic;
+
 #[cfg(feature = "regex")]
 pub use with_regex::*;
 
 #[cfg(feature = "regex")]
 mod with_regex {
-    use crate::{invalid_value, FieldName, Validate, Validation};
+    use crate::{invalid_value, ConstraintViolation, FieldName, Validate, ValidateRef, Validation};
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
     use regex::Regex;
 
     /// Error code: the value does not match the specified pattern
@@ -664,25 +1429,41 @@ mod with_regex {
     #[derive(Debug, Clone)]
     pub struct Pattern(pub Regex);
 
-    impl Validate<Pattern, FieldName> for String {
-        fn validate(
-            self,
+    impl ValidateRef<Pattern, FieldName> for String {
+        fn validate_ref(
+            &self,
             name: impl Into<FieldName>,
             constraint: &Pattern,
-        ) -> Validation<Pattern, Self> {
-            if constraint.0.is_match(&self) {
-                Validation::success(self)
+        ) -> Vec<ConstraintViolation> {
+            if constraint.0.is_match(self) {
+                vec![]
             } else {
-                Validation::failure(vec![invalid_value(
+                vec![invalid_value(
                     INVALID_PATTERN,
                     name,
-                    self,
+                    self.clone(),
                     constraint.0.to_string(),
-                )])
+                )]
             }
         }
     }
+
+    impl Validate<Pattern, FieldName> for String {
+        fn validate(
+            self,
+            name: impl Into<FieldName>,
+            constraint: &Pattern,
+        ) -> Validation<Pattern, Self> {
+            let violations = self.validate_ref(name, constraint);
+            Validation::checked(self, violations)
+        }
+    }
 }
 
 #[cfg(test)]
+#[allow(
+    deprecated,
+    clippy::bool_assert_comparison,
+    clippy::legacy_numeric_constants
+)]
 mod tests;
@@ -1,5 +1,8 @@
 use super::*;
-use crate::{ConstraintViolation, Field, InvalidValue, ValidationError};
+use crate::{
+    ConstraintViolation, Field, InvalidFieldSet, InvalidRelation, InvalidValue, Severity,
+    ValidationError,
+};
 use proptest::prelude::*;
 
 mod assert_true {
@@ -7,14 +10,14 @@ mod assert_true {
 
     #[test]
     fn validate_assert_true_on_value_true() {
-        let result = true.validate("agreed", &AssertTrue).result();
+        let result = true.validate("agreed", &AssertTrue).result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), true);
+        assert_eq!(result.unwrap().0.unwrap(), true);
     }
 
     #[test]
     fn validate_assert_true_on_value_false() {
-        let result = false.validate("agreed", &AssertTrue).result();
+        let result = false.validate("agreed", &AssertTrue).result_with_warnings();
 
         assert_eq!(
             result,
@@ -22,12 +25,14 @@ mod assert_true {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-assert-true".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "agreed".into(),
                         actual: Some(Value::Boolean(false)),
                         expected: Some(Value::Boolean(true)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         );
     }
@@ -38,14 +43,18 @@ mod assert_false {
 
     #[test]
     fn validate_assert_false_on_value_false() {
-        let result = false.validate("unchecked", &AssertFalse).result();
+        let result = false
+            .validate("unchecked", &AssertFalse)
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), false);
+        assert_eq!(result.unwrap().0.unwrap(), false);
     }
 
     #[test]
     fn validate_assert_false_on_value_true() {
-        let result = true.validate("unchecked", &AssertFalse).result();
+        let result = true
+            .validate("unchecked", &AssertFalse)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -53,12 +62,14 @@ mod assert_false {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-assert-false".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "unchecked".into(),
                         actual: Some(Value::Boolean(true)),
                         expected: Some(Value::Boolean(false)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         );
     }
@@ -66,13 +77,16 @@ mod assert_false {
 
 mod not_empty {
     use super::*;
+    #[cfg(feature = "std")]
     use std::collections::{HashMap, HashSet};
 
     #[test]
     fn validate_not_empty_on_empty_string() {
         let input = String::new();
 
-        let result = input.validate("text_field", &NotEmpty).result();
+        let result = input
+            .validate("text_field", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -80,12 +94,14 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "text_field".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -97,9 +113,9 @@ mod not_empty {
         ) {
             let original = input.clone();
 
-            let result = input.validate("text_field", &NotEmpty).result();
+            let result = input.validate("text_field", &NotEmpty).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
     }
 
@@ -107,7 +123,9 @@ mod not_empty {
     fn validate_not_empty_on_empty_vec() {
         let input = Vec::<u16>::new();
 
-        let result = input.validate("collection", &NotEmpty).result();
+        let result = input
+            .validate("collection", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -115,12 +133,14 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "collection".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -132,17 +152,20 @@ mod not_empty {
         ) {
             let original = input.clone();
 
-            let result = input.validate("collection", &NotEmpty).result();
+            let result = input.validate("collection", &NotEmpty).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn validate_not_empty_on_empty_hash_set() {
         let input = HashSet::<u16>::new();
 
-        let result = input.validate("collection", &NotEmpty).result();
+        let result = input
+            .validate("collection", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -150,34 +173,40 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "collection".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
 
     proptest! {
+        #[cfg(feature = "std")]
         #[test]
         fn validate_not_empty_on_non_empty_hash_set(
            input in prop::collection::hash_set(any::<u16>(), 1..100)
         ) {
             let original = input.clone();
 
-            let result = input.validate("collection", &NotEmpty).result();
+            let result = input.validate("collection", &NotEmpty).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn validate_not_empty_on_empty_hash_map() {
         let input = HashMap::<u16, i64>::new();
 
-        let result = input.validate("collection", &NotEmpty).result();
+        let result = input
+            .validate("collection", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -185,26 +214,29 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "collection".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
 
     proptest! {
+        #[cfg(feature = "std")]
         #[test]
         fn validate_not_empty_on_non_empty_hash_map(
            input in prop::collection::hash_map(any::<u16>(), any::<i64>(), 1..100)
         ) {
             let original = input.clone();
 
-            let result = input.validate("collection", &NotEmpty).result();
+            let result = input.validate("collection", &NotEmpty).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
     }
 
@@ -212,7 +244,9 @@ mod not_empty {
     fn validate_not_empty_on_option_none() {
         let input: Option<String> = None;
 
-        let result = input.validate("optional_text", &NotEmpty).result();
+        let result = input
+            .validate("optional_text", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -220,12 +254,14 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "optional_text".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -234,7 +270,9 @@ mod not_empty {
     fn validate_not_empty_on_option_of_some_empty_string() {
         let input: Option<String> = Some(String::new());
 
-        let result = input.validate("optional_text", &NotEmpty).result();
+        let result = input
+            .validate("optional_text", &NotEmpty)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -242,12 +280,14 @@ mod not_empty {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-not-empty".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "optional_text".into(),
                         actual: None,
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -259,9 +299,9 @@ mod not_empty {
         ) {
             let original = Some(input.clone());
 
-            let result = Some(input).validate("text_field", &NotEmpty).result();
+            let result = Some(input).validate("text_field", &NotEmpty).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
     }
 }
@@ -277,9 +317,9 @@ mod length {
             let input = vec![1; target_len];
             let original = input.clone();
 
-            let result = input.validate("text_field", &Length::Exact(target_len)).result();
+            let result = input.validate("text_field", &Length::Exact(target_len)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
 
         #[test]
@@ -292,7 +332,7 @@ mod length {
         ) {
             let input = vec![1; input_len];
 
-            let result = input.validate("text_field", &Length::Exact(target_len)).result();
+            let result = input.validate("text_field", &Length::Exact(target_len)).result_with_warnings();
 
             assert_eq!(
                 result,
@@ -300,12 +340,14 @@ mod length {
                     message: None,
                     violations: vec![ConstraintViolation::Field(InvalidValue {
                         code: "invalid-length-exact".into(),
+                        severity: Severity::Error,
                         field: Field {
                             name: "text_field".into(),
                             actual: Some(Value::Integer(input_len as i32)),
                             expected: Some(Value::Integer(target_len as i32)),
                         }
-                    })]
+                    })],
+                    truncated: false,
                 })
             )
         }
@@ -319,9 +361,9 @@ mod length {
             let input = vec![1; input_len];
             let original = input.clone();
 
-            let result = input.validate("text_field", &Length::Max(max_len)).result();
+            let result = input.validate("text_field", &Length::Max(max_len)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
 
         #[test]
@@ -332,7 +374,7 @@ mod length {
         ) {
             let input = vec![1; input_len];
 
-            let result = input.validate("text_field", &Length::Max(max_len)).result();
+            let result = input.validate("text_field", &Length::Max(max_len)).result_with_warnings();
 
             assert_eq!(
                 result,
@@ -340,12 +382,14 @@ mod length {
                     message: None,
                     violations: vec![ConstraintViolation::Field(InvalidValue {
                         code: "invalid-length-max".into(),
+                        severity: Severity::Error,
                         field: Field {
                             name: "text_field".into(),
                             actual: Some(Value::Integer(input_len as i32)),
                             expected: Some(Value::Integer(max_len as i32)),
                         }
-                    })]
+                    })],
+                    truncated: false,
                 })
             )
         }
@@ -359,9 +403,9 @@ mod length {
             let input = vec![1; input_len];
             let original = input.clone();
 
-            let result = input.validate("text_field", &Length::Min(min_len)).result();
+            let result = input.validate("text_field", &Length::Min(min_len)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
 
         #[test]
@@ -372,7 +416,7 @@ mod length {
         ) {
             let input = vec![1; input_len];
 
-            let result = input.validate("text_field", &Length::Min(min_len)).result();
+            let result = input.validate("text_field", &Length::Min(min_len)).result_with_warnings();
 
             assert_eq!(
                 result,
@@ -380,12 +424,14 @@ mod length {
                     message: None,
                     violations: vec![ConstraintViolation::Field(InvalidValue {
                         code: "invalid-length-min".into(),
+                        severity: Severity::Error,
                         field: Field {
                             name: "text_field".into(),
                             actual: Some(Value::Integer(input_len as i32)),
                             expected: Some(Value::Integer(min_len as i32)),
                         }
-                    })]
+                    })],
+                    truncated: false,
                 })
             )
         }
@@ -401,9 +447,9 @@ mod length {
             let input = vec![1; input_len];
             let original = input.clone();
 
-            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result();
+            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), original);
+            prop_assert_eq!(result.unwrap().0.unwrap(), original);
         }
 
         #[test]
@@ -416,7 +462,7 @@ mod length {
         ) {
             let input = vec![1; input_len];
 
-            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result();
+            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result_with_warnings();
 
             assert_eq!(
                 result,
@@ -424,12 +470,14 @@ mod length {
                     message: None,
                     violations: vec![ConstraintViolation::Field(InvalidValue {
                         code: "invalid-length-min".into(),
+                        severity: Severity::Error,
                         field: Field {
                             name: "text_field".into(),
                             actual: Some(Value::Integer(input_len as i32)),
                             expected: Some(Value::Integer(min_len as i32)),
                         }
-                    })]
+                    })],
+                    truncated: false,
                 })
             )
         }
@@ -444,7 +492,7 @@ mod length {
         ) {
             let input = vec![1; input_len];
 
-            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result();
+            let result = input.validate("text_field", &Length::MinMax(min_len, max_len)).result_with_warnings();
 
             assert_eq!(
                 result,
@@ -452,12 +500,14 @@ mod length {
                     message: None,
                     violations: vec![ConstraintViolation::Field(InvalidValue {
                         code: "invalid-length-max".into(),
+                        severity: Severity::Error,
                         field: Field {
                             name: "text_field".into(),
                             actual: Some(Value::Integer(input_len as i32)),
                             expected: Some(Value::Integer(max_len as i32)),
                         }
-                    })]
+                    })],
+                    truncated: false,
                 })
             )
         }
@@ -473,9 +523,11 @@ mod char_count {
         assert_eq!(text.len(), 9);
         let original = text;
 
-        let result = text.validate("message", &CharCount::Exact(7)).result();
+        let result = text
+            .validate("message", &CharCount::Exact(7))
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), original);
+        assert_eq!(result.unwrap().0.unwrap(), original);
     }
 
     #[test]
@@ -483,7 +535,9 @@ mod char_count {
         let text = "I ❤ u";
         assert_eq!(text.len(), 7);
 
-        let result = text.validate("message", &CharCount::Exact(7)).result();
+        let result = text
+            .validate("message", &CharCount::Exact(7))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -491,12 +545,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-exact".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(5)),
                         expected: Some(Value::Integer(7)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -506,7 +562,9 @@ mod char_count {
         let text = "I ❤ you!";
         assert_eq!(text.len(), 10);
 
-        let result = text.validate("message", &CharCount::Exact(7)).result();
+        let result = text
+            .validate("message", &CharCount::Exact(7))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -514,12 +572,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-exact".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(8)),
                         expected: Some(Value::Integer(7)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -530,9 +590,11 @@ mod char_count {
         assert_eq!(text.len(), 9);
         let original = text;
 
-        let result = text.validate("message", &CharCount::Max(7)).result();
+        let result = text
+            .validate("message", &CharCount::Max(7))
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), original);
+        assert_eq!(result.unwrap().0.unwrap(), original);
     }
 
     #[test]
@@ -540,7 +602,9 @@ mod char_count {
         let text = "I ❤ you!";
         assert_eq!(text.len(), 10);
 
-        let result = text.validate("message", &CharCount::Max(7)).result();
+        let result = text
+            .validate("message", &CharCount::Max(7))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -548,12 +612,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(8)),
                         expected: Some(Value::Integer(7)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -564,9 +630,11 @@ mod char_count {
         assert_eq!(text.len(), 10);
         let original = text;
 
-        let result = text.validate("message", &CharCount::Min(8)).result();
+        let result = text
+            .validate("message", &CharCount::Min(8))
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), original);
+        assert_eq!(result.unwrap().0.unwrap(), original);
     }
 
     #[test]
@@ -574,7 +642,9 @@ mod char_count {
         let text = "I ❤ you";
         assert_eq!(text.len(), 9);
 
-        let result = text.validate("message", &CharCount::Min(8)).result();
+        let result = text
+            .validate("message", &CharCount::Min(8))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -582,12 +652,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(7)),
                         expected: Some(Value::Integer(8)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -598,9 +670,11 @@ mod char_count {
         assert_eq!(text.len(), 9);
         let original = text;
 
-        let result = text.validate("message", &CharCount::MinMax(6, 7)).result();
+        let result = text
+            .validate("message", &CharCount::MinMax(6, 7))
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), original);
+        assert_eq!(result.unwrap().0.unwrap(), original);
     }
 
     #[test]
@@ -608,7 +682,9 @@ mod char_count {
         let text = "I ❤ you!";
         assert_eq!(text.len(), 10);
 
-        let result = text.validate("message", &CharCount::MinMax(6, 7)).result();
+        let result = text
+            .validate("message", &CharCount::MinMax(6, 7))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -616,12 +692,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(8)),
                         expected: Some(Value::Integer(7)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -631,7 +709,9 @@ mod char_count {
         let text = "I ❤ u";
         assert_eq!(text.len(), 7);
 
-        let result = text.validate("message", &CharCount::MinMax(6, 7)).result();
+        let result = text
+            .validate("message", &CharCount::MinMax(6, 7))
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -639,12 +719,14 @@ mod char_count {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-char-count-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "message".into(),
                         actual: Some(Value::Integer(5)),
                         expected: Some(Value::Integer(6)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -661,9 +743,9 @@ mod bound {
         ) {
             let float_value = exact_bound;
 
-            let result = float_value.validate("float_value", &Bound::Exact(exact_bound)).result();
+            let result = float_value.validate("float_value", &Bound::Exact(exact_bound)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), float_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), float_value);
         }
 
         #[test]
@@ -677,18 +759,20 @@ mod bound {
                 exact_bound * 1.001 + 0.001
             };
 
-            let result = float_value.validate("float_value", &Bound::Exact(exact_bound)).result();
+            let result = float_value.validate("float_value", &Bound::Exact(exact_bound)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-exact".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "float_value".into(),
                         actual: Some(Value::Float(float_value)),
                         expected: Some(Value::Float(exact_bound)),
                     }
-                })]
+                })],
+                truncated: false,
             }));
         }
 
@@ -698,9 +782,9 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), min..=max) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), long_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), long_value);
         }
 
         #[test]
@@ -709,18 +793,20 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), i64::min_value()..min) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-closed-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(lower)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -730,18 +816,20 @@ mod bound {
                 .prop_flat_map(|max| (i64::min_value()..=max, Just(max)) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), max + 1..i64::max_value()) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-closed-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(upper)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -751,9 +839,9 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), min..max) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), long_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), long_value);
         }
 
         #[test]
@@ -762,18 +850,20 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), i64::min_value()..min) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-closed-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(lower)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -783,18 +873,20 @@ mod bound {
                 .prop_flat_map(|max| (i64::min_value()..=max, Just(max)) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), max..i64::max_value()) )
         ) {
-            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::ClosedOpenRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-open-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(upper)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -804,9 +896,9 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), min + 1..=max) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), long_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), long_value);
         }
 
         #[test]
@@ -815,18 +907,20 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), i64::min_value()..=min) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-open-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(lower)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -836,18 +930,20 @@ mod bound {
                 .prop_flat_map(|max| (i64::min_value()..=max, Just(max)) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), max + 1..i64::max_value()) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenClosedRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-closed-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(upper)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -857,9 +953,9 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), min + 1..max) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), long_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), long_value);
         }
 
         #[test]
@@ -868,18 +964,20 @@ mod bound {
                 .prop_flat_map(|min| (Just(min), min..=i64::max_value()) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), i64::min_value()..=min) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-open-min".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(lower)),
                     }
-                })]
+                })],
+                truncated: false,
             }))
         }
 
@@ -889,18 +987,146 @@ mod bound {
                 .prop_flat_map(|max| (i64::min_value()..=max, Just(max)) )
                 .prop_flat_map(|(min, max)| (Just(min), Just(max), max..i64::max_value()) )
         ) {
-            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result();
+            let result = long_value.validate("long_value", &Bound::OpenRange(lower, upper)).result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-bound-open-max".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "long_value".into(),
                         actual: Some(Value::Long(long_value)),
                         expected: Some(Value::Long(upper)),
                     }
-                })]
+                })],
+                truncated: false,
+            }))
+        }
+    }
+
+    #[test]
+    fn validate_bound_min_on_an_integer_value_that_is_equal_to_the_minimum() {
+        let result = 1
+            .validate("quantity", &Bound::Min(1))
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), 1);
+    }
+
+    #[test]
+    fn validate_bound_max_on_an_integer_value_that_is_equal_to_the_maximum() {
+        let result = 99
+            .validate("quantity", &Bound::Max(99))
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), 99);
+    }
+
+    #[test]
+    fn validate_bound_greater_than_on_an_integer_value_that_is_greater_than_the_minimum() {
+        let result = 2
+            .validate("quantity", &Bound::GreaterThan(1))
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), 2);
+    }
+
+    #[test]
+    fn validate_bound_less_than_on_an_integer_value_that_is_less_than_the_maximum() {
+        let result = 98
+            .validate("quantity", &Bound::LessThan(99))
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), 98);
+    }
+
+    proptest! {
+        #[test]
+        fn validate_bound_min_on_a_long_value_that_is_less_than_the_minimum(
+            (lower, long_value) in (i64::MIN + 1..=i64::MAX)
+                .prop_flat_map(|min| (Just(min), i64::MIN..min) )
+        ) {
+            let result = long_value.validate("long_value", &Bound::Min(lower)).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-bound-closed-min".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "long_value".into(),
+                        actual: Some(Value::Long(long_value)),
+                        expected: Some(Value::Long(lower)),
+                    }
+                })],
+                truncated: false,
+            }))
+        }
+
+        #[test]
+        fn validate_bound_max_on_a_long_value_that_is_greater_than_the_maximum(
+            (upper, long_value) in (i64::MIN..i64::MAX)
+                .prop_flat_map(|max| (Just(max), max + 1..=i64::MAX) )
+        ) {
+            let result = long_value.validate("long_value", &Bound::Max(upper)).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-bound-closed-max".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "long_value".into(),
+                        actual: Some(Value::Long(long_value)),
+                        expected: Some(Value::Long(upper)),
+                    }
+                })],
+                truncated: false,
+            }))
+        }
+
+        #[test]
+        fn validate_bound_greater_than_on_a_long_value_that_is_less_than_or_equal_the_minimum(
+            (lower, long_value) in (i64::MIN..=i64::MAX)
+                .prop_flat_map(|min| (Just(min), i64::MIN..=min) )
+        ) {
+            let result = long_value.validate("long_value", &Bound::GreaterThan(lower)).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-bound-open-min".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "long_value".into(),
+                        actual: Some(Value::Long(long_value)),
+                        expected: Some(Value::Long(lower)),
+                    }
+                })],
+                truncated: false,
+            }))
+        }
+
+        #[test]
+        fn validate_bound_less_than_on_a_long_value_that_is_greater_than_or_equal_the_maximum(
+            (upper, long_value) in (i64::MIN..=i64::MAX)
+                .prop_flat_map(|max| (Just(max), max..=i64::MAX) )
+        ) {
+            let result = long_value.validate("long_value", &Bound::LessThan(upper)).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-bound-open-max".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "long_value".into(),
+                        actual: Some(Value::Long(long_value)),
+                        expected: Some(Value::Long(upper)),
+                    }
+                })],
+                truncated: false,
             }))
         }
     }
@@ -913,7 +1139,9 @@ mod non_zero {
     fn validate_non_zero_on_a_double_that_is_zero() {
         let field_value = 0f64;
 
-        let result = field_value.validate("field_value", &NonZero).result();
+        let result = field_value
+            .validate("field_value", &NonZero)
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -921,12 +1149,14 @@ mod non_zero {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-non-zero".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "field_value".into(),
                         actual: Some(Value::Double(field_value)),
                         expected: None,
                     }
-                })]
+                })],
+                truncated: false,
             })
         )
     }
@@ -937,9 +1167,9 @@ mod non_zero {
         fn validate_non_zero_on_a_double_that_is_not_zero(
             field_value in any::<f64>().prop_filter("non zero values", |v| *v != 0.)
         ) {
-            let result = field_value.validate("field_value", &NonZero).result();
+            let result = field_value.validate("field_value", &NonZero).result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), field_value);
+            prop_assert_eq!(result.unwrap().0.unwrap(), field_value);
         }
     }
 }
@@ -962,10 +1192,10 @@ mod digits_bigdecimal {
                     fraction: 2,
                 },
             )
-            .result();
+            .result_with_warnings();
 
         assert_eq!(
-            result.unwrap().unwrap(),
+            result.unwrap().0.unwrap(),
             BigDecimal::from_str("12345678.99").unwrap()
         );
     }
@@ -982,7 +1212,7 @@ mod digits_bigdecimal {
                     fraction: 2,
                 },
             )
-            .result();
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -990,12 +1220,14 @@ mod digits_bigdecimal {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-digits-integer".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "account_balance".into(),
                         actual: Some(Value::Long(9)),
                         expected: Some(Value::Long(8)),
                     }
-                })]
+                })],
+                truncated: false,
             })
         );
     }
@@ -1012,7 +1244,7 @@ mod digits_bigdecimal {
                     fraction: 2,
                 },
             )
-            .result();
+            .result_with_warnings();
 
         assert_eq!(
             result,
@@ -1020,143 +1252,906 @@ mod digits_bigdecimal {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-digits-fraction".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "account_balance".into(),
                         actual: Some(Value::Long(3)),
                         expected: Some(Value::Long(2)),
                     }
-                })]
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_digits_of_bigdecimal_with_too_many_integer_and_fraction_digits() {
+        let account_balance = BigDecimal::from_str("123456780.995").unwrap();
+
+        let result = account_balance
+            .validate(
+                "account_balance",
+                &Digits {
+                    integer: 8,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-digits-integer".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "account_balance".into(),
+                            actual: Some(Value::Long(9)),
+                            expected: Some(Value::Long(8)),
+                        }
+                    }),
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-digits-fraction".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "account_balance".into(),
+                            actual: Some(Value::Long(3)),
+                            expected: Some(Value::Long(2)),
+                        }
+                    })
+                ],
+                truncated: false,
+            })
+        );
+    }
+}
+
+mod digits_primitive {
+    use super::*;
+
+    #[test]
+    fn validate_digits_of_an_integer_that_is_compliant() {
+        let quantity = -12_345_i64;
+
+        let result = quantity
+            .validate(
+                "quantity",
+                &Digits {
+                    integer: 5,
+                    fraction: 0,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), -12_345);
+    }
+
+    #[test]
+    fn validate_digits_of_an_integer_with_too_many_integer_digits() {
+        let quantity = 123_456_u32;
+
+        let result = quantity
+            .validate(
+                "quantity",
+                &Digits {
+                    integer: 5,
+                    fraction: 0,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-digits-integer".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "quantity".into(),
+                        actual: Some(Value::Long(6)),
+                        expected: Some(Value::Long(5)),
+                    }
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_digits_of_a_double_uses_the_shortest_representation() {
+        let weight = 0.1_f64 + 0.2_f64;
+
+        let result = weight
+            .validate(
+                "weight",
+                &Digits {
+                    integer: 3,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-digits-fraction".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "weight".into(),
+                        actual: Some(Value::Long(17)),
+                        expected: Some(Value::Long(2)),
+                    }
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_digits_of_a_float_that_is_compliant() {
+        let weight = 12.75_f32;
+
+        let result = weight
+            .validate(
+                "weight",
+                &Digits {
+                    integer: 2,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), 12.75);
+    }
+
+    #[test]
+    fn validate_digits_of_a_double_that_is_not_a_number() {
+        let weight = f64::NAN;
+
+        let result = weight
+            .validate(
+                "weight",
+                &Digits {
+                    integer: 3,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-digits-number".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "weight".into(),
+                        actual: None,
+                        expected: None,
+                    }
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn the_integer_digits_of_an_integer_are_the_length_of_its_decimal_representation(
+            value in any::<i64>()
+        ) {
+            let num_digits = value.unsigned_abs().to_string().len() as u64;
+
+            prop_assert_eq!(value.integer_digits(), num_digits);
+            prop_assert_eq!(value.fraction_digits(), 0);
+        }
+    }
+}
+
+mod digits_string {
+    use super::*;
+
+    #[test]
+    fn validate_digits_of_a_decimal_string_that_is_compliant() {
+        let amount = "-12345678.90".to_string();
+
+        let result = amount
+            .validate(
+                "amount",
+                &Digits {
+                    integer: 8,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), "-12345678.90");
+    }
+
+    #[test]
+    fn validate_digits_of_a_decimal_string_with_too_many_integer_and_fraction_digits() {
+        let amount = "123456780.995".to_string();
+
+        let result = amount
+            .validate(
+                "amount",
+                &Digits {
+                    integer: 8,
+                    fraction: 2,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-digits-integer".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "amount".into(),
+                            actual: Some(Value::Long(9)),
+                            expected: Some(Value::Long(8)),
+                        }
+                    }),
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-digits-fraction".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "amount".into(),
+                            actual: Some(Value::Long(3)),
+                            expected: Some(Value::Long(2)),
+                        }
+                    })
+                ],
+                truncated: false,
             })
         );
     }
 
     #[test]
-    fn validate_digits_of_bigdecimal_with_too_many_integer_and_fraction_digits() {
-        let account_balance = BigDecimal::from_str("123456780.995").unwrap();
+    fn leading_zeros_of_a_decimal_string_are_not_counted() {
+        assert_eq!("007.50".integer_digits(), 1);
+        assert_eq!("007.50".fraction_digits(), 2);
+        assert_eq!("0.05".integer_digits(), 0);
+        assert_eq!("0".integer_digits(), 1);
+    }
+
+    #[test]
+    fn validate_digits_of_a_malformed_decimal_string() {
+        for amount in &["", "12a.5", "1.", ".5", "1e5", "1.2.3", "--1", " 1"] {
+            let result = amount
+                .to_string()
+                .validate(
+                    "amount",
+                    &Digits {
+                        integer: 8,
+                        fraction: 2,
+                    },
+                )
+                .result_with_warnings();
+
+            assert_eq!(
+                result,
+                Err(ValidationError {
+                    message: None,
+                    violations: vec![ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-digits-number".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "amount".into(),
+                            actual: None,
+                            expected: None,
+                        }
+                    })],
+                    truncated: false,
+                })
+            );
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+mod digits_bigint {
+    use super::*;
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_digits_of_bigint_with_too_many_integer_digits() {
+        let population = BigInt::from_str("-12345678901234567890").unwrap();
+
+        let result = population
+            .validate(
+                "population",
+                &Digits {
+                    integer: 19,
+                    fraction: 0,
+                },
+            )
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Field(InvalidValue {
+                    code: "invalid-digits-integer".into(),
+                    severity: Severity::Error,
+                    field: Field {
+                        name: "population".into(),
+                        actual: Some(Value::Long(20)),
+                        expected: Some(Value::Long(19)),
+                    }
+                })],
+                truncated: false,
+            })
+        );
+    }
+}
+
+mod must_match {
+    use super::*;
+    use crate::InvalidRelation;
+
+    proptest! {
+        #[test]
+        fn validate_must_match_of_two_equal_strings(
+            input in "\\PC*"
+        ) {
+            let password = input.clone();
+            let repeated = input.clone();
+
+            let result = (password, repeated).validate(("password", "repeated"), &MustMatch).result_with_warnings();
+
+            prop_assert_eq!(result.unwrap().0.unwrap(), (input.clone(), input));
+        }
+
+        #[test]
+        fn validate_must_match_of_two_strings_that_are_not_equal(
+            input in "\\PC*",
+            diff in "\\PC+",
+        ) {
+            let password = input.clone();
+            let repeated = input.clone() + &diff;
+
+            let result = (password, repeated).validate(("password", "repeated"), &MustMatch).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-must-match".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "password".into(),
+                        actual: Some(Value::String(input.clone())),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "repeated".into(),
+                        actual: Some(Value::String(input + &diff)),
+                        expected: None,
+                    },
+                })],
+
+                truncated: false,
+            }));
+        }
+
+        #[test]
+        fn validate_must_match_of_two_equal_integer(
+            input in any::<i32>()
+        ) {
+            let code1 = input;
+            let code2 = input;
+
+            let result = (code1, code2).validate(("code1", "code2"), &MustMatch).result_with_warnings();
+
+            prop_assert_eq!(result.unwrap().0.unwrap(), (input, input));
+        }
+
+        #[test]
+        fn validate_must_match_of_two_different_integer(
+            input in any::<i32>(),
+            diff in any::<i32>(),
+        ) {
+            let code1 = input / 2;
+            let code2 = input / 2 + diff / 2;
+
+            let result = (code1, code2).validate(("code1", "code2"), &MustMatch).result_with_warnings();
+
+            prop_assert_eq!(result, Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-must-match".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "code1".into(),
+                        actual: Some(Value::Integer(code1)),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "code2".into(),
+                        actual: Some(Value::Integer(code2)),
+                        expected: None,
+                    },
+                })],
+
+                truncated: false,
+            }));
+        }
+    }
+}
+
+mod must_not_match {
+    use super::*;
+
+    #[test]
+    fn validate_must_not_match_for_two_strings_that_are_different() {
+        let passwords = ("s3cr3t".to_string(), "n3w-s3cr3t".to_string());
+
+        let result = passwords
+            .validate(("old_password", "new_password"), &MustNotMatch)
+            .result_with_warnings();
+
+        assert_eq!(
+            result.unwrap().0.unwrap(),
+            ("s3cr3t".to_string(), "n3w-s3cr3t".to_string())
+        );
+    }
+
+    #[test]
+    fn validate_must_not_match_for_two_strings_that_are_equal() {
+        let passwords = ("s3cr3t".to_string(), "s3cr3t".to_string());
+
+        let result = passwords
+            .validate(("old_password", "new_password"), &MustNotMatch)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-must-not-match".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "old_password".into(),
+                        actual: Some(Value::String("s3cr3t".into())),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "new_password".into(),
+                        actual: Some(Value::String("s3cr3t".into())),
+                        expected: None,
+                    },
+                })],
+                truncated: false,
+            })
+        );
+    }
+}
+
+mod must_compare {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn validate_must_compare_for_two_integers_that_are_compliant(
+            (value1, value2) in (i32::MIN..i32::MAX)
+                .prop_flat_map(|val| (Just(val), val + 1..=i32::MAX) ),
+        ) {
+            let result = (value1, value2)
+                .validate(("start", "end"), &MustCompare::LessThan)
+                .result_with_warnings();
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value1, value2));
+
+            let result = (value1, value2)
+                .validate(("start", "end"), &MustCompare::LessOrEqual)
+                .result_with_warnings();
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value1, value2));
+
+            let result = (value2, value1)
+                .validate(("end", "start"), &MustCompare::GreaterThan)
+                .result_with_warnings();
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value2, value1));
+
+            let result = (value2, value1)
+                .validate(("end", "start"), &MustCompare::GreaterOrEqual)
+                .result_with_warnings();
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value2, value1));
+        }
+    }
+
+    #[test]
+    fn validate_must_compare_for_two_equal_integers() {
+        let less_or_equal = (42, 42).validate(("start", "end"), &MustCompare::LessOrEqual);
+        let greater_or_equal = (42, 42).validate(("start", "end"), &MustCompare::GreaterOrEqual);
+
+        assert_eq!(
+            less_or_equal.result_with_warnings().unwrap().0.unwrap(),
+            (42, 42)
+        );
+        assert_eq!(
+            greater_or_equal.result_with_warnings().unwrap().0.unwrap(),
+            (42, 42)
+        );
+    }
+
+    #[test]
+    fn validate_must_compare_greater_or_equal_for_two_integers_that_are_not_compliant() {
+        let result = (1, 2)
+            .validate(("end", "start"), &MustCompare::GreaterOrEqual)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-must-compare-greater-or-equal".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "end".into(),
+                        actual: Some(Value::Integer(1)),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "start".into(),
+                        actual: Some(Value::Integer(2)),
+                        expected: None,
+                    },
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_must_compare_less_than_for_two_equal_integers() {
+        let result = (7, 7)
+            .validate(("start", "end"), &MustCompare::LessThan)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-must-compare-less-than".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "start".into(),
+                        actual: Some(Value::Integer(7)),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "end".into(),
+                        actual: Some(Value::Integer(7)),
+                        expected: None,
+                    },
+                })],
+                truncated: false,
+            })
+        );
+    }
+}
+
+mod required_if {
+    use super::*;
+
+    #[test]
+    fn validate_required_if_where_both_fields_are_present_or_the_first_is_missing() {
+        let both = (Some("US".to_string()), Some("CA".to_string()));
+        let only_second = (None::<String>, Some("CA".to_string()));
+        let none = (None::<String>, None::<String>);
+
+        assert!(both
+            .validate(("country", "state"), &RequiredIf)
+            .result_with_warnings()
+            .is_ok());
+        assert!(only_second
+            .validate(("country", "state"), &RequiredIf)
+            .result_with_warnings()
+            .is_ok());
+        assert!(none
+            .validate(("country", "state"), &RequiredIf)
+            .result_with_warnings()
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_required_if_where_the_second_field_is_missing() {
+        let fields = (Some("US".to_string()), None::<String>);
+
+        let result = fields
+            .validate(("country", "state"), &RequiredIf)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-required-if".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "country".into(),
+                        actual: Some(Value::String("US".into())),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "state".into(),
+                        actual: None,
+                        expected: None,
+                    },
+                })],
+                truncated: false,
+            })
+        );
+    }
+}
+
+mod exclusive_or {
+    use super::*;
+
+    #[test]
+    fn validate_exclusive_or_where_exactly_one_field_is_present() {
+        let first = (Some(42), None::<String>);
+        let second = (None::<i32>, Some("acme".to_string()));
+
+        assert!(first
+            .validate(("customer_id", "company"), &ExclusiveOr)
+            .result_with_warnings()
+            .is_ok());
+        assert!(second
+            .validate(("customer_id", "company"), &ExclusiveOr)
+            .result_with_warnings()
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_exclusive_or_where_both_fields_are_present() {
+        let fields = (Some(42), Some("acme".to_string()));
+
+        let result = fields
+            .validate(("customer_id", "company"), &ExclusiveOr)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![ConstraintViolation::Relation(InvalidRelation {
+                    code: "invalid-exclusive-or".into(),
+                    severity: Severity::Error,
+                    field1: Field {
+                        name: "customer_id".into(),
+                        actual: Some(Value::Integer(42)),
+                        expected: None,
+                    },
+                    field2: Field {
+                        name: "company".into(),
+                        actual: Some(Value::String("acme".into())),
+                        expected: None,
+                    },
+                })],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_exclusive_or_where_none_of_the_fields_is_present() {
+        let fields = (None::<i32>, None::<String>);
+
+        let violations = fields.validate_ref(("customer_id", "company"), &ExclusiveOr);
+
+        assert_eq!(
+            violations,
+            vec![ConstraintViolation::Relation(InvalidRelation {
+                code: "invalid-exclusive-or".into(),
+                severity: Severity::Error,
+                field1: Field {
+                    name: "customer_id".into(),
+                    actual: None,
+                    expected: None,
+                },
+                field2: Field {
+                    name: "company".into(),
+                    actual: None,
+                    expected: None,
+                },
+            })]
+        );
+    }
+}
+
+mod at_least_one_of {
+    use super::*;
+
+    #[test]
+    fn validate_at_least_one_of_where_one_value_is_present() {
+        let contact = (
+            None::<String>,
+            Some("jane@example.com".to_string()),
+            None::<String>,
+        );
+
+        let result = contact
+            .validate(["phone", "email", "address"], &AtLeastOneOf)
+            .result_with_warnings();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn validate_at_least_one_of_where_no_value_is_present() {
+        let contact = (None::<String>, None::<String>, None::<String>);
 
-        let result = account_balance
-            .validate(
-                "account_balance",
-                &Digits {
-                    integer: 8,
-                    fraction: 2,
-                },
-            )
-            .result();
+        let result = contact
+            .validate(["phone", "email", "address"], &AtLeastOneOf)
+            .result_with_warnings();
 
         assert_eq!(
             result,
             Err(ValidationError {
                 message: None,
-                violations: vec![
-                    ConstraintViolation::Field(InvalidValue {
-                        code: "invalid-digits-integer".into(),
-                        field: Field {
-                            name: "account_balance".into(),
-                            actual: Some(Value::Long(9)),
-                            expected: Some(Value::Long(8)),
-                        }
-                    }),
-                    ConstraintViolation::Field(InvalidValue {
-                        code: "invalid-digits-fraction".into(),
-                        field: Field {
-                            name: "account_balance".into(),
-                            actual: Some(Value::Long(3)),
-                            expected: Some(Value::Long(2)),
-                        }
-                    })
-                ]
+                violations: vec![ConstraintViolation::FieldSet(InvalidFieldSet {
+                    code: "invalid-at-least-one-of".into(),
+                    severity: Severity::Error,
+                    fields: vec![
+                        Field {
+                            name: "phone".into(),
+                            actual: None,
+                            expected: None,
+                        },
+                        Field {
+                            name: "email".into(),
+                            actual: None,
+                            expected: None,
+                        },
+                        Field {
+                            name: "address".into(),
+                            actual: None,
+                            expected: None,
+                        },
+                    ],
+                })],
+                truncated: false,
             })
         );
     }
-}
 
-mod must_match {
-    use super::*;
-    use crate::InvalidRelation;
+    #[test]
+    #[should_panic(expected = "the number of field names must be equal to the number of values")]
+    fn validate_at_least_one_of_with_less_field_names_than_values_panics() {
+        let contact = (None::<String>, None::<String>, None::<String>);
 
-    proptest! {
-        #[test]
-        fn validate_must_match_of_two_equal_strings(
-            input in "\\PC*"
-        ) {
-            let password = input.clone();
-            let repeated = input.clone();
+        let _ = contact.validate_ref(["phone", "email"], &AtLeastOneOf);
+    }
 
-            let result = (password, repeated).validate(("password", "repeated"), &MustMatch).result();
+    #[test]
+    #[should_panic(expected = "the number of field names must be equal to the number of values")]
+    fn validate_at_least_one_of_with_more_field_names_than_values_panics() {
+        let contact = (Some("+1 555 0100".to_string()), None::<String>);
 
-            prop_assert_eq!(result.unwrap().unwrap(), (input.clone(), input));
-        }
+        let _ = contact.validate_ref(["phone", "email", "address"], &AtLeastOneOf);
+    }
+}
 
-        #[test]
-        fn validate_must_match_of_two_strings_that_are_not_equal(
-            input in "\\PC*",
-            diff in "\\PC+",
-        ) {
-            let password = input.clone();
-            let repeated = input.clone() + &diff;
+mod at_most_one_of {
+    use super::*;
 
-            let result = (password, repeated).validate(("password", "repeated"), &MustMatch).result();
+    #[test]
+    fn validate_at_most_one_of_where_no_or_one_value_is_present() {
+        let none = vec![None::<i32>, None, None];
+        let one = vec![None, Some(3), None];
+
+        assert!(none
+            .validate(vec!["a", "b", "c"], &AtMostOneOf)
+            .result_with_warnings()
+            .is_ok());
+        assert!(one
+            .validate(vec!["a", "b", "c"], &AtMostOneOf)
+            .result_with_warnings()
+            .is_ok());
+    }
 
-            prop_assert_eq!(result, Err(ValidationError {
-                message: None,
-                violations: vec![ConstraintViolation::Relation(InvalidRelation {
-                    code: "invalid-must-match".into(),
-                    field1: Field {
-                        name: "password".into(),
-                        actual: Some(Value::String(input.clone())),
+    #[test]
+    fn validate_at_most_one_of_where_two_values_are_present() {
+        let discounts = (Some(10), Some("SUMMER".to_string()));
+
+        let violations = discounts.validate_ref(["percent", "voucher"], &AtMostOneOf);
+
+        assert_eq!(
+            violations,
+            vec![ConstraintViolation::FieldSet(InvalidFieldSet {
+                code: "invalid-at-most-one-of".into(),
+                severity: Severity::Error,
+                fields: vec![
+                    Field {
+                        name: "percent".into(),
+                        actual: Some(Value::Integer(10)),
                         expected: None,
                     },
-                    field2: Field {
-                        name: "repeated".into(),
-                        actual: Some(Value::String(input + &diff)),
+                    Field {
+                        name: "voucher".into(),
+                        actual: Some(Value::String("SUMMER".into())),
                         expected: None,
                     },
-                })]
-
-            }));
-        }
-
-        #[test]
-        fn validate_must_match_of_two_equal_integer(
-            input in any::<i32>()
-        ) {
-            let code1 = input;
-            let code2 = input;
+                ],
+            })]
+        );
+    }
+}
 
-            let result = (code1, code2).validate(("code1", "code2"), &MustMatch).result();
+mod all_or_none {
+    use super::*;
 
-            prop_assert_eq!(result.unwrap().unwrap(), (input, input));
-        }
+    #[test]
+    fn validate_all_or_none_where_all_or_no_values_are_present() {
+        let all = (
+            Some("Main St".to_string()),
+            Some(1234),
+            Some("Springfield".to_string()),
+        );
+        let none = (None::<String>, None::<i32>, None::<String>);
+
+        assert!(all
+            .validate(["street", "zip", "city"], &AllOrNone)
+            .result_with_warnings()
+            .is_ok());
+        assert!(none
+            .validate(["street", "zip", "city"], &AllOrNone)
+            .result_with_warnings()
+            .is_ok());
+    }
 
-        #[test]
-        fn validate_must_match_of_two_different_integer(
-            input in any::<i32>(),
-            diff in any::<i32>(),
-        ) {
-            let code1 = input / 2;
-            let code2 = input / 2 + diff / 2;
+    #[test]
+    fn validate_all_or_none_where_some_values_are_missing() {
+        let address = (Some("Main St".to_string()), None::<i32>, None::<String>);
 
-            let result = (code1, code2).validate(("code1", "code2"), &MustMatch).result();
+        let result = address
+            .validate(["street", "zip", "city"], &AllOrNone)
+            .result_with_warnings();
 
-            prop_assert_eq!(result, Err(ValidationError {
+        assert_eq!(
+            result,
+            Err(ValidationError {
                 message: None,
-                violations: vec![ConstraintViolation::Relation(InvalidRelation {
-                    code: "invalid-must-match".into(),
-                    field1: Field {
-                        name: "code1".into(),
-                        actual: Some(Value::Integer(code1)),
-                        expected: None,
-                    },
-                    field2: Field {
-                        name: "code2".into(),
-                        actual: Some(Value::Integer(code2)),
-                        expected: None,
-                    },
-                })]
-
-            }));
-        }
+                violations: vec![ConstraintViolation::FieldSet(InvalidFieldSet {
+                    code: "invalid-all-or-none".into(),
+                    severity: Severity::Error,
+                    fields: vec![
+                        Field {
+                            name: "street".into(),
+                            actual: Some(Value::String("Main St".into())),
+                            expected: None,
+                        },
+                        Field {
+                            name: "zip".into(),
+                            actual: None,
+                            expected: None,
+                        },
+                        Field {
+                            name: "city".into(),
+                            actual: None,
+                            expected: None,
+                        },
+                    ],
+                })],
+                truncated: false,
+            })
+        );
     }
 }
 
@@ -1175,9 +2170,9 @@ mod must_define_range {
                     ("value1", "value2"),
                     &MustDefineRange::Inclusive,
                 )
-                .result();
+                .result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), (value1, value2));
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value1, value2));
         }
 
         #[test]
@@ -1190,12 +2185,13 @@ mod must_define_range {
                     ("value1", "value2"),
                     &MustDefineRange::Inclusive,
                 )
-                .result();
+                .result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Relation(InvalidRelation {
                     code: "invalid-must-define-range-inclusive".into(),
+                    severity: Severity::Error,
                     field1: Field {
                         name: "value1".into(),
                         actual: Some(Value::Integer(value1)),
@@ -1206,8 +2202,9 @@ mod must_define_range {
                         actual: Some(Value::Integer(value2)),
                         expected: None,
                     },
-                })]
+                })],
 
+                truncated: false,
             }));
         }
 
@@ -1221,9 +2218,9 @@ mod must_define_range {
                     ("value1", "value2"),
                     &MustDefineRange::Exclusive,
                 )
-                .result();
+                .result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), (value1, value2));
+            prop_assert_eq!(result.unwrap().0.unwrap(), (value1, value2));
         }
 
         #[test]
@@ -1236,12 +2233,13 @@ mod must_define_range {
                     ("value1", "value2"),
                     &MustDefineRange::Exclusive,
                 )
-                .result();
+                .result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Relation(InvalidRelation {
                     code: "invalid-must-define-range-exclusive".into(),
+                    severity: Severity::Error,
                     field1: Field {
                         name: "value1".into(),
                         actual: Some(Value::Integer(value1)),
@@ -1252,8 +2250,9 @@ mod must_define_range {
                         actual: Some(Value::Integer(value2)),
                         expected: None,
                     },
-                })]
+                })],
 
+                truncated: false,
             }));
         }
     }
@@ -1280,9 +2279,9 @@ mod must_define_range_naive_date {
                     ("valid_from", "valid_until"),
                     &MustDefineRange::Inclusive,
                 )
-                .result();
+                .result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), (valid_from, valid_until));
+            prop_assert_eq!(result.unwrap().0.unwrap(), (valid_from, valid_until));
         }
 
         #[test]
@@ -1299,12 +2298,13 @@ mod must_define_range_naive_date {
                     ("valid_from", "valid_until"),
                     &MustDefineRange::Inclusive,
                 )
-                .result();
+                .result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Relation(InvalidRelation {
                     code: "invalid-must-define-range-inclusive".into(),
+                    severity: Severity::Error,
                     field1: Field {
                         name: "valid_from".into(),
                         actual: Some(Value::Date(valid_from)),
@@ -1315,8 +2315,9 @@ mod must_define_range_naive_date {
                         actual: Some(Value::Date(valid_until)),
                         expected: None,
                     },
-                })]
+                })],
 
+                truncated: false,
             }));
         }
 
@@ -1334,9 +2335,9 @@ mod must_define_range_naive_date {
                     ("valid_from", "valid_until"),
                     &MustDefineRange::Exclusive,
                 )
-                .result();
+                .result_with_warnings();
 
-            prop_assert_eq!(result.unwrap().unwrap(), (valid_from, valid_until));
+            prop_assert_eq!(result.unwrap().0.unwrap(), (valid_from, valid_until));
         }
 
         #[test]
@@ -1353,12 +2354,13 @@ mod must_define_range_naive_date {
                     ("valid_from", "valid_until"),
                     &MustDefineRange::Exclusive,
                 )
-                .result();
+                .result_with_warnings();
 
             prop_assert_eq!(result, Err(ValidationError {
                 message: None,
                 violations: vec![ConstraintViolation::Relation(InvalidRelation {
                     code: "invalid-must-define-range-exclusive".into(),
+                    severity: Severity::Error,
                     field1: Field {
                         name: "valid_from".into(),
                         actual: Some(Value::Date(valid_from)),
@@ -1369,13 +2371,243 @@ mod must_define_range_naive_date {
                         actual: Some(Value::Date(valid_until)),
                         expected: None,
                     },
-                })]
+                })],
 
+                truncated: false,
             }));
         }
     }
 }
 
+mod const_generic {
+    use super::*;
+    use crate::{invalid_value, Validated};
+
+    #[test]
+    fn validate_max_len_on_a_compliant_string() {
+        let result = String::from("abcde")
+            .validate("name", &MaxLen::<5>)
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), "abcde");
+    }
+
+    #[test]
+    fn validate_max_len_on_a_too_long_vec() {
+        let result = vec![1, 2, 3]
+            .validate("items", &MaxLen::<2>)
+            .result_with_warnings();
+
+        assert_eq!(
+            result.unwrap_err().violations,
+            vec![invalid_value(INVALID_LENGTH_MAX, "items", 3, 2)]
+        );
+    }
+
+    #[test]
+    fn validate_min_max_len_reports_the_same_violations_as_length_min_max() {
+        for value in &["ab", "abc", "abcdefgh", "abcdefghi"] {
+            assert_eq!(
+                value.validate_ref("code", &MinMaxLen::<3, 8>),
+                value.validate_ref("code", &Length::MinMax(3, 8)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn validate_max_chars_counts_characters() {
+        let name = String::from("Jürgen");
+
+        assert_eq!(name.validate_ref("name", &MaxChars::<6>), vec![]);
+        assert_eq!(
+            name.validate_ref("name", &MaxChars::<5>),
+            vec![invalid_value(INVALID_CHAR_COUNT_MAX, "name", 6, 5)]
+        );
+    }
+
+    #[test]
+    fn validate_closed_range_on_integers() {
+        assert_eq!(42u8.validate_ref("age", &ClosedRange::<1, 120>), vec![]);
+        assert_eq!(
+            0i32.validate_ref("age", &ClosedRange::<1, 120>),
+            vec![invalid_value(INVALID_BOUND_CLOSED_MIN, "age", 0, 1)]
+        );
+        assert_eq!(
+            121i64.validate_ref("age", &ClosedRange::<1, 120>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MAX,
+                "age",
+                121i64,
+                120i64
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_closed_range_on_a_float() {
+        assert_eq!(
+            0.5f64.validate_ref("ratio", &ClosedRange::<1, 9>),
+            vec![invalid_value(INVALID_BOUND_CLOSED_MIN, "ratio", 0.5, 1.)]
+        );
+    }
+
+    #[test]
+    fn closed_range_bounds_out_of_the_range_of_the_type_are_not_clamped() {
+        assert_eq!(
+            255u8.validate_ref("level", &ClosedRange::<-5, 1000>),
+            vec![]
+        );
+        assert_eq!(0u8.validate_ref("level", &ClosedRange::<-5, 1000>), vec![]);
+        assert_eq!(
+            100i8.validate_ref("level", &ClosedRange::<-500, 50>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MAX,
+                "level",
+                100i8,
+                50i8
+            )]
+        );
+        assert_eq!(
+            (-100i8).validate_ref("level", &ClosedRange::<-500, 50>),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn closed_range_that_is_out_of_the_range_of_the_type_rejects_all_values() {
+        assert_eq!(
+            255u8.validate_ref("level", &ClosedRange::<300, 400>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MIN,
+                "level",
+                255u8,
+                Value::Long(300)
+            )]
+        );
+        assert_eq!(
+            0u8.validate_ref("level", &ClosedRange::<-10, -5>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MAX,
+                "level",
+                0u8,
+                Value::Long(-5)
+            )]
+        );
+        assert_eq!(
+            i8::MAX.validate_ref("level", &ClosedRange::<{ i8::MAX as i64 + 1 }, 1000>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MIN,
+                "level",
+                i8::MAX,
+                Value::Long(128)
+            )]
+        );
+    }
+
+    #[test]
+    fn closed_range_with_min_greater_than_max_rejects_all_values() {
+        assert_eq!(
+            5u8.validate_ref("level", &ClosedRange::<10, 1>),
+            vec![invalid_value(INVALID_BOUND_CLOSED_MIN, "level", 5u8, 10u8)]
+        );
+        assert_eq!(
+            20u8.validate_ref("level", &ClosedRange::<10, 1>),
+            vec![invalid_value(INVALID_BOUND_CLOSED_MAX, "level", 20u8, 1u8)]
+        );
+    }
+
+    #[test]
+    fn closed_range_on_floats_compares_with_the_declared_bounds() {
+        // 2^24 + 1 can not be represented as f32
+        assert_eq!(
+            16_777_216f32.validate_ref("amount", &ClosedRange::<16_777_217, 16_777_300>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MIN,
+                "amount",
+                16_777_216f32,
+                Value::Long(16_777_217)
+            )]
+        );
+        // i64::MAX is rounded up to 2^63 as f64
+        assert_eq!(
+            9_223_372_036_854_775_808f64.validate_ref("amount", &ClosedRange::<0, { i64::MAX }>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MAX,
+                "amount",
+                9_223_372_036_854_775_808f64,
+                Value::Long(i64::MAX)
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_min_len_and_min_chars() {
+        assert_eq!(
+            "ab".validate_ref("code", &MinLen::<3>),
+            vec![invalid_value(INVALID_LENGTH_MIN, "code", 2, 3)]
+        );
+        assert_eq!(
+            "Jürgen".validate_ref("name", &MinChars::<7>),
+            vec![invalid_value(INVALID_CHAR_COUNT_MIN, "name", 6, 7)]
+        );
+        assert_eq!("Jürgen".validate_ref("name", &MinMaxChars::<2, 6>), vec![]);
+    }
+
+    #[test]
+    fn validate_min_and_max_compare_with_the_declared_bound() {
+        assert_eq!(0u8.validate_ref("level", &Min::<-1>), vec![]);
+        assert_eq!(
+            0u8.validate_ref("level", &Min::<1>),
+            vec![invalid_value(INVALID_BOUND_CLOSED_MIN, "level", 0u8, 1u8)]
+        );
+        assert_eq!(255u8.validate_ref("level", &Max::<256>), vec![]);
+        assert_eq!(
+            0.5f32.validate_ref("ratio", &Max::<0>),
+            vec![invalid_value(
+                INVALID_BOUND_CLOSED_MAX,
+                "ratio",
+                0.5f32,
+                0f32
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_max_digits_reports_the_same_violations_as_digits() {
+        let digits = Digits {
+            integer: 2,
+            fraction: 1,
+        };
+        for value in &[1.5, 12.25, 123.5, 123.25] {
+            assert_eq!(
+                value.validate_ref("price", &MaxDigits::<2, 1>),
+                value.validate_ref("price", &digits),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn const_generic_constraints_can_be_used_as_type_of_validated() {
+        type Rating = Validated<ClosedRange<1, 5>, u8>;
+
+        fn rate(rating: Rating) -> u8 {
+            *rating
+        }
+
+        let rating: Rating = 4u8
+            .validate("rating", &ClosedRange)
+            .result_with_warnings()
+            .unwrap()
+            .0;
+
+        assert_eq!(rate(rating), 4);
+    }
+}
+
 #[cfg(feature = "regex")]
 mod pattern {
     use super::*;
@@ -1391,9 +2623,9 @@ mod pattern {
 
         let result = email_address
             .validate("email_address", &basic_email_pattern)
-            .result();
+            .result_with_warnings();
 
-        assert_eq!(result.unwrap().unwrap(), "jane.doe@email.net");
+        assert_eq!(result.unwrap().0.unwrap(), "jane.doe@email.net");
     }
 
     #[test]
@@ -1406,7 +2638,7 @@ mod pattern {
 
         let result = email_address
             .validate("email_address", &basic_email_pattern)
-            .result();
+            .result_with_warnings();
 
         assert_eq!(
             result.unwrap_err(),
@@ -1414,6 +2646,7 @@ mod pattern {
                 message: None,
                 violations: vec![ConstraintViolation::Field(InvalidValue {
                     code: "invalid-pattern".into(),
+                    severity: Severity::Error,
                     field: Field {
                         name: "email_address".into(),
                         actual: Some(Value::String("jane*doe@email.net".into())),
@@ -1421,8 +2654,107 @@ mod pattern {
                             r#"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$"#.into()
                         )),
                     }
-                })]
+                })],
+                truncated: false,
             }
         );
     }
 }
+
+mod validate_ref {
+    use super::*;
+
+    #[test]
+    fn validate_ref_a_value_against_several_constraints_without_consuming_it() {
+        let username = String::from("jane.doe");
+
+        let mut violations = username.validate_ref("username", &NotEmpty);
+        violations.extend(username.validate_ref("username", &CharCount::MinMax(4, 20)));
+        violations.extend(username.validate_ref("username", &Length::Max(20)));
+
+        assert!(violations.is_empty());
+        assert_eq!(username, "jane.doe");
+    }
+
+    #[test]
+    fn validate_ref_returns_the_same_violations_as_validate() {
+        let age = 12;
+
+        let violations = age.validate_ref("age", &Bound::ClosedRange(13, 199));
+
+        assert_eq!(
+            Err(ValidationError {
+                message: None,
+                violations,
+                truncated: false,
+            }),
+            age.validate("age", &Bound::ClosedRange(13, 199))
+                .result_with_warnings()
+        );
+    }
+
+    #[test]
+    fn validate_ref_on_a_pair_of_related_fields() {
+        let passwords = ("s3cr3t".to_string(), "s3cr3T".to_string());
+
+        let violations = passwords.validate_ref(("password", "password2"), &MustMatch);
+
+        assert_eq!(
+            violations,
+            vec![ConstraintViolation::Relation(InvalidRelation {
+                code: "invalid-must-match".into(),
+                severity: Severity::Error,
+                field1: Field {
+                    name: "password".into(),
+                    actual: Some(Value::String("s3cr3t".into())),
+                    expected: None,
+                },
+                field2: Field {
+                    name: "password2".into(),
+                    actual: Some(Value::String("s3cr3T".into())),
+                    expected: None,
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn turn_the_violations_of_several_checks_into_a_failed_validation() {
+        let quantity = 0;
+
+        let mut violations = quantity.validate_ref("quantity", &NonZero);
+        violations.extend(quantity.validate_ref("quantity", &Bound::Min(1)));
+
+        let validation: Validation<(NonZero, Bound<i32>), i32> =
+            Validation::checked(quantity, violations);
+        let result = validation.result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-non-zero".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "quantity".into(),
+                            actual: Some(Value::Integer(0)),
+                            expected: None,
+                        }
+                    }),
+                    ConstraintViolation::Field(InvalidValue {
+                        code: "invalid-bound-closed-min".into(),
+                        severity: Severity::Error,
+                        field: Field {
+                            name: "quantity".into(),
+                            actual: Some(Value::Integer(0)),
+                            expected: Some(Value::Integer(1)),
+                        }
+                    }),
+                ],
+                truncated: false,
+            })
+        );
+    }
+}
@@ -1,21 +1,29 @@
 //! The core API of the `valid` crate
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 #[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::convert::{identity, TryFrom};
+use core::fmt;
+use core::fmt::{Debug, Display, Write};
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::ops::Deref;
 #[cfg(feature = "num-bigint")]
 use num_bigint::BigInt;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
-use std::convert::TryFrom;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
-use std::fmt::{Debug, Display, Write};
-use std::iter::FromIterator;
-use std::marker::PhantomData;
-use std::ops::Deref;
 
 /// A wrapper type to express that the value of type `T` has been validated by
 /// the constraint `C`.
@@ -26,6 +34,11 @@ use std::ops::Deref;
 ///
 /// It follows the new type pattern and can be de-referenced to a immutable
 /// reference to its inner value or unwrapped to get the owned inner value.
+/// The traits `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `Display`,
+/// `AsRef` and `Borrow` are delegated to the inner value regardless of the
+/// constraint type, so that a `Validated` value can for example be used as
+/// key in a `HashMap`. With the crate feature `serde1` it is serialized as
+/// its inner value.
 ///
 /// In an application we can make use of the type system to assure that only
 /// valid values of some type can be input to some function performing some
@@ -63,7 +76,8 @@ use std::ops::Deref;
 /// # }
 /// use valid::{Validated, Validate};
 ///
-/// let to_addr = "jane.doe@email.net".to_string().validate("email", Email).result()
+/// let (to_addr, _) = "jane.doe@email.net".to_string().validate("email", Email)
+///         .result_with_warnings()
 ///         .expect("valid email address");
 ///
 /// send_email(to_addr, "some message".into());
@@ -117,6 +131,99 @@ impl<C, T> Validated<C, T> {
     pub fn unwrap(self) -> T {
         self.1
     }
+
+    /// Validates the already validated value against another constraint.
+    ///
+    /// On success the `Validated` value of the returned validation proves
+    /// that the value complies to both constraints, the constraint `C` of this validated
+    /// value and the given constraint `D`. Thus functions can demand several
+    /// constraints at once like in `Validated<(CharCount, Pattern), String>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, Validate, Validated};
+    /// use valid::constraint::{Bound, NonZero};
+    ///
+    /// fn validate_percent(value: i32) -> Option<Validated<(Bound<i32>, NonZero), i32>> {
+    ///     let (validated, _) = value
+    ///         .validate("percent", &Bound::ClosedRange(0, 100))
+    ///         .result_with_warnings()
+    ///         .ok()?;
+    ///     let (validated, _) = validated
+    ///         .refine("percent", &NonZero)
+    ///         .result_with_warnings()
+    ///         .ok()?;
+    ///     Some(validated)
+    /// }
+    ///
+    /// assert_eq!(validate_percent(42).map(|percent| percent.unwrap()), Some(42));
+    /// assert!(validate_percent(0).is_none());
+    ///
+    /// let (validated, _) = 120
+    ///     .validate("percent", &Bound::Min(0))
+    ///     .result_with_warnings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     validated
+    ///         .refine("percent", &Bound::Max(100))
+    ///         .result_with_warnings()
+    ///         .unwrap_err()
+    ///         .violations,
+    ///     vec![invalid_value("invalid-bound-closed-max", "percent", 120, 100)]
+    /// );
+    /// ```
+    pub fn refine<D>(self, name: impl Into<FieldName>, constraint: &D) -> Validation<(C, D), T>
+    where
+        T: Validate<D, FieldName>,
+    {
+        self.1.validate(name, constraint).map(identity)
+    }
+}
+
+impl<C, D, T> Validated<(C, D), T> {
+    /// Weakens the proof of a validated value by dropping the constraint `D`.
+    ///
+    /// A value that complies to the constraints `C` and `D` complies to the
+    /// constraint `C` as well. This conversion lets a value that has been
+    /// validated against several constraints be passed to functions that
+    /// demand only some of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{Validate, Validated};
+    /// use valid::constraint::{CharCount, NotEmpty};
+    ///
+    /// fn greet(name: Validated<NotEmpty, String>) -> String {
+    ///     format!("Hello {}!", *name)
+    /// }
+    ///
+    /// let (name, _) = String::from("Jane")
+    ///     .validate("name", &NotEmpty)
+    ///     .result_with_warnings()
+    ///     .unwrap();
+    /// let (name, _) = name
+    ///     .refine("name", &CharCount::Max(20))
+    ///     .result_with_warnings()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(greet(name.weaken()), "Hello Jane!");
+    /// ```
+    pub fn weaken(self) -> Validated<C, T> {
+        Validated(PhantomData, self.1)
+    }
+
+    /// Swaps the order of the constraints in the proof of a validated value.
+    ///
+    /// Together with the [`weaken`] method any of the two constraints can be
+    /// dropped.
+    ///
+    /// [`weaken`]: #method.weaken
+    pub fn swap(self) -> Validated<(D, C), T> {
+        Validated(PhantomData, self.1)
+    }
 }
 
 impl<C, T> Debug for Validated<C, T>
@@ -153,6 +260,75 @@ where
 
 impl<C, T> Copy for Validated<C, T> where T: Copy {}
 
+impl<C, T> PartialOrd for Validated<C, T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.1.partial_cmp(&other.1)
+    }
+}
+
+impl<C, T> Ord for Validated<C, T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<C, T> Hash for Validated<C, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+impl<C, T> Display for Validated<C, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.1, f)
+    }
+}
+
+impl<C, T, U> AsRef<U> for Validated<C, T>
+where
+    T: AsRef<U>,
+    U: ?Sized,
+{
+    fn as_ref(&self) -> &U {
+        self.1.as_ref()
+    }
+}
+
+impl<C, T> Borrow<T> for Validated<C, T> {
+    fn borrow(&self) -> &T {
+        &self.1
+    }
+}
+
+/// A `Validated` value is serialized as its inner value.
+///
+/// There is no implementation of `Deserialize` as a deserialized value is not
+/// validated. Deserialize the inner value and validate it instead.
+#[cfg(feature = "serde1")]
+impl<C, T> Serialize for Validated<C, T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.1.serialize(serializer)
+    }
+}
+
 impl<C, T> Deref for Validated<C, T> {
     type Target = T;
 
@@ -186,6 +362,45 @@ where
     fn validate(self, context: impl Into<S>, constraint: &C) -> Validation<C, Self>;
 }
 
+/// The validation function that validates a value by reference.
+///
+/// In contrast to the [`Validate`] trait the value is not consumed. This is
+/// useful to check one value against several constraints without cloning it
+/// for each constraint. The validation function returns the list of
+/// constraint violations found, which is empty if the value is valid.
+///
+/// The violations of several checks can be turned into a `Validation` of the
+/// owned value using the [`Validation::checked`] method.
+///
+/// # Examples
+///
+/// ```
+/// use valid::{ValidateRef, Validation};
+/// use valid::constraint::{CharCount, NotEmpty};
+///
+/// let username = String::from("jane.doe");
+///
+/// let mut violations = username.validate_ref("username", &NotEmpty);
+/// violations.extend(username.validate_ref("username", &CharCount::MinMax(4, 20)));
+///
+/// let validation: Validation<(NotEmpty, CharCount), String> =
+///     Validation::checked(username, violations);
+///
+/// assert_eq!(validation.result_with_warnings().unwrap().0.unwrap(), "jane.doe");
+/// ```
+///
+/// [`Validate`]: trait.Validate.html
+/// [`Validation::checked`]: struct.Validation.html#method.checked
+pub trait ValidateRef<C, S>
+where
+    S: Context,
+{
+    /// Validates this value for being compliant to the specified constraint
+    /// `C` in the given context `S` and returns the constraint violations
+    /// found.
+    fn validate_ref(&self, context: impl Into<S>, constraint: &C) -> Vec<ConstraintViolation>;
+}
+
 mod private {
     pub trait Sealed {}
 
@@ -263,6 +478,57 @@ impl RelatedFields {
     }
 }
 
+/// Represents a set of related fields as context for validation functions.
+/// It holds the names of the related fields that are validated.
+///
+/// In contrast to [`RelatedFields`] the number of fields is not limited to
+/// two. It is used by constraints on the relation of any number of fields,
+/// such as "at least one of phone, email or postal address must be given".
+///
+/// # Panics
+///
+/// The constraints validated in this context panic if the number of field
+/// names differs from the number of validated values.
+///
+/// [`RelatedFields`]: struct.RelatedFields.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedFieldSet(pub Vec<Cow<'static, str>>);
+
+impl Context for RelatedFieldSet {}
+
+impl<A> From<Vec<A>> for RelatedFieldSet
+where
+    A: Into<Cow<'static, str>>,
+{
+    fn from(values: Vec<A>) -> Self {
+        RelatedFieldSet(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<A, const N: usize> From<[A; N]> for RelatedFieldSet
+where
+    A: Into<Cow<'static, str>>,
+{
+    fn from(values: [A; N]) -> Self {
+        RelatedFieldSet::from(Vec::from(values))
+    }
+}
+
+impl Deref for RelatedFieldSet {
+    type Target = [Cow<'static, str>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl RelatedFieldSet {
+    /// Unwraps this related field set context and returns the field names
+    pub fn unwrap(self) -> Vec<Cow<'static, str>> {
+        self.0
+    }
+}
+
 /// Represents the state context for validation functions. Its value is the
 /// state information needed to execute the validation.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -291,10 +557,200 @@ impl<S> State<S> {
     }
 }
 
+/// Represents the context of a field whose validation needs some state
+/// information. It holds the name of the validated field and the state.
+///
+/// A business rule like "the username must not be taken yet" needs access to
+/// some state, e.g. a repository of registered users, but refers to a single
+/// field. In this context the violation of such a rule is reported as
+/// `ConstraintViolation::Field` with the name of the field, so that it can be
+/// presented to the user like any other invalid field value.
+///
+/// The context can be constructed from a tuple of the field name and the
+/// state.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use valid::{invalid_value, FieldState, Validate, Validation};
+///
+/// struct IsUniqueUsername;
+///
+/// impl<'a> Validate<IsUniqueUsername, FieldState<&'a HashSet<String>>> for String {
+///     fn validate(
+///         self,
+///         context: impl Into<FieldState<&'a HashSet<String>>>,
+///         _constraint: &IsUniqueUsername,
+///     ) -> Validation<IsUniqueUsername, Self> {
+///         let context = context.into();
+///         if context.contains(&self) {
+///             let expected = "a unique username".to_string();
+///             Validation::failure(vec![invalid_value(
+///                 "invalid-unique-username",
+///                 context.name().to_string(),
+///                 self,
+///                 expected,
+///             )])
+///         } else {
+///             Validation::success(self)
+///         }
+///     }
+/// }
+///
+/// let registered = vec!["jane.doe".to_string()].into_iter().collect::<HashSet<_>>();
+///
+/// let result = "jane.doe"
+///     .to_string()
+///     .validate(("username", &registered), &IsUniqueUsername)
+///     .result_with_warnings();
+///
+/// let error = result.unwrap_err();
+/// assert!(error.violations[0].is_for_field("username"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldState<S>(pub Cow<'static, str>, pub S);
+
+impl<S> Context for FieldState<S> {}
+
+impl<A, S> From<(A, S)> for FieldState<S>
+where
+    A: Into<Cow<'static, str>>,
+{
+    fn from((name, state): (A, S)) -> Self {
+        FieldState(name.into(), state)
+    }
+}
+
+impl<S> Deref for FieldState<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+impl<S> FieldState<S> {
+    /// Unwraps this field state context and returns the field name and the
+    /// state information
+    pub fn unwrap(self) -> (Cow<'static, str>, S) {
+        (self.0, self.1)
+    }
+
+    /// Returns a reference to the name of the field
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a reference to the state information
+    pub fn state(&self) -> &S {
+        &self.1
+    }
+}
+
+/// The policy that determines how many constraint violations a validation
+/// collects before it stops.
+///
+/// By default a validation collects all constraint violations it can find.
+/// For expensive validations or huge inputs where lots of values may be
+/// invalid it can be useful to stop after the first violation or after a
+/// certain number of violations. Only violations of severity [`Error`] are
+/// counted. Violations found after the limit has been reached are dropped and
+/// the resulting [`ValidationError`] is marked as truncated.
+///
+/// The mode of a validation is set with the [`Validation::with_mode`] method.
+/// It is honored by the combinator methods [`and`] and [`and_then`] and when
+/// validating the elements of a collection with [`Validation::each`]. Once the
+/// limit is reached the validations given to [`and`] as closures and the
+/// remaining elements of a collection are not validated anymore.
+///
+/// # Examples
+///
+/// ```
+/// use valid::{invalid_value, Validation, ValidationMode};
+///
+/// let validation: Validation<(), String> =
+///     Validation::failure(vec![invalid_value("invalid-length-min", "username", 2, 4)])
+///         .with_mode(ValidationMode::FailFast);
+///
+/// let age_validation: Validation<(), i32> =
+///     Validation::failure(vec![invalid_value("invalid-bound-min", "age", 12, 13)]);
+///
+/// let error = validation.and(age_validation).result_with_warnings().unwrap_err();
+///
+/// assert_eq!(
+///     error.violations,
+///     vec![invalid_value("invalid-length-min", "username", 2, 4)]
+/// );
+/// assert!(error.truncated);
+/// ```
+///
+/// [`Error`]: enum.Severity.html#variant.Error
+/// [`ValidationError`]: struct.ValidationError.html
+/// [`Validation::with_mode`]: struct.Validation.html#method.with_mode
+/// [`Validation::each`]: struct.Validation.html#method.each
+/// [`and`]: struct.Validation.html#method.and
+/// [`and_then`]: struct.Validation.html#method.and_then
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationMode {
+    /// collect all constraint violations
+    #[default]
+    Complete,
+    /// stop after the first constraint violation
+    FailFast,
+    /// stop after the given number of constraint violations
+    ///
+    /// A limit of 0 is treated like a limit of 1.
+    Limit(usize),
+}
+
+impl ValidationMode {
+    /// Returns the maximum number of errors that are collected in this mode
+    /// or `None` if the number of errors is not limited.
+    pub fn max_errors(self) -> Option<usize> {
+        match self {
+            ValidationMode::Complete => None,
+            ValidationMode::FailFast => Some(1),
+            ValidationMode::Limit(limit) => Some(limit.max(1)),
+        }
+    }
+
+    /// Returns whether the given list of constraint violations has reached
+    /// the limit of errors in this mode.
+    pub fn is_limit_reached(self, violations: &[ConstraintViolation]) -> bool {
+        match self.max_errors() {
+            None => false,
+            Some(limit) => violations.iter().filter(|v| v.is_error()).count() >= limit,
+        }
+    }
+
+    /// Removes all constraint violations after the last error permitted by
+    /// this mode from the given list. Returns whether any violation has been
+    /// removed.
+    fn truncate(self, violations: &mut Vec<ConstraintViolation>) -> bool {
+        let limit = match self.max_errors() {
+            None => return false,
+            Some(limit) => limit,
+        };
+        let mut errors = 0;
+        for index in 0..violations.len() {
+            if violations[index].is_error() {
+                errors += 1;
+                if errors == limit {
+                    let truncated = index + 1 < violations.len();
+                    violations.truncate(index + 1);
+                    return truncated;
+                }
+            }
+        }
+        false
+    }
+}
+
 #[derive(PartialEq)]
 enum InnerValidation<C, T> {
-    Success(PhantomData<C>, T),
-    Failure(Vec<ConstraintViolation>),
+    Success(PhantomData<C>, T, Vec<ConstraintViolation>),
+    Failure(Vec<ConstraintViolation>, bool),
 }
 
 /// State of an ongoing validation.
@@ -305,14 +761,26 @@ enum InnerValidation<C, T> {
 ///
 /// The result of a validation can be obtained by calling the [`result`] method.
 ///
+/// A validation is successful as long as no constraint violation of severity
+/// [`Error`] has been found. Violations of severity `Warning` or `Info` are
+/// carried along with a successful validation as warnings. They can be
+/// obtained by calling the [`result_with_warnings`] method instead of
+/// [`result`]. **The [`result`] method drops the warnings.**
+///
+/// How many constraint violations are accumulated is determined by the
+/// [`ValidationMode`] of the validation.
+///
 /// see the crate level documentation for details and examples on how to use
 /// the methods provided by this struct.
 ///
 /// [`and`]: #method.and
 /// [`and_then`]: #method.and_then
 /// [`result`]: #method.result
+/// [`result_with_warnings`]: #method.result_with_warnings
+/// [`Error`]: enum.Severity.html#variant.Error
+/// [`ValidationMode`]: enum.ValidationMode.html
 #[derive(PartialEq)]
-pub struct Validation<C, T>(InnerValidation<C, T>);
+pub struct Validation<C, T>(InnerValidation<C, T>, ValidationMode);
 
 impl<C, T> Debug for Validation<C, T>
 where
@@ -321,12 +789,23 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.0 {
-            InnerValidation::Success(constraint, value) => {
-                write!(f, "Validation(Success({:?}, {:?}))", constraint, value)
+            InnerValidation::Success(constraint, value, warnings) => {
+                if warnings.is_empty() {
+                    write!(f, "Validation(Success({:?}, {:?}))", constraint, value)
+                } else {
+                    write!(
+                        f,
+                        "Validation(Success({:?}, {:?}, {:?}))",
+                        constraint, value, warnings
+                    )
+                }
             }
-            InnerValidation::Failure(violations) => {
+            InnerValidation::Failure(violations, false) => {
                 write!(f, "Validation(Failure({:?}))", violations)
             }
+            InnerValidation::Failure(violations, true) => {
+                write!(f, "Validation(Failure({:?}, truncated))", violations)
+            }
         }
     }
 }
@@ -337,7 +816,28 @@ impl<C, T> Validation<C, T> {
     /// This method is provided to enable users of this crate to implement
     /// custom validation functions.
     pub fn success(valid: T) -> Self {
-        Validation(InnerValidation::Success(PhantomData, valid))
+        Validation::success_with_warnings(valid, Vec::new())
+    }
+
+    /// Constructs a `Validation` for a successful validation step that found
+    /// some warnings.
+    ///
+    /// The given constraint violations are kept as they are, regardless of
+    /// their severity. Use the [`checked`] method to decide on success or
+    /// failure by the severity of the violations.
+    ///
+    /// This method is provided to enable users of this crate to implement
+    /// custom validation functions.
+    ///
+    /// [`checked`]: #method.checked
+    pub fn success_with_warnings(
+        valid: T,
+        warnings: impl IntoIterator<Item = ConstraintViolation>,
+    ) -> Self {
+        Validation(
+            InnerValidation::Success(PhantomData, valid, Vec::from_iter(warnings)),
+            ValidationMode::Complete,
+        )
     }
 
     /// Constructs a `Validation` for a failed validation step.
@@ -345,9 +845,75 @@ impl<C, T> Validation<C, T> {
     /// This method is provided to enable users of this crate to implement
     /// custom validation functions.
     pub fn failure(constraint_violations: impl IntoIterator<Item = ConstraintViolation>) -> Self {
-        Validation(InnerValidation::Failure(Vec::from_iter(
-            constraint_violations.into_iter(),
-        )))
+        Validation(
+            InnerValidation::Failure(Vec::from_iter(constraint_violations), false),
+            ValidationMode::Complete,
+        )
+    }
+
+    /// Constructs a `Validation` for a value that has been validated by
+    /// reference.
+    ///
+    /// The given constraint violations are the accumulated violations found
+    /// by one or several implementations of the [`ValidateRef`] trait. If
+    /// there are no violations of severity [`Error`] the resulting validation
+    /// is successful and holds the given value. Any violations of lower
+    /// severity are kept as warnings.
+    ///
+    /// [`ValidateRef`]: trait.ValidateRef.html
+    /// [`Error`]: enum.Severity.html#variant.Error
+    pub fn checked(
+        value: T,
+        constraint_violations: impl IntoIterator<Item = ConstraintViolation>,
+    ) -> Self {
+        let violations = Vec::from_iter(constraint_violations);
+        if violations.iter().all(|violation| !violation.is_error()) {
+            Validation::success_with_warnings(value, violations)
+        } else {
+            Validation::failure(violations)
+        }
+    }
+
+    /// Sets the [`ValidationMode`] of this validation.
+    ///
+    /// If this validation has already found more errors than permitted by
+    /// the given mode the surplus violations are dropped.
+    ///
+    /// The mode is passed on to the validations resulting from the combinator
+    /// methods of this struct. Thus it is sufficient to set the mode on the
+    /// first validation step.
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn with_mode(self, mode: ValidationMode) -> Self {
+        Validation(self.0, mode).limited()
+    }
+
+    /// Returns the [`ValidationMode`] of this validation.
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn mode(&self) -> ValidationMode {
+        self.1
+    }
+
+    /// Returns whether this validation has found as many errors as are
+    /// permitted by its [`ValidationMode`].
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn is_limit_reached(&self) -> bool {
+        match &self.0 {
+            InnerValidation::Success(_, _, _) => false,
+            InnerValidation::Failure(violations, _) => self.1.is_limit_reached(violations),
+        }
+    }
+
+    fn limited(self) -> Self {
+        match self.0 {
+            InnerValidation::Failure(mut violations, truncated) => {
+                let truncated = self.1.truncate(&mut violations) || truncated;
+                Validation(InnerValidation::Failure(violations, truncated), self.1)
+            }
+            success => Validation(success, self.1),
+        }
     }
 
     /// Finishes a validation and returns the result of the validation.
@@ -356,17 +922,74 @@ impl<C, T> Validation<C, T> {
     /// using the combinator methods of this struct. After all steps are
     /// executed this method can be called to get the [`ValidationResult`]
     ///
+    /// This method drops the warnings of a successful validation, as the
+    /// returned [`Validated`] value can not carry them. Use the
+    /// [`result_with_warnings`] method instead.
+    ///
     /// [`ValidationResult`]: type.ValidationResult.html
+    /// [`Validated`]: struct.Validated.html
+    /// [`result_with_warnings`]: #method.result_with_warnings
+    #[deprecated(
+        note = "drops the warnings of a successful validation, use `result_with_warnings` instead"
+    )]
     pub fn result(self) -> ValidationResult<C, T> {
+        self.result_with_warnings().map(|(validated, _)| validated)
+    }
+
+    /// Finishes a validation and returns the result of the validation
+    /// including the warnings found.
+    ///
+    /// On success the validated value is returned together with the list of
+    /// constraint violations of severity `Warning` or `Info`. On failure the
+    /// returned [`ValidationError`] holds all constraint violations
+    /// including the warnings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, Severity, Validation};
+    ///
+    /// let nickname = String::from("the-quick-brown-fox");
+    /// let warning = invalid_value("invalid-length-max", "nickname", 19, 15)
+    ///     .with_severity(Severity::Warning);
+    ///
+    /// let validation: Validation<(), String> = Validation::checked(nickname, vec![warning]);
+    /// let (validated, warnings) = validation.result_with_warnings().unwrap();
+    ///
+    /// assert_eq!(validated.unwrap(), "the-quick-brown-fox");
+    /// assert_eq!(
+    ///     warnings,
+    ///     vec![invalid_value("invalid-length-max", "nickname", 19, 15)
+    ///         .with_severity(Severity::Warning)]
+    /// );
+    /// ```
+    ///
+    /// [`ValidationError`]: struct.ValidationError.html
+    #[allow(clippy::type_complexity)]
+    pub fn result_with_warnings(
+        self,
+    ) -> Result<(Validated<C, T>, Vec<ConstraintViolation>), ValidationError> {
         match self.0 {
-            InnerValidation::Success(_c, entity) => Ok(Validated(_c, entity)),
-            InnerValidation::Failure(violations) => Err(ValidationError {
+            InnerValidation::Success(_c, entity, warnings) => Ok((Validated(_c, entity), warnings)),
+            InnerValidation::Failure(violations, truncated) => Err(ValidationError {
                 message: None,
                 violations,
+                truncated,
             }),
         }
     }
 
+    /// Returns the warnings found so far by this validation.
+    ///
+    /// Returns an empty slice if the validation has failed. The warnings of a
+    /// failed validation are part of its list of constraint violations.
+    pub fn warnings(&self) -> &[ConstraintViolation] {
+        match &self.0 {
+            InnerValidation::Success(_, _, warnings) => warnings,
+            InnerValidation::Failure(_, _) => &[],
+        }
+    }
+
     /// Finishes a validation providing a message and returns the result.
     ///
     /// A validation may comprise multiple validation steps that are combined
@@ -380,14 +1003,26 @@ impl<C, T> Validation<C, T> {
     /// or when validating a struct that represents a REST command the message
     /// would be something like "invalid post entry command".
     ///
+    /// Like the [`result`] method this method drops the warnings of a
+    /// successful validation. Use the [`result_with_warnings`] method and set
+    /// the message with [`ValidationError::with_message`] instead.
+    ///
     /// [`ValidationResult`]: type.ValidationResult.html
     /// [`ValidationError`]: struct.ValidationError.html
+    /// [`result`]: #method.result
+    /// [`result_with_warnings`]: #method.result_with_warnings
+    /// [`ValidationError::with_message`]: struct.ValidationError.html#method.with_message
+    #[deprecated(
+        note = "drops the warnings of a successful validation, use `result_with_warnings` and \
+                `ValidationError::with_message` instead"
+    )]
     pub fn with_message(self, message: impl Into<Cow<'static, str>>) -> ValidationResult<C, T> {
         match self.0 {
-            InnerValidation::Success(_c, entity) => Ok(Validated(_c, entity)),
-            InnerValidation::Failure(violations) => Err(ValidationError {
+            InnerValidation::Success(_c, entity, _) => Ok(Validated(_c, entity)),
+            InnerValidation::Failure(violations, truncated) => Err(ValidationError {
                 message: Some(message.into()),
                 violations,
+                truncated,
             }),
         }
     }
@@ -401,10 +1036,15 @@ impl<C, T> Validation<C, T> {
     ///
     /// [`and_then`]: #method.and_then
     pub fn combine<U>(self, value: U) -> Validation<C, (U, T)> {
-        match self.0 {
-            InnerValidation::Success(_, entity) => Validation::success((value, entity)),
-            InnerValidation::Failure(violations) => Validation::failure(violations),
-        }
+        let inner = match self.0 {
+            InnerValidation::Success(_, entity, warnings) => {
+                InnerValidation::Success(PhantomData, (value, entity), warnings)
+            }
+            InnerValidation::Failure(violations, truncated) => {
+                InnerValidation::Failure(violations, truncated)
+            }
+        };
+        Validation(inner, self.1)
     }
 
     /// Maps the validated values into another type.
@@ -413,65 +1053,428 @@ impl<C, T> Validation<C, T> {
     /// fields of a struct and the result should be mapped back into this
     /// struct. See the crate level documentation for an example.
     pub fn map<D, U>(self, convert: impl Fn(T) -> U) -> Validation<D, U> {
-        match self.0 {
-            InnerValidation::Success(_, entity) => Validation::success(convert(entity)),
-            InnerValidation::Failure(violations) => Validation::failure(violations),
-        }
+        let inner = match self.0 {
+            InnerValidation::Success(_, entity, warnings) => {
+                InnerValidation::Success(PhantomData, convert(entity), warnings)
+            }
+            InnerValidation::Failure(violations, truncated) => {
+                InnerValidation::Failure(violations, truncated)
+            }
+        };
+        Validation(inner, self.1)
     }
 
-    /// Combines this validation with another validation unconditionally.
+    /// Combines this validation with another validation.
     ///
     /// The other validation is executed regardless whether this validation has
-    /// been successful or not.
+    /// been successful or not, unless this validation has reached the limit of
+    /// errors of its [`ValidationMode`] already.
     ///
     /// The resulting validation is only successful if itself was successful
     /// and the other validation is also successful. Any constraint violations
     /// found either by this validation or the other validation are accumulated.
+    /// This includes the warnings found by successful validations.
+    ///
+    /// The other validation can be given as a `Validation` or as a closure
+    /// that returns a `Validation` (see [`IntoValidation`]). A closure is only
+    /// executed if this validation has not reached the limit of errors yet.
+    /// Thus expensive validations should be given as closures when the
+    /// validation is run in [`ValidationMode::FailFast`] or
+    /// [`ValidationMode::Limit`]. If a closure is skipped the resulting
+    /// validation is marked as truncated.
+    ///
+    /// The resulting validation has the [`ValidationMode`] of this validation.
+    /// If the number of errors exceeds the limit of the mode the surplus
+    /// violations are dropped and the resulting validation is marked as
+    /// truncated.
     ///
     /// See the crate level documentation for an example.
-    pub fn and<D, U>(self, other: Validation<D, U>) -> Validation<D, (T, U)> {
-        match (self.0, other.0) {
-            (InnerValidation::Success(_, value1), InnerValidation::Success(_, value2)) => {
-                Validation::success((value1, value2))
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, Validation, ValidationMode};
+    ///
+    /// let validation: Validation<(), String> =
+    ///     Validation::failure(vec![invalid_value("invalid-length-min", "username", 2, 4)])
+    ///         .with_mode(ValidationMode::FailFast);
+    ///
+    /// let result = validation
+    ///     .and(|| -> Validation<(), i32> { unreachable!("expensive validation") })
+    ///     .result_with_warnings();
+    ///
+    /// assert!(result.unwrap_err().truncated);
+    /// ```
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    /// [`ValidationMode::FailFast`]: enum.ValidationMode.html#variant.FailFast
+    /// [`ValidationMode::Limit`]: enum.ValidationMode.html#variant.Limit
+    /// [`IntoValidation`]: trait.IntoValidation.html
+    pub fn and<D, U>(self, other: impl IntoValidation<D, U>) -> Validation<D, (T, U)> {
+        let inner = match self.0 {
+            InnerValidation::Failure(violations, _)
+                if other.is_deferred() && self.1.is_limit_reached(&violations) =>
+            {
+                InnerValidation::Failure(violations, true)
             }
-            (InnerValidation::Failure(violations), InnerValidation::Success(_, _)) => {
-                Validation::failure(violations)
+            inner => match (inner, other.into_validation().0) {
+                (
+                    InnerValidation::Success(_, value1, mut warnings),
+                    InnerValidation::Success(_, value2, warnings2),
+                ) => {
+                    warnings.extend(warnings2);
+                    InnerValidation::Success(PhantomData, (value1, value2), warnings)
+                }
+                (
+                    InnerValidation::Failure(mut violations, truncated),
+                    InnerValidation::Success(_, _, warnings),
+                ) => {
+                    violations.extend(warnings);
+                    InnerValidation::Failure(violations, truncated)
+                }
+                (
+                    InnerValidation::Success(_, _, mut warnings),
+                    InnerValidation::Failure(violations, truncated),
+                ) => {
+                    warnings.extend(violations);
+                    InnerValidation::Failure(warnings, truncated)
+                }
+                (
+                    InnerValidation::Failure(mut violations, truncated1),
+                    InnerValidation::Failure(violations2, truncated2),
+                ) => {
+                    violations.extend(violations2);
+                    InnerValidation::Failure(violations, truncated1 || truncated2)
+                }
+            },
+        };
+        Validation(inner, self.1).limited()
+    }
+
+    /// Combines this validation with another validation conditionally.
+    ///
+    /// The other validation is only executed if this validation has been
+    /// successful. It has access to the values that have been validated so far.
+    ///
+    /// Those values are provided in a tuple as an argument to the given
+    /// closure. If there is one value that has been validated so far the
+    /// argument `T` to the closure is simple the type of the value. In case of
+    /// two values `T` is a tuple of type `(A, B)`, in case of 3 values the type
+    /// of `T` is a tuple of a tuple and the 3rd value like `((A, B), C)` and
+    /// so on.
+    ///
+    /// Values that are given as argument to the closure but not used for the
+    /// other validation are not part of the final result of the validation.
+    /// To add unused values to the result of the validation we can use the
+    /// [`combine`] method.
+    ///
+    /// See the crate level documentation for an example.
+    ///
+    /// Warnings found by this validation are kept in front of the
+    /// constraint violations found by the other validation. The resulting
+    /// validation has the [`ValidationMode`] of this validation.
+    ///
+    /// [`combine`]: #method.combine
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn and_then<D, U>(self, next: impl FnOnce(T) -> Validation<D, U>) -> Validation<D, U> {
+        let inner = match self.0 {
+            InnerValidation::Success(_, value1, mut warnings) => match next(value1).0 {
+                InnerValidation::Success(_, value2, warnings2) => {
+                    warnings.extend(warnings2);
+                    InnerValidation::Success(PhantomData, value2, warnings)
+                }
+                InnerValidation::Failure(violations, truncated) => {
+                    warnings.extend(violations);
+                    InnerValidation::Failure(warnings, truncated)
+                }
+            },
+            InnerValidation::Failure(violations, truncated) => {
+                InnerValidation::Failure(violations, truncated)
+            }
+        };
+        Validation(inner, self.1).limited()
+    }
+
+    /// Prefixes the field names of all constraint violations found by this
+    /// validation with the given path.
+    ///
+    /// See [`ConstraintViolation::with_field_prefix`] for how the names are
+    /// joined.
+    ///
+    /// [`ConstraintViolation::with_field_prefix`]: enum.ConstraintViolation.html#method.with_field_prefix
+    pub fn with_field_prefix(self, prefix: &str) -> Self {
+        let prefixed = |violations: Vec<ConstraintViolation>| {
+            violations
+                .into_iter()
+                .map(|violation| violation.with_field_prefix(prefix))
+                .collect()
+        };
+        let inner = match self.0 {
+            InnerValidation::Success(_, value, warnings) => {
+                InnerValidation::Success(PhantomData, value, prefixed(warnings))
             }
-            (InnerValidation::Success(_, _), InnerValidation::Failure(violations)) => {
-                Validation::failure(violations)
+            InnerValidation::Failure(violations, truncated) => {
+                InnerValidation::Failure(prefixed(violations), truncated)
+            }
+        };
+        Validation(inner, self.1)
+    }
+
+    /// Annotates the field names of all constraint violations found by this
+    /// validation with the given index of an element in a collection.
+    ///
+    /// For example the field name `name` becomes `[2].name` for the index 2.
+    /// This is a shortcut for `with_field_prefix(&format!("[{}]", index))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, Validate, Validation};
+    /// use valid::constraint::Bound;
+    ///
+    /// let quantities = vec![1, 0, 3];
+    ///
+    /// let validation: Validation<_, Vec<_>> = quantities
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(index, quantity)| {
+    ///         quantity
+    ///             .validate("quantity", &Bound::Min(1))
+    ///             .with_index(index)
+    ///     })
+    ///     .collect();
+    ///
+    /// let result = validation.with_field_prefix("line_items").result_with_warnings();
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err().violations,
+    ///     vec![invalid_value("invalid-bound-closed-min", "line_items[1].quantity", 0, 1)]
+    /// );
+    /// ```
+    pub fn with_index(self, index: usize) -> Self {
+        self.with_field_prefix(&format!("[{}]", index))
+    }
+}
+
+impl<C, T> Validation<C, Vec<T>> {
+    /// Validates each element of a collection and accumulates the results
+    /// into a `Validation` of a `Vec` holding the validated elements.
+    ///
+    /// The given function is called for each element in order of iteration.
+    /// The resulting validation is only successful if all elements are valid.
+    /// Once the limit of errors of the given [`ValidationMode`] is reached
+    /// the remaining elements are not validated and the resulting validation
+    /// is marked as truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{Validate, Validation, ValidationMode};
+    /// use valid::constraint::Bound;
+    ///
+    /// let ages = vec![12, 27, 7, 130, 43];
+    ///
+    /// let validation = Validation::each(ValidationMode::Limit(2), ages, |age| {
+    ///     age.validate("age", &Bound::ClosedRange(13, 120))
+    /// });
+    ///
+    /// let error = validation.result_with_warnings().unwrap_err();
+    ///
+    /// assert_eq!(error.violations.len(), 2);
+    /// assert!(error.truncated);
+    /// ```
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn each<U>(
+        mode: ValidationMode,
+        elements: impl IntoIterator<Item = U>,
+        validate: impl FnMut(U) -> Validation<C, T>,
+    ) -> Self {
+        collect_validations(mode, elements, validate)
+    }
+}
+
+/// Validates each element and accumulates the results into one validation of
+/// a collection.
+///
+/// Stops validating the elements once the limit of errors of the given mode
+/// is reached and marks the resulting validation as truncated.
+fn collect_validations<C, T, U, V>(
+    mode: ValidationMode,
+    elements: impl IntoIterator<Item = U>,
+    mut validate: impl FnMut(U) -> Validation<C, T>,
+) -> Validation<C, V>
+where
+    V: FromIterator<T>,
+{
+    let mut values = Vec::new();
+    let mut violations = Vec::new();
+    let mut failed = false;
+    let mut truncated = false;
+    for element in elements {
+        if failed && mode.is_limit_reached(&violations) {
+            truncated = true;
+            break;
+        }
+        match validate(element).0 {
+            InnerValidation::Success(_, value, warnings) => {
+                values.push(value);
+                violations.extend(warnings);
             }
-            (InnerValidation::Failure(mut violations), InnerValidation::Failure(violations2)) => {
+            InnerValidation::Failure(violations2, truncated2) => {
+                failed = true;
                 violations.extend(violations2);
-                Validation::failure(violations)
+                truncated = truncated || truncated2;
             }
         }
     }
+    let inner = if failed {
+        InnerValidation::Failure(violations, truncated)
+    } else {
+        InnerValidation::Success(PhantomData, V::from_iter(values), violations)
+    };
+    Validation(inner, mode).limited()
+}
+
+/// Conversion into a [`Validation`] that is executed on demand.
+///
+/// It is implemented for `Validation` itself and for closures that return a
+/// `Validation`. A closure is only called when the validation is needed,
+/// which lets the [`Validation::and`] combinator skip expensive validations
+/// once the limit of errors of the [`ValidationMode`] has been reached.
+///
+/// [`Validation`]: struct.Validation.html
+/// [`Validation::and`]: struct.Validation.html#method.and
+/// [`ValidationMode`]: enum.ValidationMode.html
+pub trait IntoValidation<C, T> {
+    /// Executes the validation if not done yet and returns it.
+    fn into_validation(self) -> Validation<C, T>;
+
+    /// Returns whether the validation has not been executed yet and thus can
+    /// be skipped.
+    ///
+    /// The default implementation returns `true`.
+    fn is_deferred(&self) -> bool {
+        true
+    }
+}
+
+impl<C, T> IntoValidation<C, T> for Validation<C, T> {
+    fn into_validation(self) -> Validation<C, T> {
+        self
+    }
+
+    fn is_deferred(&self) -> bool {
+        false
+    }
+}
+
+impl<C, T, F> IntoValidation<C, T> for F
+where
+    F: FnOnce() -> Validation<C, T>,
+{
+    fn into_validation(self) -> Validation<C, T> {
+        self()
+    }
+}
+
+/// Collects an iterator of validations into one validation of a collection.
+///
+/// The resulting validation is only successful if all validations are
+/// successful. In this case it holds the values of all validations in the
+/// order of iteration. Otherwise it holds the constraint violations of all
+/// validations. Warnings are accumulated in both cases.
+///
+/// The resulting validation has the default [`ValidationMode`]. To stop
+/// validating the elements of a collection after some errors use
+/// [`Validation::each`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use valid::{invalid_value, Validate, Validation};
+/// use valid::constraint::Bound;
+///
+/// let ages = vec![12, 27, 130];
+///
+/// let validation: Validation<_, Vec<_>> = ages
+///     .into_iter()
+///     .map(|age| age.validate("age", &Bound::ClosedRange(13, 120)))
+///     .collect();
+///
+/// assert_eq!(
+///     validation.result_with_warnings().unwrap_err().violations,
+///     vec![
+///         invalid_value("invalid-bound-closed-min", "age", 12, 13),
+///         invalid_value("invalid-bound-closed-max", "age", 130, 120),
+///     ]
+/// );
+/// ```
+///
+/// [`ValidationMode`]: enum.ValidationMode.html
+/// [`Validation::each`]: struct.Validation.html#method.each
+impl<C, T, V> FromIterator<Validation<C, T>> for Validation<C, V>
+where
+    V: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Validation<C, T>>>(iter: I) -> Self {
+        collect_validations(ValidationMode::default(), iter, |validation| validation)
+    }
+}
 
-    /// Combines this validation with another validation conditionally.
-    ///
-    /// The other validation is only executed if this validation has been
-    /// successful. It has access to the values that have been validated so far.
-    ///
-    /// Those values are provided in a tuple as an argument to the given
-    /// closure. If there is one value that has been validated so far the
-    /// argument `T` to the closure is simple the type of the value. In case of
-    /// two values `T` is a tuple of type `(A, B)`, in case of 3 values the type
-    /// of `T` is a tuple of a tuple and the 3rd value like `((A, B), C)` and
-    /// so on.
-    ///
-    /// Values that are given as argument to the closure but not used for the
-    /// other validation are not part of the final result of the validation.
-    /// To add unused values to the result of the validation we can use the
-    /// [`combine`] method.
-    ///
-    /// See the crate level documentation for an example.
-    ///
-    /// [`combine`]: #method.combine
-    pub fn and_then<D, U>(self, next: impl FnOnce(T) -> Validation<D, U>) -> Validation<D, U> {
-        match self.0 {
-            InnerValidation::Success(_, value1) => next(value1),
-            InnerValidation::Failure(violations) => Validation::failure(violations),
+/// Extends a validation of a collection with the results of more
+/// validations.
+///
+/// Values of successful validations are added to the collection as long as
+/// all validations are successful. The constraint violations of all
+/// validations are accumulated. The [`ValidationMode`] of the extended
+/// validation is applied to the accumulated constraint violations.
+///
+/// [`ValidationMode`]: enum.ValidationMode.html
+impl<C, T, V> Extend<Validation<C, T>> for Validation<C, V>
+where
+    V: Extend<T>,
+{
+    fn extend<I: IntoIterator<Item = Validation<C, T>>>(&mut self, iter: I) {
+        let mut inner =
+            core::mem::replace(&mut self.0, InnerValidation::Failure(Vec::new(), false));
+        for validation in iter {
+            inner = match (inner, validation.0) {
+                (
+                    InnerValidation::Success(_, mut values, mut warnings),
+                    InnerValidation::Success(_, value, warnings2),
+                ) => {
+                    values.extend(Some(value));
+                    warnings.extend(warnings2);
+                    InnerValidation::Success(PhantomData, values, warnings)
+                }
+                (
+                    InnerValidation::Success(_, _, mut violations),
+                    InnerValidation::Failure(violations2, truncated),
+                ) => {
+                    violations.extend(violations2);
+                    InnerValidation::Failure(violations, truncated)
+                }
+                (
+                    InnerValidation::Failure(mut violations, truncated),
+                    InnerValidation::Success(_, _, warnings),
+                ) => {
+                    violations.extend(warnings);
+                    InnerValidation::Failure(violations, truncated)
+                }
+                (
+                    InnerValidation::Failure(mut violations, truncated1),
+                    InnerValidation::Failure(violations2, truncated2),
+                ) => {
+                    violations.extend(violations2);
+                    InnerValidation::Failure(violations, truncated1 || truncated2)
+                }
+            };
         }
+        if let InnerValidation::Failure(violations, truncated) = &mut inner {
+            *truncated = self.1.truncate(violations) || *truncated;
+        }
+        self.0 = inner;
     }
 }
 
@@ -497,11 +1500,39 @@ impl<C, T> Validation<C, T> {
 /// there are implementations of the `From` trait for the primitive types `i8`,
 /// `i16`, `i64`, `u8`, `u16`, `u32`, `u64`.
 ///
-/// `u32` values greater than `i32::max_value()` are converted to `Long(i64)`.
+/// `u32` values greater than `i32::MAX` are converted to `Long(i64)`.
+///
+/// The inner value can be obtained using the accessor methods, like
+/// [`as_i64`] or [`as_str`], or by converting the value into the underlying
+/// type using the `TryFrom` trait, which returns the original value on
+/// failure. The name of the type of a value is returned by [`type_name`].
+///
+/// Values are equal if they are of the same variant and hold equal values.
+/// The methods [`numeric_eq`] and [`numeric_cmp`] compare numbers by their
+/// value regardless of their variant, e.g. `Value::Integer(1)` is numerically
+/// equal to `Value::Long(1)` and less than `Value::Double(1.5)`. The
+/// `PartialOrd` implementation orders numbers of different variants as well,
+/// but does not consider numbers of different variants equal.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use valid::Value;
+///
+/// assert_ne!(Value::Integer(42), Value::Long(42));
+/// assert!(Value::Integer(42).numeric_eq(&Value::Long(42)));
+/// assert!(Value::Long(3) < Value::Double(3.5));
+/// assert_eq!(Value::Integer(42).to_long(), Some(Value::Long(42)));
+/// assert_eq!(i64::try_from(Value::Integer(42)), Ok(42));
+/// assert_eq!(
+///     i64::try_from(Value::String("42".into())),
+///     Err(Value::String("42".into()))
+/// );
+/// assert_eq!(Value::Boolean(true).type_name(), "boolean");
+/// ```
 ///
 /// # Panics
 ///
-/// Converting `u64` values greater than `i64::max_value()` has an unreliable
+/// Converting `u64` values greater than `i64::MAX` has an unreliable
 /// behavior and might panic.
 ///
 /// # Notes
@@ -511,6 +1542,11 @@ impl<C, T> Validation<C, T> {
 /// type may be useful!
 ///
 /// [`ConstraintViolation`]: enum.ConstraintViolation.html
+/// [`as_i64`]: #method.as_i64
+/// [`as_str`]: #method.as_str
+/// [`type_name`]: #method.type_name
+/// [`numeric_eq`]: #method.numeric_eq
+/// [`numeric_cmp`]: #method.numeric_cmp
 /// [`bigdecimal`]: https://crates.io/crates/bigdecimal
 /// [`chrono`]: https://crates.io/crates/chrono
 /// [`num-bigint`]: https://crates.io/crates/num-bigint
@@ -602,7 +1638,7 @@ impl From<u8> for Value {
 
 impl From<u32> for Value {
     fn from(value: u32) -> Self {
-        if value > i32::max_value() as u32 {
+        if value > i32::MAX as u32 {
             Value::Long(i64::from(value))
         } else {
             Value::Integer(value as i32)
@@ -620,7 +1656,7 @@ impl From<i64> for Value {
 impl From<u64> for Value {
     fn from(value: u64) -> Self {
         assert!(
-            value <= i64::max_value() as u64,
+            value <= i64::MAX as u64,
             "u64 value too big to be converted to i64"
         );
         Value::Long(value as i64)
@@ -681,9 +1717,9 @@ impl TryFrom<usize> for Value {
     type Error = &'static str;
 
     fn try_from(value: usize) -> Result<Self, Self::Error> {
-        if value <= i32::max_value() as usize {
+        if value <= i32::MAX as usize {
             Ok(Value::Integer(value as i32))
-        } else if value as u64 <= i64::max_value() as u64 {
+        } else if value as u64 <= i64::MAX as u64 {
             Ok(Value::Long(value as i64))
         } else {
             Err("usize value too big to be converted to i64")
@@ -696,9 +1732,9 @@ impl TryFrom<usize> for Value {
     type Error = &'static str;
 
     fn try_from(value: usize) -> Result<Self, Self::Error> {
-        if value <= i32::max_value() as usize {
+        if value <= i32::MAX as usize {
             Ok(Value::Integer(value as i32))
-        } else if value <= i64::max_value() as usize {
+        } else if value <= i64::MAX as usize {
             Ok(Value::Long(value as i64))
         } else {
             Err("usize value too big to be converted to i64")
@@ -794,6 +1830,48 @@ impl Display for Field {
     }
 }
 
+/// The severity of a constraint violation.
+///
+/// Only violations with severity [`Error`] make a validation fail. Violations
+/// with severity [`Warning`] or [`Info`] are reported as warnings of an
+/// otherwise successful validation. (see [`Validation::checked`] and
+/// [`Validation::result_with_warnings`])
+///
+/// The convenience functions for constructing constraint violations, like
+/// [`invalid_value`], always create violations of severity `Error`. The
+/// severity of a violation can be changed using the
+/// [`ConstraintViolation::with_severity`] method.
+///
+/// [`Error`]: #variant.Error
+/// [`Warning`]: #variant.Warning
+/// [`Info`]: #variant.Info
+/// [`Validation::checked`]: struct.Validation.html#method.checked
+/// [`Validation::result_with_warnings`]: struct.Validation.html#method.result_with_warnings
+/// [`invalid_value`]: fn.invalid_value.html
+/// [`ConstraintViolation::with_severity`]: enum.ConstraintViolation.html#method.with_severity
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde1", serde(rename_all = "lowercase"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Severity {
+    /// the value is invalid, the validation fails
+    #[default]
+    Error,
+    /// the value is valid but should be reviewed
+    Warning,
+    /// the value is valid, the violation is informational only
+    Info,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
 /// Holds details about a constraint violation found by validating a constraint
 /// in the [`FieldName`] context.
 ///
@@ -807,6 +1885,15 @@ pub struct InvalidValue {
     /// interpret this error code.
     pub code: Cow<'static, str>,
 
+    /// The severity of the constraint violation.
+    ///
+    /// Defaults to [`Severity::Error`] when deserialized from data that has no
+    /// severity.
+    ///
+    /// [`Severity::Error`]: enum.Severity.html#variant.Error
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub severity: Severity,
+
     /// Details about the field having a value that violates a constraint.
     pub field: Field,
 }
@@ -837,6 +1924,15 @@ pub struct InvalidRelation {
     /// interpret this error code.
     pub code: Cow<'static, str>,
 
+    /// The severity of the constraint violation.
+    ///
+    /// Defaults to [`Severity::Error`] when deserialized from data that has no
+    /// severity.
+    ///
+    /// [`Severity::Error`]: enum.Severity.html#variant.Error
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub severity: Severity,
+
     /// Details about the first of the pair of related fields
     pub field1: Field,
 
@@ -858,6 +1954,50 @@ impl Display for InvalidRelation {
     }
 }
 
+/// Holds details about a constraint violation found by validating a constraint
+/// in the [`RelatedFieldSet`] context.
+///
+/// [`RelatedFieldSet`]: struct.RelatedFieldSet.html
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidFieldSet {
+    /// Error code that identifies the exact error.
+    ///
+    /// A client that receives the constraint violation should be able to
+    /// interpret this error code.
+    pub code: Cow<'static, str>,
+
+    /// The severity of the constraint violation.
+    ///
+    /// Defaults to [`Severity::Error`] when deserialized from data that has no
+    /// severity.
+    ///
+    /// [`Severity::Error`]: enum.Severity.html#variant.Error
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub severity: Severity,
+
+    /// Details about each of the related fields
+    pub fields: Vec<Field>,
+}
+
+impl Display for InvalidFieldSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of ", self.code)?;
+        let mut separator = "";
+        for field in &self.fields {
+            write!(
+                f,
+                "{}{} which is {}",
+                separator,
+                field.name,
+                option_to_string(field.actual.as_ref())
+            )?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
 /// Holds details about a constraint violation found by validating a constraint
 /// in the [`State`] context.
 ///
@@ -871,6 +2011,15 @@ pub struct InvalidState {
     /// interpret this error code.
     pub code: Cow<'static, str>,
 
+    /// The severity of the constraint violation.
+    ///
+    /// Defaults to [`Severity::Error`] when deserialized from data that has no
+    /// severity.
+    ///
+    /// [`Severity::Error`]: enum.Severity.html#variant.Error
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub severity: Severity,
+
     /// A list of parameters that may be used to provide more meaningful error
     /// messages to the user of an application
     pub params: Vec<Parameter>,
@@ -908,7 +2057,9 @@ impl Display for InvalidState {
 /// | Context            | Constraint Violation | Construction Method      |
 /// |--------------------|----------------------|--------------------------|
 /// | [`FieldName`]      | `Field`              | [`invalid_value`]<br/>[`invalid_optional_value`] |
-/// | [`RelatedFields`]  | `Relation`           | [`invalid_relation`]     |
+/// | [`RelatedFields`]  | `Relation`           | [`invalid_relation`]<br/>[`invalid_optional_relation`] |
+/// | [`RelatedFieldSet`] | `FieldSet`          | [`invalid_field_set`]    |
+/// | [`FieldState<S>`]  | `Field`              | [`invalid_value`]<br/>[`invalid_optional_value`] |
 /// | [`State<S>`]       | `State`              | [`invalid_state`]        |
 ///
 /// The construction methods are a convenient way to construct
@@ -920,10 +2071,14 @@ impl Display for InvalidState {
 ///
 /// [`FieldName`]: struct.FieldName.html
 /// [`RelatedFields`]: struct.RelatedFields.html
+/// [`RelatedFieldSet`]: struct.RelatedFieldSet.html
 /// [`State<S>`]: struct.State.html
+/// [`FieldState<S>`]: struct.FieldState.html
+/// [`invalid_field_set`]: fn.invalid_field_set.html
 /// [`invalid_value`]: fn.invalid_value.html
 /// [`invalid_optional_value`]: fn.invalid_optional_value.html
 /// [`invalid_relation`]: fn.invalid_relation.html
+/// [`invalid_optional_relation`]: fn.invalid_optional_relation.html
 /// [`invalid_state`]: fn.invalid_state.html
 /// [`serde`]: https://crates.io/crates/serde
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
@@ -934,6 +2089,8 @@ pub enum ConstraintViolation {
     Field(InvalidValue),
     /// Violation of a constraint validated in the `RelatedField` context
     Relation(InvalidRelation),
+    /// Violation of a constraint validated in the `RelatedFieldSet` context
+    FieldSet(InvalidFieldSet),
     /// Violation of a constraint validated in the `State` context
     State(InvalidState),
 }
@@ -943,11 +2100,190 @@ impl Display for ConstraintViolation {
         match self {
             ConstraintViolation::Field(value) => write!(f, "{}", value),
             ConstraintViolation::Relation(value) => write!(f, "{}", value),
+            ConstraintViolation::FieldSet(value) => write!(f, "{}", value),
             ConstraintViolation::State(value) => write!(f, "{}", value),
         }
     }
 }
 
+impl ConstraintViolation {
+    /// Returns the severity of this constraint violation.
+    pub fn severity(&self) -> Severity {
+        match self {
+            ConstraintViolation::Field(value) => value.severity,
+            ConstraintViolation::Relation(value) => value.severity,
+            ConstraintViolation::FieldSet(value) => value.severity,
+            ConstraintViolation::State(value) => value.severity,
+        }
+    }
+
+    /// Returns this constraint violation with its severity set to the given
+    /// severity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, Severity};
+    ///
+    /// let violation = invalid_value("invalid-length-max", "nickname", 18, 15)
+    ///     .with_severity(Severity::Warning);
+    ///
+    /// assert_eq!(violation.severity(), Severity::Warning);
+    /// ```
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        match &mut self {
+            ConstraintViolation::Field(value) => value.severity = severity,
+            ConstraintViolation::Relation(value) => value.severity = severity,
+            ConstraintViolation::FieldSet(value) => value.severity = severity,
+            ConstraintViolation::State(value) => value.severity = severity,
+        }
+        self
+    }
+
+    /// Returns whether this constraint violation has the severity `Error`.
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+
+    /// Returns the error code of this constraint violation.
+    pub fn code(&self) -> &str {
+        match self {
+            ConstraintViolation::Field(value) => &value.code,
+            ConstraintViolation::Relation(value) => &value.code,
+            ConstraintViolation::FieldSet(value) => &value.code,
+            ConstraintViolation::State(value) => &value.code,
+        }
+    }
+
+    /// Returns whether this constraint violation has the given error code.
+    pub fn has_code(&self, code: &str) -> bool {
+        self.code() == code
+    }
+
+    /// Returns the names of the fields this constraint violation refers to.
+    ///
+    /// A violation of a constraint on related fields refers to all of the
+    /// related fields. A violation of a constraint validated in the [`State`]
+    /// context refers to the names of its parameters.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        let (field1, field2, fields, params): (_, _, &[Field], &[Parameter]) = match self {
+            ConstraintViolation::Field(value) => (Some(&value.field), None, &[], &[]),
+            ConstraintViolation::Relation(value) => {
+                (Some(&value.field1), Some(&value.field2), &[], &[])
+            }
+            ConstraintViolation::FieldSet(value) => (None, None, &value.fields, &[]),
+            ConstraintViolation::State(value) => (None, None, &[], &value.params),
+        };
+        field1
+            .into_iter()
+            .chain(field2)
+            .chain(fields)
+            .map(|field| &field.name[..])
+            .chain(params.iter().map(|param| &param.name[..]))
+    }
+
+    /// Returns whether this constraint violation refers to the field of the
+    /// given name.
+    ///
+    /// See [`field_names`] for the fields a constraint violation refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_relation, invalid_state, param};
+    ///
+    /// let violation = invalid_relation(
+    ///     "invalid-must-match",
+    ///     "password",
+    ///     "s3cr3t".to_string(),
+    ///     "password2",
+    ///     "s3crEt".to_string(),
+    /// );
+    ///
+    /// assert!(violation.is_for_field("password"));
+    /// assert!(violation.is_for_field("password2"));
+    /// assert!(!violation.is_for_field("username"));
+    ///
+    /// let violation = invalid_state(
+    ///     "invalid-unique-username",
+    ///     vec![param("username", "jon.doe".to_string())],
+    /// );
+    ///
+    /// assert!(violation.is_for_field("username"));
+    /// ```
+    ///
+    /// [`field_names`]: #method.field_names
+    pub fn is_for_field(&self, name: &str) -> bool {
+        self.field_names().any(|field_name| field_name == name)
+    }
+
+    /// Returns this constraint violation with the given path prepended to
+    /// the names of the fields it refers to.
+    ///
+    /// The prefix and a field name are joined by a dot (`.`) unless the field
+    /// name starts with an index in square brackets, like `[2]`. The
+    /// parameters of a violation in the [`State`] context are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::invalid_value;
+    ///
+    /// let violation = invalid_value("invalid-not-empty", "name", 0, 1);
+    ///
+    /// assert_eq!(
+    ///     violation.clone().with_field_prefix("address"),
+    ///     invalid_value("invalid-not-empty", "address.name", 0, 1)
+    /// );
+    /// assert_eq!(
+    ///     violation.with_field_prefix("[2]").with_field_prefix("addresses"),
+    ///     invalid_value("invalid-not-empty", "addresses[2].name", 0, 1)
+    /// );
+    /// ```
+    ///
+    /// [`State`]: struct.State.html
+    pub fn with_field_prefix(mut self, prefix: &str) -> Self {
+        let prefixed = |field: &mut Field| {
+            field.name = if field.name.is_empty() {
+                prefix.to_string().into()
+            } else if field.name.starts_with('[') {
+                format!("{}{}", prefix, field.name).into()
+            } else {
+                format!("{}.{}", prefix, field.name).into()
+            };
+        };
+        match &mut self {
+            ConstraintViolation::Field(value) => prefixed(&mut value.field),
+            ConstraintViolation::Relation(value) => {
+                prefixed(&mut value.field1);
+                prefixed(&mut value.field2);
+            }
+            ConstraintViolation::FieldSet(value) => value.fields.iter_mut().for_each(prefixed),
+            ConstraintViolation::State(_) => {}
+        }
+        self
+    }
+
+    /// Returns the value of the parameter with the given name.
+    ///
+    /// Only constraint violations in the [`State`] context have parameters.
+    /// For all other violations `None` is returned.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn param(&self, name: &str) -> Option<&Value> {
+        match self {
+            ConstraintViolation::State(value) => value
+                .params
+                .iter()
+                .find(|param| param.name == name)
+                .map(|param| &param.value),
+            _ => None,
+        }
+    }
+}
+
 impl From<InvalidValue> for ConstraintViolation {
     fn from(invalid_value: InvalidValue) -> Self {
         ConstraintViolation::Field(invalid_value)
@@ -960,6 +2296,12 @@ impl From<InvalidRelation> for ConstraintViolation {
     }
 }
 
+impl From<InvalidFieldSet> for ConstraintViolation {
+    fn from(invalid_field_set: InvalidFieldSet) -> Self {
+        ConstraintViolation::FieldSet(invalid_field_set)
+    }
+}
+
 impl From<InvalidState> for ConstraintViolation {
     fn from(invalid_state: InvalidState) -> Self {
         ConstraintViolation::State(invalid_state)
@@ -991,20 +2333,95 @@ pub struct ValidationError {
 
     /// A list of constraint violations found during validation
     pub violations: Vec<ConstraintViolation>,
+
+    /// Whether the list of constraint violations is incomplete.
+    ///
+    /// This is `true` if the validation has been stopped after reaching the
+    /// limit of errors of its [`ValidationMode`].
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub truncated: bool,
 }
 
 impl Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.message {
-            Some(message) => write!(f, "{}: {}", message, array_to_string(&self.violations)),
-            None => write!(f, "{}", array_to_string(&self.violations)),
+            Some(message) => write!(f, "{}: {}", message, array_to_string(&self.violations))?,
+            None => write!(f, "{}", array_to_string(&self.violations))?,
         }
+        if self.truncated {
+            write!(f, " (truncated)")?;
+        }
+        Ok(())
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ValidationError {}
 
 impl ValidationError {
+    /// Constructs a validation error with the given message and list of
+    /// constraint violations.
+    ///
+    /// The constructed error is not truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_value, ValidationError};
+    ///
+    /// let error = ValidationError::new(
+    ///     Some("validating a user's age".into()),
+    ///     vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "validating a user's age: [ invalid-bound-min of age which is 12, expected to be 13 ]"
+    /// );
+    /// assert!(!error.truncated);
+    /// ```
+    pub fn new(
+        message: Option<Cow<'static, str>>,
+        violations: impl IntoIterator<Item = ConstraintViolation>,
+    ) -> Self {
+        ValidationError {
+            message,
+            violations: Vec::from_iter(violations),
+            truncated: false,
+        }
+    }
+
+    /// Sets the message that describes the context in which the validation
+    /// has been performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::constraint::Bound;
+    /// use valid::Validate;
+    ///
+    /// let error = 12
+    ///     .validate("age", &Bound::Min(13))
+    ///     .result_with_warnings()
+    ///     .map_err(|error| error.with_message("validating a user's age"))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(error.message, Some("validating a user's age".into()));
+    /// ```
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets whether the list of constraint violations of this error is
+    /// incomplete.
+    pub fn with_truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
+
     /// Merges this validation error with another validation error and returns
     /// a new validation error that contains all constraint violations from
     /// both errors merged into one list.
@@ -1014,33 +2431,33 @@ impl ValidationError {
     /// two errors contain a message than this message becomes the message of
     /// the resulting error.
     ///
+    /// The merged error is truncated if any of the two errors is truncated.
+    ///
     /// # Examples
     ///
     /// ```
     /// use valid::{ValidationError, invalid_value};
     ///
-    /// let validation_error1 = ValidationError {
-    ///     message: Some("validating a user's age".into()),
-    ///     violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
-    /// };
-    /// let validation_error2 = ValidationError {
-    ///     message: Some("validating a user registration command".into()),
-    ///     violations: vec![invalid_value("invalid-length-min", "username", 3, 4)],
-    /// };
+    /// let validation_error1 = ValidationError::new(
+    ///     Some("validating a user's age".into()),
+    ///     vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+    /// );
+    /// let validation_error2 = ValidationError::new(
+    ///     Some("validating a user registration command".into()),
+    ///     vec![invalid_value("invalid-length-min", "username", 3, 4)],
+    /// );
     ///
     /// let merged_error = validation_error2.merge(validation_error1);
     ///
     /// assert_eq!(
     ///     merged_error,
-    ///     ValidationError {
-    ///         message: Some(
-    ///             "validating a user registration command / validating a user's age".into()
-    ///         ),
-    ///         violations: vec![
+    ///     ValidationError::new(
+    ///         Some("validating a user registration command / validating a user's age".into()),
+    ///         vec![
     ///             invalid_value("invalid-length-min", "username", 3, 4),
     ///             invalid_value("invalid-bound-min", "age", 12, 13),
-    ///         ]
-    ///     }
+    ///         ],
+    ///     )
     /// );
     /// ```
     pub fn merge(mut self, other: ValidationError) -> Self {
@@ -1051,8 +2468,156 @@ impl ValidationError {
             (None, None) => None,
         };
         self.violations.extend(other.violations);
+        self.truncated = self.truncated || other.truncated;
         self
     }
+
+    /// Returns whether any of the constraint violations has the given error
+    /// code.
+    pub fn has_code(&self, code: &str) -> bool {
+        self.violations
+            .iter()
+            .any(|violation| violation.has_code(code))
+    }
+
+    /// Returns an iterator over the constraint violations that refer to the
+    /// field of the given name.
+    ///
+    /// See [`ConstraintViolation::field_names`] for the fields a constraint
+    /// violation refers to.
+    ///
+    /// [`ConstraintViolation::field_names`]: enum.ConstraintViolation.html#method.field_names
+    pub fn violations_for_field<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a ConstraintViolation> + 'a {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.is_for_field(name))
+    }
+
+    /// Returns an iterator over the constraint violations with the given
+    /// error code.
+    pub fn violations_with_code<'a>(
+        &'a self,
+        code: &'a str,
+    ) -> impl Iterator<Item = &'a ConstraintViolation> + 'a {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.has_code(code))
+    }
+
+    /// Groups the constraint violations by the names of the fields they
+    /// refer to.
+    ///
+    /// A violation that refers to several fields, like a violation of a
+    /// constraint on related fields, is contained in the group of each of
+    /// those fields. Within a group the violations keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use valid::{invalid_relation, invalid_value, ValidationError};
+    ///
+    /// let validation_error = ValidationError::new(
+    ///     None,
+    ///     vec![
+    ///         invalid_value("invalid-length-min", "password", 4, 8),
+    ///         invalid_value("invalid-length-min", "username", 2, 4),
+    ///         invalid_relation(
+    ///             "invalid-must-match",
+    ///             "password",
+    ///             "s3cr3t".to_string(),
+    ///             "password2",
+    ///             "s3crEt".to_string(),
+    ///         ),
+    ///     ],
+    /// );
+    ///
+    /// let groups = validation_error.group_by_field();
+    ///
+    /// assert_eq!(groups.keys().collect::<Vec<_>>(), vec![&"password", &"password2", &"username"]);
+    /// assert_eq!(groups["password"].len(), 2);
+    /// assert_eq!(groups["password2"][0].code(), "invalid-must-match");
+    /// ```
+    pub fn group_by_field(&self) -> BTreeMap<&str, Vec<&ConstraintViolation>> {
+        let mut groups = BTreeMap::<&str, Vec<&ConstraintViolation>>::new();
+        for violation in &self.violations {
+            for name in violation.field_names() {
+                let group = groups.entry(name).or_default();
+                if !matches!(group.last(), Some(last) if core::ptr::eq(*last, violation)) {
+                    group.push(violation);
+                }
+            }
+        }
+        groups
+    }
+
+    /// Returns the first constraint violation for each field.
+    ///
+    /// This is useful for displaying only one error message per input field.
+    pub fn first_per_field(&self) -> BTreeMap<&str, &ConstraintViolation> {
+        let mut firsts = BTreeMap::new();
+        for violation in &self.violations {
+            for name in violation.field_names() {
+                firsts.entry(name).or_insert(violation);
+            }
+        }
+        firsts
+    }
+
+    /// Sorts the constraint violations by the name of the first field they
+    /// refer to.
+    ///
+    /// The sort is stable, so violations of the same field keep their order.
+    /// Violations that do not refer to any field are moved to the end.
+    pub fn sort_by_field(&mut self) {
+        self.violations.sort_by(|violation1, violation2| {
+            let field1 = violation1.field_names().next();
+            let field2 = violation2.field_names().next();
+            (field1.is_none(), field1).cmp(&(field2.is_none(), field2))
+        });
+    }
+
+    /// Sorts the constraint violations by their error code.
+    ///
+    /// The sort is stable, so violations with the same code keep their order.
+    pub fn sort_by_code(&mut self) {
+        self.violations
+            .sort_by(|violation1, violation2| violation1.code().cmp(violation2.code()));
+    }
+
+    /// Removes duplicate constraint violations.
+    ///
+    /// Of identical constraint violations only the first one is kept. The
+    /// remaining violations keep their order.
+    pub fn dedup(&mut self) {
+        // Sorting by error code and field names puts identical violations
+        // into the same run, so only the violations of a run are compared.
+        let violations = &self.violations;
+        let compare = |violation1: &ConstraintViolation, violation2: &ConstraintViolation| {
+            violation1
+                .code()
+                .cmp(violation2.code())
+                .then_with(|| violation1.field_names().cmp(violation2.field_names()))
+        };
+        let mut indices = (0..violations.len()).collect::<Vec<_>>();
+        indices.sort_by(|&index1, &index2| compare(&violations[index1], &violations[index2]));
+        let mut duplicates = vec![false; violations.len()];
+        let mut run = 0;
+        for position in 0..indices.len() {
+            let violation = &violations[indices[position]];
+            if compare(&violations[indices[run]], violation) != Ordering::Equal {
+                run = position;
+            }
+            duplicates[indices[position]] = indices[run..position]
+                .iter()
+                .any(|&index| violations[index] == *violation);
+        }
+        let mut duplicates = duplicates.into_iter();
+        self.violations
+            .retain(|_| !duplicates.next().unwrap_or_default());
+    }
 }
 
 /// Type alias for the validation result for shorter type annotations.
@@ -1075,6 +2640,7 @@ pub fn invalid_value(
 ) -> ConstraintViolation {
     ConstraintViolation::Field(InvalidValue {
         code: code.into(),
+        severity: Severity::Error,
         field: Field {
             name: field_name.into().unwrap(),
             actual: Some(actual_value.into()),
@@ -1100,6 +2666,7 @@ pub fn invalid_optional_value(
 ) -> ConstraintViolation {
     ConstraintViolation::Field(InvalidValue {
         code: code.into(),
+        severity: Severity::Error,
         field: Field {
             name: field_name.into().unwrap(),
             actual,
@@ -1122,6 +2689,7 @@ pub fn invalid_relation(
 ) -> ConstraintViolation {
     ConstraintViolation::Relation(InvalidRelation {
         code: code.into(),
+        severity: Severity::Error,
         field1: Field {
             name: field_name1.into(),
             actual: Some(field_value1.into()),
@@ -1135,6 +2703,65 @@ pub fn invalid_relation(
     })
 }
 
+/// Convenience function to construct a [`ConstraintViolation`] for a validation
+/// performed in the [`RelatedFields`] context.
+///
+/// Use this method if the values of the related fields are optional. If the
+/// fields are not of type `Option<T>` consider using the [`invalid_relation`]
+/// method instead.
+///
+/// [`ConstraintViolation`]: enum.ConstraintViolation.html
+/// [`RelatedFields`]: struct.RelatedFields.html
+/// [`invalid_relation`]: fn.invalid_relation.html
+pub fn invalid_optional_relation(
+    code: impl Into<Cow<'static, str>>,
+    field_name1: impl Into<Cow<'static, str>>,
+    field_value1: Option<Value>,
+    field_name2: impl Into<Cow<'static, str>>,
+    field_value2: Option<Value>,
+) -> ConstraintViolation {
+    ConstraintViolation::Relation(InvalidRelation {
+        code: code.into(),
+        severity: Severity::Error,
+        field1: Field {
+            name: field_name1.into(),
+            actual: field_value1,
+            expected: None,
+        },
+        field2: Field {
+            name: field_name2.into(),
+            actual: field_value2,
+            expected: None,
+        },
+    })
+}
+
+/// Convenience function to construct a [`ConstraintViolation`] for a validation
+/// performed in the [`RelatedFieldSet`] context.
+///
+/// The fields are given as pairs of the field name and the optional actual
+/// value of the field.
+///
+/// [`ConstraintViolation`]: enum.ConstraintViolation.html
+/// [`RelatedFieldSet`]: struct.RelatedFieldSet.html
+pub fn invalid_field_set(
+    code: impl Into<Cow<'static, str>>,
+    fields: impl IntoIterator<Item = (Cow<'static, str>, Option<Value>)>,
+) -> ConstraintViolation {
+    ConstraintViolation::FieldSet(InvalidFieldSet {
+        code: code.into(),
+        severity: Severity::Error,
+        fields: fields
+            .into_iter()
+            .map(|(name, actual)| Field {
+                name,
+                actual,
+                expected: None,
+            })
+            .collect(),
+    })
+}
+
 /// Convenience function to construct a [`ConstraintViolation`] for a validation
 /// performed in the [`State`] context.
 ///
@@ -1146,7 +2773,8 @@ pub fn invalid_state(
 ) -> ConstraintViolation {
     ConstraintViolation::State(InvalidState {
         code: code.into(),
-        params: Vec::from_iter(params.into_iter()),
+        severity: Severity::Error,
+        params: Vec::from_iter(params),
     })
 }
 
@@ -1160,5 +2788,15 @@ pub fn param(name: impl Into<Cow<'static, str>>, value: impl Into<Value>) -> Par
     }
 }
 
+mod validator;
+mod value;
+
+pub use self::validator::Validator;
+
 #[cfg(test)]
+#[allow(
+    deprecated,
+    clippy::legacy_numeric_constants,
+    clippy::unnecessary_fallible_conversions
+)]
 mod tests;
@@ -3,19 +3,22 @@ use proptest::prelude::*;
 
 mod validated {
     use super::*;
-    use crate::constraint::{Bound, NotEmpty};
+    use crate::constraint::{Bound, Length, NotEmpty};
 
     #[test]
     fn unfortunately_we_can_construct_an_instance_of_validated_without_doing_any_validation() {
         //TODO find a way to prevent this from compiling and still support the
         //     possibility for custom implementations of the `Validate` trait
 
-        let value: Validated<Bound<i32>, i32> = Validation::success(42).result().unwrap();
+        let value: Validated<Bound<i32>, i32> =
+            Validation::success(42).result_with_warnings().unwrap().0;
 
         assert_eq!(value.unwrap(), 42);
 
-        let value: Validated<NotEmpty, String> =
-            Validation::success("invalid".to_string()).result().unwrap();
+        let value: Validated<NotEmpty, String> = Validation::success("invalid".to_string())
+            .result_with_warnings()
+            .unwrap()
+            .0;
 
         assert_eq!(value.unwrap(), "invalid");
     }
@@ -42,6 +45,159 @@ mod validated {
             r#"Validated(_, "2839-234892-222")"#
         );
     }
+
+    #[test]
+    fn implements_display() {
+        struct Isbn;
+
+        let validated: Validated<Isbn, String> =
+            Validated(PhantomData, "2839-234892-222".to_string());
+
+        assert_eq!(validated.to_string(), "2839-234892-222");
+    }
+
+    #[test]
+    fn implements_ord() {
+        struct Positive;
+
+        let mut values: Vec<Validated<Positive, i32>> = vec![
+            Validated(PhantomData, 3),
+            Validated(PhantomData, 1),
+            Validated(PhantomData, 2),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values
+                .into_iter()
+                .map(Validated::unwrap)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(Validated::<Positive, f64>(PhantomData, 0.5) < Validated(PhantomData, 1.5));
+    }
+
+    #[test]
+    fn can_be_used_as_key_in_a_hash_map_and_looked_up_by_the_inner_value() {
+        use std::collections::HashMap;
+
+        struct Username;
+
+        let mut logins: HashMap<Validated<Username, String>, u32> = HashMap::new();
+        logins.insert(Validated(PhantomData, "jane".to_string()), 3);
+        let inner = String::from("jane");
+
+        assert_eq!(logins.get(&inner), Some(&3));
+        assert_eq!(
+            logins.get(&Validated(PhantomData, "jane".to_string())),
+            Some(&3)
+        );
+    }
+
+    #[test]
+    fn can_be_passed_as_reference_to_the_target_of_as_ref() {
+        struct Username;
+
+        fn length(value: impl AsRef<str>) -> usize {
+            value.as_ref().len()
+        }
+
+        let validated: Validated<Username, String> = Validated(PhantomData, "jane".to_string());
+
+        assert_eq!(length(&validated), 4);
+        assert_eq!(AsRef::<[u8]>::as_ref(&validated), b"jane");
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn is_serialized_as_its_inner_value() {
+        struct Username;
+
+        let validated: Validated<Username, String> = Validated(PhantomData, "jane".to_string());
+
+        assert_eq!(serde_json::to_string(&validated).unwrap(), r#""jane""#);
+    }
+
+    #[test]
+    fn refine_a_validated_value_with_another_constraint() {
+        let (validated, _) = 42
+            .validate("age", &Bound::Min(13))
+            .result_with_warnings()
+            .unwrap();
+
+        let refined: Validation<(Bound<i32>, Bound<i32>), i32> =
+            validated.refine("age", &Bound::Max(120));
+        let (refined, _) = refined.result_with_warnings().unwrap();
+
+        assert_eq!(refined.unwrap(), 42);
+    }
+
+    #[test]
+    fn refine_a_validated_value_with_a_violated_constraint() {
+        let (validated, _) = String::new()
+            .validate("name", &Length::Max(20))
+            .result_with_warnings()
+            .unwrap();
+
+        let result = validated.refine("name", &NotEmpty).result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![invalid_optional_value(
+                    "invalid-not-empty",
+                    "name",
+                    None,
+                    None
+                )],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn refine_can_be_chained() {
+        let validated = 42
+            .validate("age", &Bound::Min(13))
+            .result_with_warnings()
+            .unwrap()
+            .0
+            .refine("age", &Bound::Max(120))
+            .result_with_warnings()
+            .unwrap()
+            .0
+            .refine("age", &Bound::LessThan(100))
+            .result_with_warnings()
+            .unwrap()
+            .0;
+
+        type Age = Validated<((Bound<i32>, Bound<i32>), Bound<i32>), i32>;
+        let age: Age = validated;
+        assert_eq!(*age, 42);
+    }
+
+    #[test]
+    fn weaken_drops_the_last_constraint() {
+        struct Isbn;
+        let validated: Validated<(NotEmpty, Isbn), String> =
+            Validated(PhantomData, "2839-234892-222".to_string());
+
+        let weakened: Validated<NotEmpty, String> = validated.weaken();
+
+        assert_eq!(weakened.unwrap(), "2839-234892-222");
+    }
+
+    #[test]
+    fn swap_and_weaken_drops_the_first_constraint() {
+        struct Isbn;
+        let validated: Validated<(NotEmpty, Isbn), String> =
+            Validated(PhantomData, "2839-234892-222".to_string());
+
+        let weakened: Validated<Isbn, String> = validated.swap().weaken();
+
+        assert_eq!(weakened.unwrap(), "2839-234892-222");
+    }
 }
 
 mod context {
@@ -112,6 +268,25 @@ mod context {
         );
     }
 
+    #[test]
+    fn can_convert_an_array_of_str_into_a_related_field_set_context() {
+        let related_field_set: RelatedFieldSet = ["phone", "email", "address"].into();
+
+        assert_eq!(
+            related_field_set,
+            RelatedFieldSet(vec!["phone".into(), "email".into(), "address".into()])
+        );
+    }
+
+    #[test]
+    fn can_dereference_a_related_field_set_context_to_a_slice_of_names() {
+        let related_field_set: RelatedFieldSet = vec!["street", "city"].into();
+
+        let names: &[Cow<'static, str>] = &related_field_set;
+
+        assert_eq!(names, &["street", "city"]);
+    }
+
     #[test]
     fn can_convert_a_custom_value_into_a_state_context() {
         let state: State<Vec<_>> = vec![25, 50, 75].into();
@@ -136,12 +311,89 @@ mod context {
 
         assert_eq!(inner_value, vec![25, 50, 75]);
     }
+
+    #[test]
+    fn can_convert_a_tuple_of_str_and_state_into_a_field_state_context() {
+        let field_state: FieldState<Vec<_>> = ("quantity", vec![1, 2]).into();
+
+        assert_eq!(field_state, FieldState("quantity".into(), vec![1, 2]));
+        assert_eq!(field_state.name(), "quantity");
+        assert_eq!(field_state.state(), &vec![1, 2]);
+    }
+
+    #[test]
+    fn can_dereference_a_field_state_context_to_its_state() {
+        let field_state: FieldState<Vec<_>> = ("quantity", vec![25, 50]).into();
+
+        let state: &[_] = &field_state;
+
+        assert_eq!(state, &[25, 50]);
+    }
+
+    #[test]
+    fn can_unwrap_a_field_state_context_into_a_tuple() {
+        let field_state = FieldState("quantity".into(), 42);
+
+        let inner_tuple = field_state.unwrap();
+
+        assert_eq!(inner_tuple, (Cow::Borrowed("quantity"), 42));
+    }
+
+    #[test]
+    fn validation_in_a_field_state_context_reports_a_field_violation() {
+        struct InStock;
+
+        impl<'a> Validate<InStock, FieldState<&'a [(&'static str, u32)]>> for (&'static str, u32) {
+            fn validate(
+                self,
+                context: impl Into<FieldState<&'a [(&'static str, u32)]>>,
+                _constraint: &InStock,
+            ) -> Validation<InStock, Self> {
+                let context = context.into();
+                let (item, quantity) = self;
+                let available = context
+                    .iter()
+                    .find(|(stocked, _)| *stocked == item)
+                    .map_or(0, |(_, available)| *available);
+                if quantity > available {
+                    Validation::failure(vec![invalid_value(
+                        "invalid-in-stock",
+                        context.name().to_string(),
+                        quantity,
+                        available,
+                    )])
+                } else {
+                    Validation::success(self)
+                }
+            }
+        }
+
+        let stock: &[(&'static str, u32)] = &[("apple", 3)];
+
+        let valid = ("apple", 2).validate(("order.quantity", stock), &InStock);
+        let invalid = ("apple", 5).validate(("order.quantity", stock), &InStock);
+
+        assert_eq!(
+            valid.result_with_warnings().unwrap().0.unwrap(),
+            ("apple", 2)
+        );
+        assert_eq!(
+            invalid.result_with_warnings().unwrap_err().violations,
+            vec![invalid_value(
+                "invalid-in-stock",
+                "order.quantity",
+                5u32,
+                3u32
+            )]
+        );
+    }
 }
 
 mod validation {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn get_the_result_of_a_successful_validation_without_a_message() {
         let validation: Validation<(), _> = Validation::success("valid text".to_string());
 
@@ -151,6 +403,7 @@ mod validation {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_the_result_of_a_failed_validation_without_a_message() {
         let validation: Validation<(), String> =
             Validation::failure(vec![invalid_state("invalid-unique-username", vec![])]);
@@ -163,14 +416,17 @@ mod validation {
                 message: None,
                 violations: vec![InvalidState {
                     code: "invalid-unique-username".into(),
+                    severity: Severity::Error,
                     params: vec![],
                 }
-                .into()]
+                .into()],
+                truncated: false,
             })
         );
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_the_result_of_a_successful_validation_with_a_message() {
         let validation: Validation<(), _> = Validation::success("valid text".to_string());
 
@@ -180,6 +436,7 @@ mod validation {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn get_the_result_of_a_failed_validation_with_a_message() {
         let validation: Validation<(), String> =
             Validation::failure(vec![invalid_state("invalid-unique-username", vec![])]);
@@ -192,13 +449,163 @@ mod validation {
                 message: Some("validating register new user command".into()),
                 violations: vec![InvalidState {
                     code: "invalid-unique-username".into(),
+                    severity: Severity::Error,
                     params: vec![],
                 }
-                .into()]
+                .into()],
+                truncated: false,
             })
         );
     }
 
+    #[test]
+    fn a_checked_validation_without_violations_is_successful() {
+        let validation: Validation<(), _> = Validation::checked("valid text".to_string(), vec![]);
+
+        let result = validation.result_with_warnings();
+
+        assert_eq!(
+            result,
+            Ok((Validated(PhantomData, "valid text".to_string()), vec![]))
+        );
+    }
+
+    #[test]
+    fn a_checked_validation_with_violations_is_failed() {
+        let validation: Validation<(), _> = Validation::checked(
+            "jane.doe".to_string(),
+            vec![invalid_state("invalid-unique-username", vec![])],
+        );
+
+        let result = validation.result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![InvalidState {
+                    code: "invalid-unique-username".into(),
+                    severity: Severity::Error,
+                    params: vec![],
+                }
+                .into()],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn a_checked_validation_with_warnings_only_is_successful() {
+        let validation: Validation<(), _> = Validation::checked(
+            "jane.doe".to_string(),
+            vec![invalid_value("invalid-length-max", "username", 8, 6)
+                .with_severity(Severity::Warning)],
+        );
+
+        let result = validation.result_with_warnings();
+
+        assert_eq!(
+            result,
+            Ok((
+                Validated(PhantomData, "jane.doe".to_string()),
+                vec![invalid_value("invalid-length-max", "username", 8, 6)
+                    .with_severity(Severity::Warning)]
+            ))
+        );
+    }
+
+    #[test]
+    fn a_checked_validation_with_warnings_and_errors_is_failed() {
+        let validation: Validation<(), _> = Validation::checked(
+            "jane.doe".to_string(),
+            vec![
+                invalid_value("invalid-length-max", "username", 8, 6).with_severity(Severity::Info),
+                invalid_state("invalid-unique-username", vec![]),
+            ],
+        );
+
+        let result = validation.result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_value("invalid-length-max", "username", 8, 6)
+                        .with_severity(Severity::Info),
+                    invalid_state("invalid-unique-username", vec![]),
+                ],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn the_result_of_a_successful_validation_drops_the_warnings() {
+        let validation: Validation<(), _> = Validation::success_with_warnings(
+            "jane.doe".to_string(),
+            vec![invalid_value("invalid-length-max", "username", 8, 6)
+                .with_severity(Severity::Warning)],
+        );
+
+        assert_eq!(validation.warnings().len(), 1);
+
+        let result = validation.result();
+
+        assert_eq!(result, Ok(Validated(PhantomData, "jane.doe".to_string())));
+    }
+
+    #[test]
+    fn combine_two_successful_validations_with_and_accumulates_the_warnings() {
+        let validation1: Validation<(), _> = Validation::success_with_warnings(
+            "jane.doe".to_string(),
+            vec![invalid_value("invalid-length-max", "username", 8, 6)
+                .with_severity(Severity::Warning)],
+        );
+        let validation2: Validation<(), _> = Validation::success_with_warnings(
+            101,
+            vec![invalid_value("invalid-bound-max", "age", 101, 100).with_severity(Severity::Info)],
+        );
+
+        let resulting_validation = validation1.and(validation2);
+
+        assert_eq!(
+            resulting_validation,
+            Validation::success_with_warnings(
+                ("jane.doe".to_string(), 101),
+                vec![
+                    invalid_value("invalid-length-max", "username", 8, 6)
+                        .with_severity(Severity::Warning),
+                    invalid_value("invalid-bound-max", "age", 101, 100)
+                        .with_severity(Severity::Info),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn combine_a_validation_with_warnings_with_a_failed_validation_with_and_then() {
+        let validation1: Validation<(), _> = Validation::success_with_warnings(
+            "jane.doe".to_string(),
+            vec![invalid_value("invalid-length-max", "username", 8, 6)
+                .with_severity(Severity::Warning)],
+        );
+
+        let resulting_validation: Validation<(), String> = validation1.and_then(|_username| {
+            Validation::failure(vec![invalid_state("invalid-unique-username", vec![])])
+        });
+
+        assert_eq!(
+            resulting_validation,
+            Validation::failure(vec![
+                invalid_value("invalid-length-max", "username", 8, 6)
+                    .with_severity(Severity::Warning),
+                invalid_state("invalid-unique-username", vec![]),
+            ])
+        );
+    }
+
     #[test]
     fn combine_a_successful_validation_with_another_value_that_needs_no_further_validation() {
         let validation: Validation<(), _> = Validation::success("valid text".to_string());
@@ -424,32 +831,733 @@ mod validation {
     }
 
     #[test]
-    fn combine_two_validations_with_and_then_where_both_are_failing() {
-        let _password = String::from("s3");
-        let _password2 = String::from("s3crEt");
-
-        let validation1: Validation<(), String> =
-            Validation::failure(vec![invalid_value("invalid-length-min", "password", 2, 6)]);
+    fn combine_two_validations_with_and_then_where_both_are_failing() {
+        let _password = String::from("s3");
+        let _password2 = String::from("s3crEt");
+
+        let validation1: Validation<(), String> =
+            Validation::failure(vec![invalid_value("invalid-length-min", "password", 2, 6)]);
+
+        let resulting_validation: Validation<(), String> = validation1.and_then(|_password| {
+            Validation::failure(vec![invalid_relation(
+                "invalid-must-match",
+                "password",
+                "s3cr3t".to_string(),
+                "password2",
+                "s3crEt".to_string(),
+            )])
+        });
+
+        assert_eq!(
+            resulting_validation,
+            Validation::failure(vec![invalid_value("invalid-length-min", "password", 2, 6)])
+        );
+    }
+}
+
+mod severity {
+    use super::*;
+
+    #[test]
+    fn constraint_violations_constructed_by_convenience_functions_are_errors() {
+        let violation = invalid_value("invalid-length-min", "username", 3, 4);
+
+        assert_eq!(violation.severity(), Severity::Error);
+        assert!(violation.is_error());
+    }
+
+    #[test]
+    fn can_change_the_severity_of_a_constraint_violation() {
+        let violation = invalid_relation(
+            "invalid-must-match",
+            "password",
+            "a".to_string(),
+            "password2",
+            "b".to_string(),
+        )
+        .with_severity(Severity::Info);
+
+        assert_eq!(violation.severity(), Severity::Info);
+        assert!(!violation.is_error());
+    }
+
+    #[test]
+    fn display_format_a_severity() {
+        assert_eq!(Severity::Error.to_string(), "error");
+        assert_eq!(Severity::Warning.to_string(), "warning");
+        assert_eq!(Severity::Info.to_string(), "info");
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn serialized_constraint_violation_contains_the_severity() {
+        let violation =
+            invalid_value("invalid-length-max", "username", 8, 6).with_severity(Severity::Warning);
+
+        let json = serde_json::to_value(&violation).unwrap();
+
+        assert_eq!(json["Field"]["severity"], "warning");
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn deserialized_constraint_violation_without_severity_is_an_error() {
+        let json = r#"{"State":{"code":"invalid-unique-username","params":[]}}"#;
+
+        let violation: ConstraintViolation = serde_json::from_str(json).unwrap();
+
+        assert_eq!(violation, invalid_state("invalid-unique-username", vec![]));
+    }
+}
+
+mod validation_mode {
+    use super::*;
+
+    fn failed(code: &'static str) -> Validation<(), i32> {
+        Validation::failure(vec![invalid_state(code, vec![])])
+    }
+
+    #[test]
+    fn complete_mode_accumulates_all_violations() {
+        let validation = failed("first")
+            .with_mode(ValidationMode::Complete)
+            .and(failed("second"))
+            .and(failed("third"));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(
+            error,
+            ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_state("first", vec![]),
+                    invalid_state("second", vec![]),
+                    invalid_state("third", vec![]),
+                ],
+                truncated: false,
+            }
+        );
+    }
+
+    #[test]
+    fn fail_fast_mode_keeps_only_the_first_error_of_and() {
+        let validation = failed("first")
+            .with_mode(ValidationMode::FailFast)
+            .and(failed("second"))
+            .and(failed("third"));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(
+            error,
+            ValidationError {
+                message: None,
+                violations: vec![invalid_state("first", vec![])],
+                truncated: true,
+            }
+        );
+    }
+
+    #[test]
+    fn limit_mode_keeps_the_given_number_of_errors() {
+        let validation = Validation::<(), i32>::success(1)
+            .with_mode(ValidationMode::Limit(2))
+            .and(failed("first"))
+            .and(failed("second"))
+            .and(failed("third"));
+
+        let error = validation
+            .result_with_warnings()
+            .map_err(|error| error.with_message("validating numbers"))
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            ValidationError {
+                message: Some("validating numbers".into()),
+                violations: vec![
+                    invalid_state("first", vec![]),
+                    invalid_state("second", vec![]),
+                ],
+                truncated: true,
+            }
+        );
+    }
+
+    #[test]
+    fn warnings_do_not_count_against_the_limit() {
+        let validation = Validation::<(), i32>::success_with_warnings(
+            1,
+            vec![invalid_state("warning", vec![]).with_severity(Severity::Warning)],
+        )
+        .with_mode(ValidationMode::FailFast)
+        .and(failed("first"));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(
+            error.violations,
+            vec![
+                invalid_state("warning", vec![]).with_severity(Severity::Warning),
+                invalid_state("first", vec![]),
+            ]
+        );
+        assert!(!error.truncated);
+    }
+
+    #[test]
+    fn and_skips_a_closure_when_the_limit_is_reached() {
+        let mut executed = false;
+
+        let validation = failed("first").with_mode(ValidationMode::FailFast).and(|| {
+            executed = true;
+            failed("second")
+        });
+
+        assert!(!executed);
+        assert_eq!(
+            validation.result_with_warnings().unwrap_err().violations,
+            vec![invalid_state("first", vec![])]
+        );
+    }
+
+    #[test]
+    fn and_with_a_successful_validation_after_the_limit_is_not_truncated() {
+        let validation = failed("first")
+            .with_mode(ValidationMode::FailFast)
+            .and(Validation::<(), i32>::success(42));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(error.violations, vec![invalid_state("first", vec![])]);
+        assert!(!error.truncated);
+    }
+
+    #[test]
+    fn and_with_a_failed_validation_after_the_limit_is_truncated() {
+        let validation = failed("first")
+            .with_mode(ValidationMode::FailFast)
+            .and(failed("second"));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(error.violations, vec![invalid_state("first", vec![])]);
+        assert!(error.truncated);
+    }
+
+    #[test]
+    fn and_executes_a_closure_when_the_limit_is_not_reached() {
+        let validation = failed("first")
+            .with_mode(ValidationMode::Limit(2))
+            .and(|| failed("second"));
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(
+            error.violations,
+            vec![
+                invalid_state("first", vec![]),
+                invalid_state("second", vec![]),
+            ]
+        );
+        assert!(!error.truncated);
+    }
+
+    #[test]
+    fn the_mode_is_passed_on_by_and_then() {
+        let validation = Validation::<(), i32>::success(1)
+            .with_mode(ValidationMode::FailFast)
+            .and_then(|_| failed("first").and(failed("second")));
+
+        assert_eq!(validation.mode(), ValidationMode::FailFast);
+        assert_eq!(
+            validation.result_with_warnings().unwrap_err().violations,
+            vec![invalid_state("first", vec![])]
+        );
+    }
+
+    #[test]
+    fn each_validates_all_elements_of_a_valid_collection() {
+        let validation: Validation<(), Vec<i32>> =
+            Validation::each(ValidationMode::FailFast, vec![1, 2, 3], Validation::success);
+
+        assert_eq!(
+            validation.result_with_warnings(),
+            Ok((Validated(PhantomData, vec![1, 2, 3]), vec![]))
+        );
+    }
+
+    #[test]
+    fn each_stops_validating_elements_when_the_limit_is_reached() {
+        let mut validated_elements = Vec::new();
+
+        let validation: Validation<(), Vec<i32>> =
+            Validation::each(ValidationMode::Limit(2), 0..100_000, |element| {
+                validated_elements.push(element);
+                failed("invalid-element")
+            });
+
+        assert_eq!(validated_elements, vec![0, 1]);
+        assert_eq!(
+            validation.result_with_warnings(),
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_state("invalid-element", vec![]),
+                    invalid_state("invalid-element", vec![]),
+                ],
+                truncated: true,
+            })
+        );
+    }
+
+    #[test]
+    fn each_collects_all_violations_in_complete_mode() {
+        let validation: Validation<(), Vec<i32>> =
+            Validation::each(ValidationMode::Complete, 0..4, |element| {
+                if element % 2 == 0 {
+                    failed("invalid-even")
+                } else {
+                    Validation::success(element)
+                }
+            });
+
+        let error = validation.result_with_warnings().unwrap_err();
+
+        assert_eq!(error.violations.len(), 2);
+        assert!(!error.truncated);
+    }
+
+    #[test]
+    fn display_format_a_truncated_validation_error() {
+        let error = ValidationError {
+            message: Some("validating numbers".into()),
+            violations: vec![invalid_state("first", vec![])],
+            truncated: true,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "validating numbers: [ first for parameters: [] ] (truncated)"
+        );
+    }
+}
+
+mod collect_validations {
+    use super::*;
+    use crate::constraint::{Bound, NotEmpty};
+    use std::collections::{BTreeSet, VecDeque};
+
+    fn validate_age(age: i32) -> Validation<Bound<i32>, i32> {
+        age.validate("age", &Bound::ClosedRange(13, 120))
+    }
+
+    #[test]
+    fn collect_successful_validations_into_a_vec() {
+        let validation: Validation<_, Vec<_>> =
+            vec![13, 42, 120].into_iter().map(validate_age).collect();
+
+        assert_eq!(
+            validation.result_with_warnings().unwrap().0.unwrap(),
+            vec![13, 42, 120]
+        );
+    }
+
+    #[test]
+    fn collect_an_empty_iterator_is_successful() {
+        let validation: Validation<Bound<i32>, Vec<i32>> = vec![].into_iter().collect();
+
+        assert_eq!(
+            validation.result_with_warnings().unwrap().0.unwrap(),
+            Vec::<i32>::new()
+        );
+    }
+
+    #[test]
+    fn collect_validations_accumulates_all_violations() {
+        let validation: Validation<_, Vec<_>> =
+            vec![12, 42, 121, 7].into_iter().map(validate_age).collect();
+
+        assert_eq!(
+            validation.result_with_warnings(),
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_value("invalid-bound-closed-min", "age", 12, 13),
+                    invalid_value("invalid-bound-closed-max", "age", 121, 120),
+                    invalid_value("invalid-bound-closed-min", "age", 7, 13),
+                ],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn collect_validations_into_other_collections() {
+        let ages = [42, 13, 42];
+
+        let set: Validation<_, BTreeSet<_>> = ages.iter().copied().map(validate_age).collect();
+        let deque: Validation<_, VecDeque<_>> = ages.iter().copied().map(validate_age).collect();
+
+        assert_eq!(
+            set.result_with_warnings().unwrap().0.unwrap(),
+            vec![13, 42].into_iter().collect::<BTreeSet<_>>()
+        );
+        assert_eq!(
+            deque.result_with_warnings().unwrap().0.unwrap(),
+            vec![42, 13, 42].into_iter().collect::<VecDeque<_>>()
+        );
+    }
+
+    #[test]
+    fn collect_validations_keeps_warnings_and_truncated_flags() {
+        let warning = invalid_value("invalid-length-max", "nickname", 18, 15)
+            .with_severity(Severity::Warning);
+        let validations: Vec<Validation<(), i32>> = vec![
+            Validation::success_with_warnings(1, vec![warning.clone()]),
+            Validation::failure(vec![
+                invalid_state("first", vec![]),
+                invalid_state("second", vec![]),
+            ])
+            .with_mode(ValidationMode::FailFast),
+        ];
+
+        let validation: Validation<(), Vec<i32>> = validations.into_iter().collect();
+
+        assert_eq!(
+            validation.result_with_warnings(),
+            Err(ValidationError {
+                message: None,
+                violations: vec![warning, invalid_state("first", vec![])],
+                truncated: true,
+            })
+        );
+    }
+
+    #[test]
+    fn extend_a_successful_validation_with_successful_validations() {
+        let mut validation: Validation<_, Vec<_>> = vec![validate_age(20)].into_iter().collect();
+
+        validation.extend(vec![validate_age(30), validate_age(40)]);
+
+        assert_eq!(
+            validation.result_with_warnings().unwrap().0.unwrap(),
+            vec![20, 30, 40]
+        );
+    }
+
+    #[test]
+    fn extend_a_validation_accumulates_all_violations() {
+        let mut validation: Validation<_, Vec<_>> = vec![validate_age(7)].into_iter().collect();
+
+        validation.extend(vec![validate_age(30), validate_age(121)]);
+
+        assert_eq!(
+            validation.result_with_warnings().unwrap_err().violations,
+            vec![
+                invalid_value("invalid-bound-closed-min", "age", 7, 13),
+                invalid_value("invalid-bound-closed-max", "age", 121, 120),
+            ]
+        );
+    }
+
+    #[test]
+    fn extend_a_validation_applies_its_validation_mode() {
+        let mut validation: Validation<_, Vec<_>> =
+            Validation::success(vec![]).with_mode(ValidationMode::Limit(2));
+
+        validation.extend(vec![validate_age(1), validate_age(2), validate_age(3)]);
+
+        let error = validation.result_with_warnings().unwrap_err();
+        assert_eq!(error.violations.len(), 2);
+        assert!(error.truncated);
+    }
+
+    #[test]
+    fn annotate_violations_with_the_index_of_the_element() {
+        let names = vec!["jane".to_string(), String::new(), String::new()];
+
+        let validation: Validation<_, Vec<_>> = names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| name.validate("name", &NotEmpty).with_index(index))
+            .collect();
+
+        assert_eq!(
+            validation
+                .with_field_prefix("authors")
+                .result_with_warnings()
+                .unwrap_err()
+                .violations,
+            vec![
+                invalid_optional_value("invalid-not-empty", "authors[1].name", None, None),
+                invalid_optional_value("invalid-not-empty", "authors[2].name", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn field_prefix_is_applied_to_warnings() {
+        let validation: Validation<(), i32> = Validation::success_with_warnings(
+            1,
+            vec![invalid_value("invalid-length-max", "nickname", 18, 15)
+                .with_severity(Severity::Warning)],
+        );
+
+        let (_, warnings) = validation
+            .with_field_prefix("profile")
+            .result_with_warnings()
+            .unwrap();
+
+        assert!(warnings[0].is_for_field("profile.nickname"));
+    }
+
+    #[test]
+    fn field_prefix_is_applied_to_all_fields_of_relations_and_field_sets() {
+        let relation = invalid_relation("invalid-must-match", "password", 1, "password2", 2);
+        let field_set = invalid_field_set("invalid-all-or-none", vec![("a".into(), None)]);
+        let state = invalid_state("invalid-unique", vec![param("name", 1)]);
+
+        assert_eq!(
+            relation
+                .with_field_prefix("[0]")
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["[0].password", "[0].password2"]
+        );
+        assert_eq!(
+            field_set
+                .with_field_prefix("range")
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["range.a"]
+        );
+        assert_eq!(
+            state
+                .with_field_prefix("user")
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["name"]
+        );
+    }
+}
+
+mod validator {
+    use super::*;
+    use crate::constraint::{
+        AtLeastOneOf, CharCount, MustMatch, NotEmpty, RequiredIf, INVALID_AT_LEAST_ONE_OF,
+        INVALID_CHAR_COUNT_MAX, INVALID_MUST_MATCH, INVALID_NOT_EMPTY, INVALID_REQUIRED_IF,
+    };
+    use std::collections::HashSet;
+    use std::sync::{Arc, OnceLock};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct RegisterUser {
+        username: String,
+        password: String,
+        password2: String,
+        email: Option<String>,
+        phone: Option<String>,
+    }
+
+    struct IsUniqueUsername;
+
+    impl ValidateRef<IsUniqueUsername, State<Arc<HashSet<String>>>> for RegisterUser {
+        fn validate_ref(
+            &self,
+            context: impl Into<State<Arc<HashSet<String>>>>,
+            _constraint: &IsUniqueUsername,
+        ) -> Vec<ConstraintViolation> {
+            let usernames = context.into();
+            if usernames.contains(&self.username) {
+                vec![invalid_state(
+                    "invalid-unique-username",
+                    vec![param("username", self.username.clone())],
+                )]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    fn register_user_validator() -> &'static Validator<RegisterUser> {
+        static VALIDATOR: OnceLock<Validator<RegisterUser>> = OnceLock::new();
+        VALIDATOR.get_or_init(|| {
+            let usernames = Arc::new(HashSet::from(["jane.doe".to_string()]));
+            Validator::new()
+                .field("username", |cmd: &RegisterUser| &cmd.username, NotEmpty)
+                .field(
+                    "username",
+                    |cmd: &RegisterUser| &cmd.username,
+                    CharCount::Max(12),
+                )
+                .relation(
+                    ("password", "password2"),
+                    |cmd: &RegisterUser| (&cmd.password, &cmd.password2),
+                    MustMatch,
+                )
+                .field_set(
+                    ["email", "phone"],
+                    |cmd: &RegisterUser| (cmd.email.clone(), cmd.phone.clone()),
+                    AtLeastOneOf,
+                )
+                .state(usernames, IsUniqueUsername)
+        })
+    }
+
+    fn valid_command() -> RegisterUser {
+        RegisterUser {
+            username: "john.doe".into(),
+            password: "s3cr3t".into(),
+            password2: "s3cr3t".into(),
+            email: Some("john.doe@example.com".into()),
+            phone: None,
+        }
+    }
+
+    #[test]
+    fn a_validator_with_all_rules_satisfied_returns_the_validated_value() {
+        let result = register_user_validator()
+            .validate(valid_command())
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), valid_command());
+    }
+
+    #[test]
+    fn a_validator_collects_the_violations_of_all_rules() {
+        let command = RegisterUser {
+            username: "".into(),
+            password: "s3cr3t".into(),
+            password2: "s3crEt".into(),
+            email: None,
+            phone: None,
+        };
+
+        let result = register_user_validator()
+            .validate(command)
+            .result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_optional_value(INVALID_NOT_EMPTY, "username", None, None),
+                    invalid_relation(
+                        INVALID_MUST_MATCH,
+                        "password",
+                        "s3cr3t".to_string(),
+                        "password2",
+                        "s3crEt".to_string()
+                    ),
+                    invalid_field_set(
+                        INVALID_AT_LEAST_ONE_OF,
+                        vec![("email".into(), None), ("phone".into(), None)]
+                    ),
+                ],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn a_validator_executes_state_rules() {
+        let command = RegisterUser {
+            username: "jane.doe".into(),
+            ..valid_command()
+        };
+
+        let violations = register_user_validator().validate_ref(&command);
+
+        assert_eq!(
+            violations,
+            vec![invalid_state(
+                "invalid-unique-username",
+                vec![param("username", "jane.doe".to_string())]
+            )]
+        );
+    }
+
+    #[test]
+    fn a_validator_in_fail_fast_mode_skips_the_remaining_rules() {
+        let validator = Validator::new()
+            .with_mode(ValidationMode::FailFast)
+            .field(
+                "username",
+                |cmd: &RegisterUser| &cmd.username,
+                CharCount::Max(4),
+            )
+            .rule(|_| panic!("rule must not be executed"));
+
+        let result = validator.validate(valid_command()).result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![invalid_value(INVALID_CHAR_COUNT_MAX, "username", 8, 4)],
+                truncated: true,
+            })
+        );
+    }
+
+    #[test]
+    fn a_validator_returns_warnings_of_custom_rules() {
+        let validator = Validator::new().rule(|cmd: &RegisterUser| {
+            vec![
+                invalid_value("invalid-weak-password", "password", cmd.password.clone(), 8)
+                    .with_severity(Severity::Warning),
+            ]
+        });
+
+        let (validated, warnings) = validator
+            .validate(valid_command())
+            .result_with_warnings()
+            .unwrap();
+
+        assert_eq!(validated.unwrap(), valid_command());
+        assert_eq!(
+            warnings,
+            vec![
+                invalid_value("invalid-weak-password", "password", "s3cr3t".to_string(), 8)
+                    .with_severity(Severity::Warning)
+            ]
+        );
+    }
+
+    #[test]
+    fn a_validator_validates_a_relation_of_optional_fields_by_reference() {
+        let validator = Validator::new().relation(
+            ("phone", "email"),
+            |cmd: &RegisterUser| (&cmd.phone, &cmd.email),
+            RequiredIf,
+        );
+        let command = RegisterUser {
+            email: None,
+            phone: Some("555-1234".into()),
+            ..valid_command()
+        };
 
-        let resulting_validation: Validation<(), String> = validation1.and_then(|_password| {
-            Validation::failure(vec![invalid_relation(
-                "invalid-must-match",
-                "password",
-                "s3cr3t".to_string(),
-                "password2",
-                "s3crEt".to_string(),
-            )])
-        });
+        let violations = validator.validate_ref(&command);
 
         assert_eq!(
-            resulting_validation,
-            Validation::failure(vec![invalid_value("invalid-length-min", "password", 2, 6)])
+            violations,
+            vec![invalid_optional_relation(
+                INVALID_REQUIRED_IF,
+                "phone",
+                Some(Value::String("555-1234".into())),
+                "email",
+                None
+            )]
         );
     }
 }
 
 mod value {
     use super::*;
+    use core::cmp::Ordering;
 
     #[cfg(not(any(feature = "bigdecimal", feature = "chrono", feature = "num-bigint")))]
     #[test]
@@ -629,7 +1737,244 @@ mod value {
         assert_eq!(value.to_string(), "128077101");
     }
 
+    #[test]
+    fn type_name_of_values() {
+        assert_eq!(Value::String("jane".into()).type_name(), "string");
+        assert_eq!(Value::Integer(1).type_name(), "integer");
+        assert_eq!(Value::Long(1).type_name(), "long");
+        assert_eq!(Value::Float(1.).type_name(), "float");
+        assert_eq!(Value::Double(1.).type_name(), "double");
+        assert_eq!(Value::Boolean(true).type_name(), "boolean");
+    }
+
+    #[test]
+    fn converting_u32_values_selects_the_variant_by_range() {
+        assert_eq!(Value::from(i32::MAX as u32).type_name(), "integer");
+        assert_eq!(Value::from(i32::MAX as u32 + 1).type_name(), "long");
+    }
+
+    #[test]
+    fn accessors_return_the_inner_value_of_matching_variants() {
+        assert_eq!(Value::String("jane".into()).as_str(), Some("jane"));
+        assert_eq!(Value::Boolean(false).as_bool(), Some(false));
+        assert_eq!(Value::Integer(-5).as_i64(), Some(-5));
+        assert_eq!(Value::Long(-5).as_i32(), Some(-5));
+        assert_eq!(Value::Float(0.5).as_f64(), Some(0.5));
+        assert_eq!(Value::Long(7).as_f64(), Some(7.));
+
+        assert_eq!(Value::Integer(1).as_str(), None);
+        assert_eq!(Value::String("1".into()).as_i64(), None);
+        assert_eq!(Value::Double(1.).as_i64(), None);
+        assert_eq!(Value::Long(i64::from(i32::MAX) + 1).as_i32(), None);
+        assert_eq!(Value::Boolean(true).as_f64(), None);
+    }
+
+    #[test]
+    fn is_numeric_for_numbers_only() {
+        assert!(Value::Integer(0).is_numeric());
+        assert!(Value::Double(f64::NAN).is_numeric());
+        assert!(!Value::String("0".into()).is_numeric());
+        assert!(!Value::Boolean(false).is_numeric());
+    }
+
+    #[test]
+    fn coerce_integer_numbers_into_long_values() {
+        let long = Value::Integer(42).to_long().unwrap();
+
+        assert_eq!(long.type_name(), "long");
+        assert_eq!(long.as_i64(), Some(42));
+        assert_eq!(Value::Double(42.).to_long(), None);
+    }
+
+    #[test]
+    fn coerce_numbers_into_double_values() {
+        let double = Value::Integer(42).to_double().unwrap();
+
+        assert_eq!(double.type_name(), "double");
+        assert_eq!(double.as_f64(), Some(42.));
+        assert_eq!(Value::String("42".into()).to_double(), None);
+    }
+
+    #[test]
+    fn numbers_of_different_variants_are_compared_by_value() {
+        assert!(Value::Integer(42).numeric_eq(&Value::Long(42)));
+        assert!(Value::Long(42).numeric_eq(&Value::Double(42.)));
+        assert!(Value::Float(0.5).numeric_eq(&Value::Double(0.5)));
+        assert_eq!(
+            Value::Integer(42).numeric_cmp(&Value::Long(42)),
+            Some(Ordering::Equal)
+        );
+        assert!(Value::Integer(3) < Value::Double(3.5));
+        assert!(Value::Double(-3.5) < Value::Integer(-3));
+        assert!(Value::Long(i64::MAX) < Value::Double(9.3e18));
+        assert!(Value::Long(i64::MIN) > Value::Double(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn integers_are_compared_with_floats_without_loss_of_precision() {
+        // 2^53 + 1 can not be represented as f64
+        let long = Value::Long(9_007_199_254_740_993);
+        let double = Value::Double(9_007_199_254_740_992.);
+
+        assert!(long > double);
+        assert!(!long.numeric_eq(&double));
+    }
+
+    #[test]
+    fn values_are_only_equal_to_values_of_the_same_variant() {
+        assert_eq!(Value::Integer(42), Value::Integer(42));
+        assert_ne!(Value::Integer(42), Value::Long(42));
+        assert_ne!(Value::Long(42), Value::Double(42.));
+        assert_ne!(Value::Float(0.5), Value::Double(0.5));
+    }
+
+    #[test]
+    fn numerically_equal_values_of_different_variants_are_not_ordered() {
+        assert_eq!(Value::Integer(42).partial_cmp(&Value::Long(42)), None);
+        assert_eq!(
+            Value::Long(42).partial_cmp(&Value::Long(42)),
+            Some(Ordering::Equal)
+        );
+        assert!(Value::Integer(42) <= Value::Long(43));
+    }
+
+    #[test]
+    fn nan_is_not_comparable() {
+        assert_eq!(
+            Value::Integer(1).partial_cmp(&Value::Double(f64::NAN)),
+            None
+        );
+        assert_ne!(Value::Double(f64::NAN), Value::Double(f64::NAN));
+    }
+
+    #[test]
+    fn values_of_different_types_are_not_comparable() {
+        assert_eq!(
+            Value::String("1".into()).partial_cmp(&Value::Integer(1)),
+            None
+        );
+        assert_eq!(Value::Boolean(true).partial_cmp(&Value::Integer(1)), None);
+        assert_ne!(Value::Boolean(true), Value::Integer(1));
+    }
+
+    #[test]
+    fn strings_and_booleans_are_compared_within_their_type() {
+        assert!(Value::String("anna".into()) < Value::String("jane".into()));
+        assert!(Value::Boolean(false) < Value::Boolean(true));
+    }
+
+    #[test]
+    fn try_from_value_into_primitives() {
+        assert_eq!(
+            String::try_from(Value::String("jane".into())),
+            Ok("jane".into())
+        );
+        assert_eq!(bool::try_from(Value::Boolean(true)), Ok(true));
+        assert_eq!(i32::try_from(Value::Long(42)), Ok(42));
+        assert_eq!(i64::try_from(Value::Integer(-42)), Ok(-42));
+        assert_eq!(usize::try_from(Value::Integer(42)), Ok(42));
+        assert_eq!(i8::try_from(Value::Integer(-42)), Ok(-42));
+        assert_eq!(i16::try_from(Value::Long(-42)), Ok(-42));
+        assert_eq!(isize::try_from(Value::Integer(42)), Ok(42));
+        assert_eq!(u8::try_from(Value::Integer(42)), Ok(42));
+        assert_eq!(u16::try_from(Value::Long(42)), Ok(42));
+        assert_eq!(u32::try_from(Value::Long(3_000_000_000)), Ok(3_000_000_000));
+        assert_eq!(u64::try_from(Value::Integer(42)), Ok(42));
+        assert_eq!(f32::try_from(Value::Float(0.5)), Ok(0.5));
+        assert_eq!(f32::try_from(Value::Double(0.5)), Ok(0.5));
+        assert_eq!(f32::try_from(Value::Integer(42)), Ok(42.));
+        assert_eq!(f32::try_from(Value::Long(1 << 24)), Ok(16_777_216.));
+        assert_eq!(f64::try_from(Value::Integer(42)), Ok(42.));
+        assert_eq!(
+            f64::try_from(Value::Long(1 << 53)),
+            Ok(9_007_199_254_740_992.)
+        );
+    }
+
+    #[test]
+    fn try_from_value_returns_the_value_if_it_can_not_be_converted() {
+        assert_eq!(
+            String::try_from(Value::Integer(42)),
+            Err(Value::Integer(42))
+        );
+        assert_eq!(
+            i32::try_from(Value::Long(i64::MAX)),
+            Err(Value::Long(i64::MAX))
+        );
+        assert_eq!(usize::try_from(Value::Integer(-1)), Err(Value::Integer(-1)));
+        assert_eq!(u8::try_from(Value::Integer(256)), Err(Value::Integer(256)));
+        assert_eq!(u64::try_from(Value::Long(-1)), Err(Value::Long(-1)));
+        assert_eq!(f32::try_from(Value::Double(0.1)), Err(Value::Double(0.1)));
+        assert_eq!(
+            f32::try_from(Value::Integer((1 << 24) + 1)),
+            Err(Value::Integer((1 << 24) + 1))
+        );
+        assert_eq!(
+            f64::try_from(Value::Long((1 << 53) + 1)),
+            Err(Value::Long((1 << 53) + 1))
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn decimals_are_compared_with_other_numbers() {
+        use std::str::FromStr;
+
+        let decimal = Value::Decimal(BigDecimal::from_str("2.5").unwrap());
+
+        assert!(decimal.numeric_eq(&Value::Double(2.5)));
+        assert!(decimal > Value::Integer(2));
+        assert!(decimal < Value::Long(3));
+        assert_eq!(decimal.partial_cmp(&Value::Double(f64::NAN)), None);
+        assert_eq!(decimal.as_f64(), Some(2.5));
+        assert_eq!(
+            BigDecimal::try_from(decimal),
+            Ok(BigDecimal::from_str("2.5").unwrap())
+        );
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn big_integers_are_compared_with_other_numbers() {
+        use std::str::FromStr;
+
+        let big = Value::BigInteger(BigInt::from_str("100000000000000000000").unwrap());
+
+        assert!(Value::BigInteger(BigInt::from(42)).numeric_eq(&Value::Integer(42)));
+        assert!(big > Value::Long(i64::MAX));
+        assert!(big.numeric_eq(&Value::Double(1e20)));
+        assert!(big < Value::Double(1.000_000_000_000_001e20));
+        assert!(big > Value::Double(-1e30));
+        assert_eq!(big.as_i64(), None);
+        assert_eq!(big.as_f64(), Some(1e20));
+        assert_eq!(BigInt::try_from(Value::Long(42)), Ok(BigInt::from(42)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn dates_are_compared_within_their_type() {
+        let date1 = Value::Date(NaiveDate::from_ymd_opt(2019, 8, 31).unwrap());
+        let date2 = Value::Date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+
+        assert!(date1 < date2);
+        assert_eq!(
+            NaiveDate::try_from(date1),
+            Ok(NaiveDate::from_ymd_opt(2019, 8, 31).unwrap())
+        );
+        assert_eq!(date2.type_name(), "date");
+    }
+
     proptest! {
+        #[test]
+        fn integer_and_double_values_are_ordered_like_f64(
+            integer in any::<i32>(),
+            double in any::<f64>()
+        ) {
+            let ordering = Value::Integer(integer).partial_cmp(&Value::Double(double));
+
+            prop_assert_eq!(ordering, f64::from(integer).partial_cmp(&double));
+        }
+
         #[test]
         fn can_convert_i8_values_into_integer_value(
             param in any::<i8>()
@@ -808,6 +2153,7 @@ mod invalid_value {
     fn display_format_invalid_value_of_field_with_actual_and_expected_value() {
         let invalid_value = InvalidValue {
             code: "invalid-allowed-characters".into(),
+            severity: Severity::Error,
             field: Field {
                 name: "code".into(),
                 actual: Some(Value::String("Wlske324$2Asd".into())),
@@ -829,6 +2175,7 @@ mod invalid_relation {
     fn display_format_invalid_relation_of_percent_range() {
         let invalid_relation = InvalidRelation {
             code: "invalid-must-define-range-inclusive".into(),
+            severity: Severity::Error,
             field1: Field {
                 name: "percent_from".into(),
                 actual: Some(Value::Integer(50)),
@@ -848,6 +2195,35 @@ mod invalid_relation {
     }
 }
 
+mod invalid_field_set {
+    use super::*;
+
+    #[test]
+    fn display_format_invalid_field_set_of_contact_fields() {
+        let invalid_field_set = InvalidFieldSet {
+            code: "invalid-at-least-one-of".into(),
+            severity: Severity::Error,
+            fields: vec![
+                Field {
+                    name: "phone".into(),
+                    actual: None,
+                    expected: None,
+                },
+                Field {
+                    name: "email".into(),
+                    actual: Some(Value::String("jane@example.com".into())),
+                    expected: None,
+                },
+            ],
+        };
+
+        assert_eq!(
+            invalid_field_set.to_string(),
+            "invalid-at-least-one-of of phone which is (n.a.), email which is jane@example.com"
+        );
+    }
+}
+
 mod invalid_state {
     use super::*;
 
@@ -855,6 +2231,7 @@ mod invalid_state {
     fn display_format_invalid_state_can_format_a_list_of_parameters() {
         let invalid_state = InvalidState {
             code: "invalid-username-is-unique".into(),
+            severity: Severity::Error,
             params: vec![Parameter {
                 name: "username".into(),
                 value: "jon.doe".to_string().into(),
@@ -868,6 +2245,100 @@ mod invalid_state {
     }
 }
 
+mod constraint_violation {
+    use super::*;
+
+    #[test]
+    fn code_of_each_kind_of_constraint_violation() {
+        assert_eq!(invalid_value("invalid-a", "a", 1, 2).code(), "invalid-a");
+        assert_eq!(
+            invalid_relation("invalid-b", "a", 1, "b", 2).code(),
+            "invalid-b"
+        );
+        assert_eq!(
+            invalid_field_set("invalid-c", vec![("a".into(), None)]).code(),
+            "invalid-c"
+        );
+        assert_eq!(invalid_state("invalid-d", vec![]).code(), "invalid-d");
+    }
+
+    #[test]
+    fn field_names_of_each_kind_of_constraint_violation() {
+        assert_eq!(
+            invalid_value("invalid-a", "a", 1, 2)
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["a"]
+        );
+        assert_eq!(
+            invalid_relation("invalid-b", "a", 1, "b", 2)
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            invalid_field_set(
+                "invalid-c",
+                vec![("a".into(), None), ("b".into(), None), ("c".into(), None)]
+            )
+            .field_names()
+            .collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            invalid_state("invalid-d", vec![param("a", 1), param("b", 2)])
+                .field_names()
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert!(invalid_state("invalid-d", vec![])
+            .field_names()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn relation_violation_is_for_both_fields() {
+        let violation = invalid_relation("invalid-must-match", "password", 1, "password2", 2);
+
+        assert!(violation.is_for_field("password"));
+        assert!(violation.is_for_field("password2"));
+        assert!(!violation.is_for_field("password3"));
+    }
+
+    #[test]
+    fn state_violation_is_for_the_fields_named_by_its_parameters() {
+        let violation = invalid_state(
+            "invalid-unique-username",
+            vec![param("username", "jon.doe".to_string())],
+        );
+
+        assert!(violation.is_for_field("username"));
+        assert!(!violation.is_for_field("email"));
+    }
+
+    #[test]
+    fn param_of_a_state_violation() {
+        let violation = invalid_state(
+            "invalid-unique-username",
+            vec![
+                param("username", "jon.doe".to_string()),
+                param("attempts", 3),
+            ],
+        );
+
+        assert_eq!(violation.param("attempts"), Some(&Value::Integer(3)));
+        assert_eq!(violation.param("email"), None);
+    }
+
+    #[test]
+    fn param_of_a_field_violation_is_none() {
+        let violation = invalid_value("invalid-length-max", "username", 21, 20);
+
+        assert_eq!(violation.param("username"), None);
+    }
+}
+
 mod validation_error {
     use super::*;
 
@@ -885,6 +2356,7 @@ mod validation_error {
                     }],
                 ),
             ],
+            truncated: false,
         };
 
         assert_eq!(validation_error.to_string(), "validating my form: [ invalid-bound-max of age which is 131, expected to be 130 / invalid-unique-username for parameters: [ username=jon.doe ] ]");
@@ -895,6 +2367,7 @@ mod validation_error {
         let validation_error = ValidationError {
             message: None,
             violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+            truncated: false,
         };
 
         assert_eq!(
@@ -908,10 +2381,12 @@ mod validation_error {
         let validation_error1 = ValidationError {
             message: Some("validating a user's age".into()),
             violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+            truncated: false,
         };
         let validation_error2 = ValidationError {
             message: Some("validating a user registration command".into()),
             violations: vec![invalid_value("invalid-length-min", "username", 3, 4)],
+            truncated: false,
         };
 
         let merged_error = validation_error2.merge(validation_error1);
@@ -925,7 +2400,8 @@ mod validation_error {
                 violations: vec![
                     invalid_value("invalid-length-min", "username", 3, 4),
                     invalid_value("invalid-bound-min", "age", 12, 13),
-                ]
+                ],
+                truncated: false,
             }
         );
     }
@@ -935,10 +2411,12 @@ mod validation_error {
         let validation_error1 = ValidationError {
             message: Some("validating a user's age".into()),
             violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+            truncated: false,
         };
         let validation_error2 = ValidationError {
             message: None,
             violations: vec![invalid_value("invalid-length-min", "username", 3, 4)],
+            truncated: false,
         };
 
         let merged_error = validation_error2.merge(validation_error1);
@@ -950,7 +2428,8 @@ mod validation_error {
                 violations: vec![
                     invalid_value("invalid-length-min", "username", 3, 4),
                     invalid_value("invalid-bound-min", "age", 12, 13),
-                ]
+                ],
+                truncated: false,
             }
         );
     }
@@ -960,10 +2439,12 @@ mod validation_error {
         let validation_error1 = ValidationError {
             message: None,
             violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+            truncated: false,
         };
         let validation_error2 = ValidationError {
             message: Some("validating a user registration command".into()),
             violations: vec![invalid_value("invalid-length-min", "username", 3, 4)],
+            truncated: false,
         };
 
         let merged_error = validation_error2.merge(validation_error1);
@@ -975,7 +2456,8 @@ mod validation_error {
                 violations: vec![
                     invalid_value("invalid-length-min", "username", 3, 4),
                     invalid_value("invalid-bound-min", "age", 12, 13),
-                ]
+                ],
+                truncated: false,
             }
         );
     }
@@ -985,10 +2467,12 @@ mod validation_error {
         let validation_error1 = ValidationError {
             message: None,
             violations: vec![invalid_value("invalid-bound-min", "age", 12, 13)],
+            truncated: false,
         };
         let validation_error2 = ValidationError {
             message: None,
             violations: vec![invalid_value("invalid-length-min", "username", 3, 4)],
+            truncated: false,
         };
 
         let merged_error = validation_error2.merge(validation_error1);
@@ -1000,8 +2484,219 @@ mod validation_error {
                 violations: vec![
                     invalid_value("invalid-length-min", "username", 3, 4),
                     invalid_value("invalid-bound-min", "age", 12, 13),
-                ]
+                ],
+                truncated: false,
             }
         );
     }
+
+    fn query_error() -> ValidationError {
+        ValidationError {
+            message: None,
+            violations: vec![
+                invalid_value("invalid-length-min", "username", 2, 4),
+                invalid_relation("invalid-must-match", "password", 1, "password2", 2),
+                invalid_state(
+                    "invalid-unique-email",
+                    vec![param("email", "jon@doe.net".to_string())],
+                ),
+                invalid_value("invalid-char-count-max", "username", 2, 1),
+                invalid_value("invalid-length-min", "password", 1, 8),
+                invalid_state("invalid-maintenance", vec![]),
+            ],
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn has_code() {
+        let validation_error = query_error();
+
+        assert!(validation_error.has_code("invalid-must-match"));
+        assert!(validation_error.has_code("invalid-maintenance"));
+        assert!(!validation_error.has_code("invalid-not-empty"));
+    }
+
+    #[test]
+    fn violations_for_a_field() {
+        let validation_error = query_error();
+
+        let violations = validation_error
+            .violations_for_field("username")
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            vec![
+                &invalid_value("invalid-length-min", "username", 2, 4),
+                &invalid_value("invalid-char-count-max", "username", 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn violations_for_a_field_include_relations_and_state_parameters() {
+        let validation_error = query_error();
+
+        assert_eq!(validation_error.violations_for_field("password").count(), 2);
+        assert_eq!(
+            validation_error.violations_for_field("password2").count(),
+            1
+        );
+        assert_eq!(validation_error.violations_for_field("email").count(), 1);
+        assert_eq!(validation_error.violations_for_field("age").count(), 0);
+    }
+
+    #[test]
+    fn violations_with_a_code() {
+        let validation_error = query_error();
+
+        let fields = validation_error
+            .violations_with_code("invalid-length-min")
+            .flat_map(ConstraintViolation::field_names)
+            .collect::<Vec<_>>();
+
+        assert_eq!(fields, vec!["username", "password"]);
+    }
+
+    #[test]
+    fn group_violations_by_field() {
+        let validation_error = query_error();
+
+        let groups = validation_error.group_by_field();
+
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            vec!["email", "password", "password2", "username"]
+        );
+        assert_eq!(
+            groups["password"],
+            vec![
+                &validation_error.violations[1],
+                &validation_error.violations[4]
+            ]
+        );
+        assert_eq!(groups["password2"], vec![&validation_error.violations[1]]);
+        assert_eq!(
+            groups["username"],
+            vec![
+                &validation_error.violations[0],
+                &validation_error.violations[3]
+            ]
+        );
+    }
+
+    #[test]
+    fn a_relation_of_a_field_with_itself_is_grouped_once() {
+        let validation_error = ValidationError {
+            message: None,
+            violations: vec![invalid_relation("invalid-must-compare", "a", 1, "a", 1)],
+            truncated: false,
+        };
+
+        assert_eq!(validation_error.group_by_field()["a"].len(), 1);
+    }
+
+    #[test]
+    fn first_violation_per_field() {
+        let validation_error = query_error();
+
+        let firsts = validation_error.first_per_field();
+
+        assert_eq!(firsts.len(), 4);
+        assert_eq!(firsts["username"].code(), "invalid-length-min");
+        assert_eq!(firsts["password"].code(), "invalid-must-match");
+        assert_eq!(firsts["email"].code(), "invalid-unique-email");
+    }
+
+    #[test]
+    fn sort_violations_by_field_is_stable() {
+        let mut validation_error = query_error();
+
+        validation_error.sort_by_field();
+
+        assert_eq!(
+            validation_error
+                .violations
+                .iter()
+                .map(ConstraintViolation::code)
+                .collect::<Vec<_>>(),
+            vec![
+                "invalid-unique-email",
+                "invalid-must-match",
+                "invalid-length-min",
+                "invalid-length-min",
+                "invalid-char-count-max",
+                "invalid-maintenance",
+            ]
+        );
+        assert!(validation_error.violations[3].is_for_field("username"));
+    }
+
+    #[test]
+    fn sort_violations_by_code_is_stable() {
+        let mut validation_error = query_error();
+
+        validation_error.sort_by_code();
+
+        assert_eq!(
+            validation_error.violations,
+            vec![
+                invalid_value("invalid-char-count-max", "username", 2, 1),
+                invalid_value("invalid-length-min", "username", 2, 4),
+                invalid_value("invalid-length-min", "password", 1, 8),
+                invalid_state("invalid-maintenance", vec![]),
+                invalid_relation("invalid-must-match", "password", 1, "password2", 2),
+                invalid_state(
+                    "invalid-unique-email",
+                    vec![param("email", "jon@doe.net".to_string())],
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_keeps_violations_of_different_codes_or_fields() {
+        let violations = vec![
+            invalid_value("invalid-length-min", "username", 2, 4),
+            invalid_relation("invalid-must-match", "password", 1, "password2", 2),
+            invalid_value("invalid-length-max", "username", 2, 4),
+            invalid_relation("invalid-must-match", "password2", 1, "password", 2),
+            invalid_value("invalid-length-min", "password", 2, 4),
+        ];
+        let mut validation_error = ValidationError::new(None, violations.clone());
+
+        validation_error.dedup();
+
+        assert_eq!(validation_error.violations, violations);
+    }
+
+    #[test]
+    fn dedup_removes_identical_violations_keeping_the_first() {
+        let mut validation_error = ValidationError {
+            message: None,
+            violations: vec![
+                invalid_value("invalid-length-min", "username", 2, 4),
+                invalid_value("invalid-length-min", "password", 1, 8),
+                invalid_value("invalid-length-min", "username", 2, 4),
+                invalid_value("invalid-length-min", "username", 3, 4),
+                invalid_value("invalid-length-min", "password", 1, 8)
+                    .with_severity(Severity::Warning),
+            ],
+            truncated: false,
+        };
+
+        validation_error.dedup();
+
+        assert_eq!(
+            validation_error.violations,
+            vec![
+                invalid_value("invalid-length-min", "username", 2, 4),
+                invalid_value("invalid-length-min", "password", 1, 8),
+                invalid_value("invalid-length-min", "username", 3, 4),
+                invalid_value("invalid-length-min", "password", 1, 8)
+                    .with_severity(Severity::Warning),
+            ]
+        );
+    }
 }
@@ -0,0 +1,261 @@
+//! A validator for structs that is configured at runtime
+
+use super::{
+    ConstraintViolation, FieldName, InnerValidation, RelatedFieldSet, RelatedFields, State,
+    ValidateRef, Validation, ValidationMode,
+};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+type Rule<T> = Box<dyn Fn(&T) -> Vec<ConstraintViolation> + Send + Sync>;
+
+/// A validator for values of type `T` whose rules are registered at runtime.
+///
+/// It is an alternative to a hand-written implementation of the [`Validate`]
+/// trait for a struct. The rules are registered using the builder methods
+/// [`field`], [`relation`], [`field_set`], [`state`] and [`rule`]. Each rule
+/// selects the values it needs from the validated struct and validates them
+/// against a constraint by reference using the [`ValidateRef`] trait.
+///
+/// Running the validator executes all rules and collects the constraint
+/// violations of all rules, unless a [`ValidationMode`] that limits the
+/// number of errors is set. Once the limit is reached the remaining rules are
+/// skipped.
+///
+/// A `Validator` is `Send` and `Sync`. It can be built once and be reused for
+/// any number of validations, for example by storing it in a `static` that is
+/// initialized on first use.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use valid::{Validator, ValidationError, invalid_relation, invalid_value};
+/// use valid::constraint::{CharCount, MustMatch, NotEmpty, INVALID_CHAR_COUNT_MAX, INVALID_MUST_MATCH};
+///
+/// #[derive(Debug)]
+/// struct RegisterUser {
+///     name: String,
+///     password: String,
+///     password2: String,
+/// }
+///
+/// fn register_user_validator() -> &'static Validator<RegisterUser> {
+///     static VALIDATOR: OnceLock<Validator<RegisterUser>> = OnceLock::new();
+///     VALIDATOR.get_or_init(|| {
+///         Validator::new()
+///             .field("name", |cmd: &RegisterUser| &cmd.name, NotEmpty)
+///             .field("name", |cmd: &RegisterUser| &cmd.name, CharCount::Max(20))
+///             .relation(
+///                 ("password", "password2"),
+///                 |cmd: &RegisterUser| (&cmd.password, &cmd.password2),
+///                 MustMatch,
+///             )
+///     })
+/// }
+///
+/// let command = RegisterUser {
+///     name: "the-quick-brown-fox-jumps".into(),
+///     password: "s3cr3t".into(),
+///     password2: "s3crEt".into(),
+/// };
+///
+/// let result = register_user_validator().validate(command).result_with_warnings();
+///
+/// assert_eq!(
+///     result.unwrap_err(),
+///     ValidationError::new(
+///         None,
+///         vec![
+///             invalid_value(INVALID_CHAR_COUNT_MAX, "name", 25, 20),
+///             invalid_relation(
+///                 INVALID_MUST_MATCH,
+///                 "password",
+///                 "s3cr3t".to_string(),
+///                 "password2",
+///                 "s3crEt".to_string()
+///             ),
+///         ],
+///     )
+/// );
+/// ```
+///
+/// [`Validate`]: trait.Validate.html
+/// [`ValidateRef`]: trait.ValidateRef.html
+/// [`ValidationMode`]: enum.ValidationMode.html
+/// [`field`]: #method.field
+/// [`relation`]: #method.relation
+/// [`field_set`]: #method.field_set
+/// [`state`]: #method.state
+/// [`rule`]: #method.rule
+pub struct Validator<T> {
+    rules: Vec<Rule<T>>,
+    mode: ValidationMode,
+    _value: PhantomData<fn(&T)>,
+}
+
+impl<T> Debug for Validator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator")
+            .field("rules", &self.rules.len())
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<T> Default for Validator<T> {
+    fn default() -> Self {
+        Validator::new()
+    }
+}
+
+impl<T> Validator<T> {
+    /// Constructs a new validator without any rules.
+    pub const fn new() -> Self {
+        Validator {
+            rules: Vec::new(),
+            mode: ValidationMode::Complete,
+            _value: PhantomData,
+        }
+    }
+
+    /// Sets the [`ValidationMode`] for validations executed by this
+    /// validator.
+    ///
+    /// [`ValidationMode`]: enum.ValidationMode.html
+    pub fn with_mode(mut self, mode: ValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Adds a rule that validates a single field in the [`FieldName`]
+    /// context.
+    ///
+    /// The given accessor selects the field from the validated value.
+    ///
+    /// [`FieldName`]: struct.FieldName.html
+    pub fn field<A, C>(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        accessor: impl for<'a> Fn(&'a T) -> &'a A + Send + Sync + 'static,
+        constraint: C,
+    ) -> Self
+    where
+        A: ValidateRef<C, FieldName>,
+        C: Send + Sync + 'static,
+    {
+        let name = name.into();
+        self.rule(move |value| accessor(value).validate_ref(name.clone(), &constraint))
+    }
+
+    /// Adds a rule that validates a pair of related fields in the
+    /// [`RelatedFields`] context.
+    ///
+    /// The given accessor selects references to the pair of fields from the
+    /// validated value. The values of the fields are cloned into a pair, which
+    /// is validated by reference.
+    ///
+    /// [`RelatedFields`]: struct.RelatedFields.html
+    pub fn relation<A, B, C>(
+        self,
+        (name1, name2): (impl Into<Cow<'static, str>>, impl Into<Cow<'static, str>>),
+        accessor: impl for<'a> Fn(&'a T) -> (&'a A, &'a B) + Send + Sync + 'static,
+        constraint: C,
+    ) -> Self
+    where
+        A: Clone,
+        B: Clone,
+        (A, B): ValidateRef<C, RelatedFields>,
+        C: Send + Sync + 'static,
+    {
+        let names = RelatedFields(name1.into(), name2.into());
+        self.rule(move |value| {
+            let (value1, value2) = accessor(value);
+            (value1.clone(), value2.clone()).validate_ref(names.clone(), &constraint)
+        })
+    }
+
+    /// Adds a rule that validates a set of related fields in the
+    /// [`RelatedFieldSet`] context.
+    ///
+    /// The given accessor returns the values of the related fields, for
+    /// example as a tuple of `Option`s.
+    ///
+    /// [`RelatedFieldSet`]: struct.RelatedFieldSet.html
+    pub fn field_set<R, C>(
+        self,
+        names: impl Into<RelatedFieldSet>,
+        accessor: impl Fn(&T) -> R + Send + Sync + 'static,
+        constraint: C,
+    ) -> Self
+    where
+        R: ValidateRef<C, RelatedFieldSet>,
+        C: Send + Sync + 'static,
+    {
+        let names = names.into();
+        self.rule(move |value| accessor(value).validate_ref(names.clone(), &constraint))
+    }
+
+    /// Adds a rule that validates the whole value in the [`State`] context.
+    ///
+    /// The given state is cloned for each validation. For state that is
+    /// expensive to clone, like a repository, consider to use a reference or
+    /// an `Arc`.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn state<S, C>(self, state: S, constraint: C) -> Self
+    where
+        T: ValidateRef<C, State<S>>,
+        S: Clone + Send + Sync + 'static,
+        C: Send + Sync + 'static,
+    {
+        self.rule(move |value| value.validate_ref(State(state.clone()), &constraint))
+    }
+
+    /// Adds a custom rule given as a function that returns the constraint
+    /// violations found in the validated value.
+    pub fn rule(
+        mut self,
+        rule: impl Fn(&T) -> Vec<ConstraintViolation> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Validates the given value by reference and returns the constraint
+    /// violations found.
+    pub fn validate_ref(&self, value: &T) -> Vec<ConstraintViolation> {
+        self.execute(value).0
+    }
+
+    /// Validates the given value and returns the resulting [`Validation`].
+    ///
+    /// [`Validation`]: struct.Validation.html
+    pub fn validate(&self, value: T) -> Validation<Validator<T>, T> {
+        let (violations, truncated) = self.execute(&value);
+        let validation = if violations.iter().all(|violation| !violation.is_error()) {
+            InnerValidation::Success(PhantomData, value, violations)
+        } else {
+            InnerValidation::Failure(violations, truncated)
+        };
+        Validation(validation, self.mode).limited()
+    }
+
+    fn execute(&self, value: &T) -> (Vec<ConstraintViolation>, bool) {
+        let mut violations = Vec::new();
+        let mut skipped = false;
+        for rule in &self.rules {
+            if self.mode.is_limit_reached(&violations) {
+                skipped = true;
+                break;
+            }
+            violations.extend(rule(value));
+        }
+        let truncated = self.mode.truncate(&mut violations) || skipped;
+        (violations, truncated)
+    }
+}
@@ -0,0 +1,397 @@
+//! Introspection, coercion and comparison of values
+
+use super::Value;
+#[cfg(feature = "num-bigint")]
+use alloc::format;
+use alloc::string::String;
+#[cfg(any(feature = "bigdecimal", feature = "num-bigint"))]
+use alloc::string::ToString;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, Utc};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigInt;
+
+impl Value {
+    /// Returns the name of the type of this value.
+    ///
+    /// The names are `string`, `integer`, `long`, `float`, `double`,
+    /// `boolean`, `decimal`, `date`, `date_time` and `big_integer`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Integer(_) => "integer",
+            Value::Long(_) => "long",
+            Value::Float(_) => "float",
+            Value::Double(_) => "double",
+            Value::Boolean(_) => "boolean",
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(_) => "decimal",
+            #[cfg(feature = "chrono")]
+            Value::Date(_) => "date",
+            #[cfg(feature = "chrono")]
+            Value::DateTime(_) => "date_time",
+            #[cfg(feature = "num-bigint")]
+            Value::BigInteger(_) => "big_integer",
+        }
+    }
+
+    /// Returns whether this value is a number.
+    pub fn is_numeric(&self) -> bool {
+        self.as_f64().is_some()
+    }
+
+    /// Returns the string if this is a `String` value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean if this is a `Boolean` value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer number if this value is an integer number in the
+    /// range of `i32`.
+    ///
+    /// The integer variants are `Integer`, `Long` and `BigInteger`.
+    pub fn as_i32(&self) -> Option<i32> {
+        self.as_i64().and_then(|value| i32::try_from(value).ok())
+    }
+
+    /// Returns the integer number if this value is an integer number in the
+    /// range of `i64`.
+    ///
+    /// The integer variants are `Integer`, `Long` and `BigInteger`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Integer(value) => Some(i64::from(*value)),
+            Value::Long(value) => Some(*value),
+            #[cfg(feature = "num-bigint")]
+            Value::BigInteger(value) => i64::try_from(value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as `f64` if this value is a number.
+    ///
+    /// Numbers that can not be represented as `f64` exactly, like big
+    /// integers or decimals with many digits, are rounded to the nearest
+    /// `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Integer(value) => Some(f64::from(*value)),
+            Value::Long(value) => Some(*value as f64),
+            Value::Float(value) => Some(f64::from(*value)),
+            Value::Double(value) => Some(*value),
+            // parsing the decimal representation rounds to the nearest f64
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(value) => value.to_string().parse().ok(),
+            #[cfg(feature = "num-bigint")]
+            Value::BigInteger(value) => value.to_string().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Converts an integer number into a `Long` value.
+    ///
+    /// Returns `None` if this value is not an integer number or is out of the
+    /// range of `i64` (see [`as_i64`]).
+    ///
+    /// [`as_i64`]: #method.as_i64
+    pub fn to_long(&self) -> Option<Value> {
+        self.as_i64().map(Value::Long)
+    }
+
+    /// Converts a number into a `Double` value.
+    ///
+    /// Returns `None` if this value is not a number (see [`as_f64`]).
+    ///
+    /// [`as_f64`]: #method.as_f64
+    pub fn to_double(&self) -> Option<Value> {
+        self.as_f64().map(Value::Double)
+    }
+
+    /// Returns whether this value is equal to the other value, where numbers
+    /// are compared by their value regardless of their variant.
+    ///
+    /// For example `Integer(1)` is numerically equal to `Long(1)` and
+    /// `Double(1.0)`, while `==` only considers values of the same variant
+    /// equal.
+    pub fn numeric_eq(&self, other: &Value) -> bool {
+        self.numeric_cmp(other) == Some(Ordering::Equal)
+    }
+
+    /// Compares this value with the other value, where numbers are compared
+    /// by their value regardless of their variant and without loss of
+    /// precision.
+    ///
+    /// Other values are only comparable to values of the same variant.
+    /// Returns `None` if the values are not comparable or if one of them is
+    /// `NaN`.
+    pub fn numeric_cmp(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::String(value1), Value::String(value2)) => value1.partial_cmp(value2),
+            (Value::Boolean(value1), Value::Boolean(value2)) => value1.partial_cmp(value2),
+            #[cfg(feature = "chrono")]
+            (Value::Date(value1), Value::Date(value2)) => value1.partial_cmp(value2),
+            #[cfg(feature = "chrono")]
+            (Value::DateTime(value1), Value::DateTime(value2)) => value1.partial_cmp(value2),
+            _ => Number::of(self)?.compare(&Number::of(other)?),
+        }
+    }
+}
+
+/// Values are ordered like by [`numeric_cmp`], but numbers of different
+/// variants that are numerically equal, like `Integer(1)` and `Long(1)`, are
+/// not comparable. This keeps the ordering consistent with `==`, which only
+/// considers values of the same variant equal.
+///
+/// [`numeric_cmp`]: enum.Value.html#method.numeric_cmp
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.numeric_cmp(other) {
+            Some(Ordering::Equal) if self != other => None,
+            ordering => ordering,
+        }
+    }
+}
+
+/// A number taken from a value for comparing numbers of different variants
+enum Number {
+    Integral(i64),
+    Floating(f64),
+    #[cfg(feature = "num-bigint")]
+    BigInteger(BigInt),
+    #[cfg(feature = "bigdecimal")]
+    Decimal(BigDecimal),
+}
+
+impl Number {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Integer(value) => Some(Number::Integral(i64::from(*value))),
+            Value::Long(value) => Some(Number::Integral(*value)),
+            Value::Float(value) => Some(Number::Floating(f64::from(*value))),
+            Value::Double(value) => Some(Number::Floating(*value)),
+            #[cfg(feature = "num-bigint")]
+            Value::BigInteger(value) => Some(
+                i64::try_from(value)
+                    .map_or_else(|_| Number::BigInteger(value.clone()), Number::Integral),
+            ),
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(value) => Some(Number::Decimal(value.clone())),
+            _ => None,
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Integral(value1), Number::Integral(value2)) => Some(value1.cmp(value2)),
+            (Number::Floating(value1), Number::Floating(value2)) => value1.partial_cmp(value2),
+            (Number::Integral(value1), Number::Floating(value2)) => {
+                compare_integral_floating(*value1, *value2)
+            }
+            (Number::Floating(value1), Number::Integral(value2)) => {
+                compare_integral_floating(*value2, *value1).map(Ordering::reverse)
+            }
+            #[cfg(feature = "bigdecimal")]
+            (Number::Decimal(value1), _) => other.to_decimal().map(|value2| value1.cmp(&value2)),
+            #[cfg(feature = "bigdecimal")]
+            (_, Number::Decimal(value2)) => self.to_decimal().map(|value1| value1.cmp(value2)),
+            #[cfg(feature = "num-bigint")]
+            (Number::BigInteger(value1), Number::BigInteger(value2)) => Some(value1.cmp(value2)),
+            #[cfg(feature = "num-bigint")]
+            (Number::BigInteger(value1), Number::Integral(value2)) => {
+                Some(value1.cmp(&BigInt::from(*value2)))
+            }
+            #[cfg(feature = "num-bigint")]
+            (Number::Integral(value1), Number::BigInteger(value2)) => {
+                Some(BigInt::from(*value1).cmp(value2))
+            }
+            #[cfg(feature = "num-bigint")]
+            (Number::BigInteger(value1), Number::Floating(value2)) => {
+                compare_big_integer_floating(value1, *value2)
+            }
+            #[cfg(feature = "num-bigint")]
+            (Number::Floating(value1), Number::BigInteger(value2)) => {
+                compare_big_integer_floating(value2, *value1).map(Ordering::reverse)
+            }
+        }
+    }
+
+    /// Converts the number into a decimal. Floats are converted by their
+    /// decimal representation, which fails for `NaN` and infinity.
+    #[cfg(feature = "bigdecimal")]
+    fn to_decimal(&self) -> Option<BigDecimal> {
+        match self {
+            Number::Integral(value) => Some(BigDecimal::from(*value)),
+            Number::Floating(value) => BigDecimal::try_from(*value).ok(),
+            #[cfg(feature = "num-bigint")]
+            Number::BigInteger(value) => Some(BigDecimal::from(value.clone())),
+            Number::Decimal(value) => Some(value.clone()),
+        }
+    }
+}
+
+/// 2^63, the smallest float greater than `i64::MAX`
+const I64_LIMIT: f64 = -(i64::MIN as f64);
+
+/// Compares an integer with a float without loss of precision.
+fn compare_integral_floating(integral: i64, floating: f64) -> Option<Ordering> {
+    if floating.is_nan() {
+        None
+    } else if floating >= I64_LIMIT {
+        Some(Ordering::Less)
+    } else if floating < -I64_LIMIT {
+        Some(Ordering::Greater)
+    } else {
+        // the cast truncates the fraction, the truncated value is exact as f64
+        let truncated = floating as i64;
+        Some(
+            integral
+                .cmp(&truncated)
+                .then((truncated as f64).partial_cmp(&floating)?),
+        )
+    }
+}
+
+/// Compares a big integer that is out of the range of `i64` with a float
+/// without loss of precision.
+#[cfg(feature = "num-bigint")]
+fn compare_big_integer_floating(integral: &BigInt, floating: f64) -> Option<Ordering> {
+    if floating.is_nan() {
+        None
+    } else if floating.is_infinite() {
+        Some(if floating > 0. {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        })
+    } else if (-I64_LIMIT..I64_LIMIT).contains(&floating) {
+        Some(integral.cmp(&BigInt::from(floating as i64)))
+    } else {
+        // floats of this magnitude have no fraction and are formatted exactly
+        let floating = format!("{:.0}", floating).parse::<BigInt>().ok()?;
+        Some(integral.cmp(&floating))
+    }
+}
+
+macro_rules! try_from_value {
+    ( $( $type:ty => $accessor:expr ),+ $(,)? ) => {
+        $(
+            impl TryFrom<Value> for $type {
+                type Error = Value;
+
+                fn try_from(value: Value) -> Result<Self, Self::Error> {
+                    let accessor: fn(&Value) -> Option<$type> = $accessor;
+                    accessor(&value).ok_or(value)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! try_from_integer_value {
+    ( $( $type:ty ),+ $(,)? ) => {
+        try_from_value! {
+            $( $type => |value| value.as_i64().and_then(|value| <$type>::try_from(value).ok()), )+
+        }
+    };
+}
+
+try_from_value! {
+    bool => Value::as_bool,
+    i32 => Value::as_i32,
+    i64 => Value::as_i64,
+    f32 => |value| match value {
+        Value::Float(value) => Some(*value),
+        // doubles that are represented exactly, NaN is kept
+        Value::Double(value) if f64::from(*value as f32) == *value || value.is_nan() => {
+            Some(*value as f32)
+        }
+        // integers up to 2^24 are represented exactly
+        _ => value
+            .as_i64()
+            .filter(|value| value.unsigned_abs() <= 1 << f32::MANTISSA_DIGITS)
+            .map(|value| value as f32),
+    },
+    f64 => |value| match value {
+        Value::Float(value) => Some(f64::from(*value)),
+        Value::Double(value) => Some(*value),
+        // integers up to 2^53 are represented exactly
+        _ => value
+            .as_i64()
+            .filter(|value| value.unsigned_abs() <= 1 << f64::MANTISSA_DIGITS)
+            .map(|value| value as f64),
+    },
+}
+
+try_from_integer_value! {
+    i8,
+    i16,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+}
+
+#[cfg(feature = "chrono")]
+try_from_value! {
+    NaiveDate => |value| match value {
+        Value::Date(value) => Some(*value),
+        _ => None,
+    },
+    DateTime<Utc> => |value| match value {
+        Value::DateTime(value) => Some(*value),
+        _ => None,
+    },
+}
+
+impl TryFrom<Value> for String {
+    type Error = Value;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(value) => Ok(value),
+            _ => Err(value),
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<Value> for BigDecimal {
+    type Error = Value;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Decimal(value) => Ok(value),
+            _ => Err(value),
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl TryFrom<Value> for BigInt {
+    type Error = Value;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::BigInteger(value) => Ok(value),
+            Value::Integer(value) => Ok(BigInt::from(value)),
+            Value::Long(value) => Ok(BigInt::from(value)),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,687 @@
+//! Validation rules that are loaded at runtime
+//!
+//! This module provides a [`RuleSet`] that is loaded from a configuration in
+//! JSON format or in TOML format (optional crate feature `toml`). It applies
+//! the constraints of this crate to documents given as [`serde_json::Value`]
+//! and produces the usual [`ValidationError`]. The rules can be changed
+//! without recompiling the application.
+//!
+//! This module is only available with the optional crate feature `dynamic`.
+//!
+//! # Format of a rule set
+//!
+//! A rule set is an object holding a list of `rules`. Each rule names the
+//! `field` it applies to and one or more constraints with their parameters.
+//! Nested fields are addressed by their path separated by dots, like
+//! `address.zip_code`. Constraints on the relation of two fields name the
+//! related fields in a list of `fields`.
+//!
+//! ```json
+//! {
+//!     "rules": [
+//!         { "field": "name", "required": true, "length": { "max": 20 } },
+//!         { "field": "age", "bound": { "min": 13, "max": 120 } },
+//!         { "field": "role", "allowed_values": ["admin", "member"] },
+//!         { "fields": ["password", "password2"], "must_match": true }
+//!     ]
+//! }
+//! ```
+//!
+//! The supported constraints are:
+//!
+//! | name             | parameters                                         | applies to               |
+//! |------------------|----------------------------------------------------|--------------------------|
+//! | `required`       | `true`                                             | any value                |
+//! | `not_empty`      | `true`                                             | strings, arrays, objects |
+//! | `length`         | `min`, `max`, `exact`                              | strings, arrays          |
+//! | `char_count`     | `min`, `max`, `exact`                              | strings                  |
+//! | `bound`          | `min`, `max`, `greater_than`, `less_than`, `exact` | numbers                  |
+//! | `digits`         | `integer`, `fraction`                              | numbers, strings         |
+//! | `allowed_values` | list of values                                     | any value                |
+//! | `pattern`        | regular expression (crate feature `regex`)         | strings                  |
+//! | `must_match`     | `true`                                             | pair of `fields`         |
+//!
+//! All constraints except `required` and `must_match` are only checked if the
+//! field is present and not `null`. The constraint `must_match` is violated if
+//! only one of the related fields is present. A value of a type a constraint
+//! does not apply to is reported with the error code [`INVALID_TYPE`].
+//!
+//! Values of the document are reported in constraint violations as the
+//! variant of [`Value`] the [`wire`] format deserializes them into, e.g. an
+//! integer in the range of `i32` as `Value::Integer`.
+//!
+//! The constraint `digits` counts the digits of a number as it is written in
+//! the document. Note that `serde_json` only keeps the literal of a number,
+//! including trailing zeros of the fraction like in `12.50`, if its feature
+//! `arbitrary_precision` is enabled. Otherwise the digits of the shortest
+//! representation of the parsed float are counted. A decimal number given as
+//! string, like `"12.50"`, is always counted as written.
+//!
+//! Unknown constraint names and invalid parameters are reported as
+//! [`LoadError`] when the rule set is loaded.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_json::json;
+//! use valid::dynamic::RuleSet;
+//! use valid::constraint::INVALID_LENGTH_MAX;
+//! use valid::invalid_value;
+//!
+//! let rule_set = RuleSet::from_json(r#"{
+//!     "rules": [ { "field": "name", "length": { "max": 20 } } ]
+//! }"#).unwrap();
+//!
+//! let document = json!({ "name": "the quick brown fox jumps" });
+//!
+//! let result = rule_set.validate(document).result_with_warnings();
+//!
+//! assert_eq!(
+//!     result.unwrap_err().violations,
+//!     vec![invalid_value(INVALID_LENGTH_MAX, "name", 25, 20)]
+//! );
+//! ```
+//!
+//! [`RuleSet`]: struct.RuleSet.html
+//! [`LoadError`]: enum.LoadError.html
+//! [`INVALID_TYPE`]: constant.INVALID_TYPE.html
+//! [`Value`]: ../enum.Value.html
+//! [`wire`]: ../wire/index.html
+//! [`ValidationError`]: ../struct.ValidationError.html
+//! [`serde_json::Value`]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+
+#[cfg(feature = "regex")]
+use crate::constraint::Pattern;
+use crate::constraint::{
+    Bound, CharCount, Digits, Length, MustMatch, NotEmpty, INVALID_MUST_MATCH, INVALID_NOT_EMPTY,
+};
+use crate::{
+    invalid_optional_relation, invalid_optional_value, ConstraintViolation, RelatedFields,
+    ValidateRef, Validation, Value,
+};
+use core::convert::TryFrom;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde_json::{Map, Number, Value as JsonValue};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+
+/// Error code: the value of a required field is missing (`required`
+/// constraint)
+pub const INVALID_REQUIRED: &str = "invalid-required";
+
+/// Error code: the value is not one of the allowed values (`allowed_values`
+/// constraint)
+pub const INVALID_ALLOWED_VALUES: &str = "invalid-allowed-values";
+
+/// Error code: the value is of a type the constraint can not be applied to
+pub const INVALID_TYPE: &str = "invalid-type";
+
+/// The error returned when loading a rule set fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// The source is not well-formed JSON or TOML.
+    Syntax(String),
+    /// The rule set or a rule is not structured as expected.
+    Structure(String),
+    /// A rule references a constraint that is not known.
+    UnknownConstraint {
+        /// The index of the rule in the list of rules
+        rule: usize,
+        /// The name of the unknown constraint
+        name: String,
+    },
+    /// The parameters of a constraint are invalid.
+    InvalidParameter {
+        /// The index of the rule in the list of rules
+        rule: usize,
+        /// The name of the constraint
+        constraint: String,
+        /// Description of the problem
+        message: String,
+    },
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Syntax(message) => write!(f, "syntax error: {}", message),
+            LoadError::Structure(message) => write!(f, "invalid rule set: {}", message),
+            LoadError::UnknownConstraint { rule, name } => {
+                write!(f, "rule {}: unknown constraint `{}`", rule, name)
+            }
+            LoadError::InvalidParameter {
+                rule,
+                constraint,
+                message,
+            } => write!(
+                f,
+                "rule {}: invalid parameters for constraint `{}`: {}",
+                rule, constraint, message
+            ),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+/// A set of validation rules loaded at runtime.
+///
+/// See the [module level documentation] for the format of a rule set and an
+/// example.
+///
+/// [module level documentation]: index.html
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    target: Target,
+    checks: Vec<Check>,
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Field(String),
+    Fields(String, String),
+}
+
+#[derive(Debug, Clone)]
+enum Check {
+    Required,
+    NotEmpty,
+    Length(Length),
+    CharCount(CharCount),
+    Bound(NumberBound),
+    Digits(Digits),
+    AllowedValues(Vec<JsonValue>),
+    #[cfg(feature = "regex")]
+    Pattern(Pattern),
+    MustMatch,
+}
+
+#[derive(Debug, Clone)]
+struct NumberBound {
+    integer: Option<Bound<i64>>,
+    float: Bound<f64>,
+}
+
+impl RuleSet {
+    /// Loads a rule set from a string in JSON format.
+    pub fn from_json(source: &str) -> Result<Self, LoadError> {
+        let value: JsonValue =
+            serde_json::from_str(source).map_err(|error| LoadError::Syntax(error.to_string()))?;
+        RuleSet::from_value(&value)
+    }
+
+    /// Loads a rule set from a string in TOML format.
+    ///
+    /// The rules are given as an array of tables named `rules`:
+    ///
+    /// ```toml
+    /// [[rules]]
+    /// field = "name"
+    /// length = { max = 20 }
+    /// ```
+    ///
+    /// This method is only available with the optional crate feature `toml`.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, LoadError> {
+        let value: JsonValue =
+            toml::from_str(source).map_err(|error| LoadError::Syntax(error.to_string()))?;
+        RuleSet::from_value(&value)
+    }
+
+    /// Loads a rule set from a JSON value.
+    pub fn from_value(value: &JsonValue) -> Result<Self, LoadError> {
+        let rules = value
+            .get("rules")
+            .and_then(JsonValue::as_array)
+            .ok_or_else(|| LoadError::Structure("expected a list of `rules`".into()))?;
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| parse_rule(index, rule))
+            .collect::<Result<_, _>>()?;
+        Ok(RuleSet { rules })
+    }
+
+    /// Validates the given document by reference and returns the constraint
+    /// violations found.
+    pub fn validate_ref(&self, document: &JsonValue) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            for check in &rule.checks {
+                violations.extend(match &rule.target {
+                    Target::Field(name) => check_field(name, lookup(document, name), check),
+                    Target::Fields(name1, name2) => check_relation(
+                        name1,
+                        lookup(document, name1),
+                        name2,
+                        lookup(document, name2),
+                        check,
+                    ),
+                });
+            }
+        }
+        violations
+    }
+
+    /// Validates the given document and returns the resulting
+    /// [`Validation`].
+    ///
+    /// [`Validation`]: ../struct.Validation.html
+    pub fn validate(&self, document: JsonValue) -> Validation<RuleSet, JsonValue> {
+        let violations = self.validate_ref(&document);
+        Validation::checked(document, violations)
+    }
+}
+
+fn parse_rule(index: usize, rule: &JsonValue) -> Result<Rule, LoadError> {
+    let rule = rule
+        .as_object()
+        .ok_or_else(|| LoadError::Structure(format!("rule {} is not an object", index)))?;
+    let target = parse_target(index, rule)?;
+    let mut checks = Vec::new();
+    for (name, params) in rule {
+        if name == "field" || name == "fields" {
+            continue;
+        }
+        if let Some(check) = parse_check(index, name, params)? {
+            let is_relation = matches!(check, Check::MustMatch);
+            let has_pair = matches!(target, Target::Fields(_, _));
+            if is_relation != has_pair {
+                return Err(invalid_parameter(
+                    index,
+                    name,
+                    if is_relation {
+                        "expected a pair of `fields`"
+                    } else {
+                        "expected a single `field`"
+                    },
+                ));
+            }
+            checks.push(check);
+        }
+    }
+    if checks.is_empty() {
+        return Err(LoadError::Structure(format!(
+            "rule {} has no constraint",
+            index
+        )));
+    }
+    Ok(Rule { target, checks })
+}
+
+fn parse_target(index: usize, rule: &Map<String, JsonValue>) -> Result<Target, LoadError> {
+    match (rule.get("field"), rule.get("fields")) {
+        (Some(JsonValue::String(name)), None) => Ok(Target::Field(name.clone())),
+        (None, Some(JsonValue::Array(names))) => match names.as_slice() {
+            [JsonValue::String(name1), JsonValue::String(name2)] => {
+                Ok(Target::Fields(name1.clone(), name2.clone()))
+            }
+            _ => Err(LoadError::Structure(format!(
+                "rule {} must name exactly 2 `fields`",
+                index
+            ))),
+        },
+        _ => Err(LoadError::Structure(format!(
+            "rule {} must name either a `field` or a pair of `fields`",
+            index
+        ))),
+    }
+}
+
+fn parse_check(index: usize, name: &str, params: &JsonValue) -> Result<Option<Check>, LoadError> {
+    let check = match name {
+        "required" => parse_flag(index, name, params, Check::Required)?,
+        "not_empty" => parse_flag(index, name, params, Check::NotEmpty)?,
+        "must_match" => parse_flag(index, name, params, Check::MustMatch)?,
+        "length" => Some(Check::Length(parse_count(
+            index,
+            name,
+            params,
+            Length::Min,
+            Length::Max,
+            Length::MinMax,
+            Length::Exact,
+        )?)),
+        "char_count" => Some(Check::CharCount(parse_count(
+            index,
+            name,
+            params,
+            CharCount::Min,
+            CharCount::Max,
+            CharCount::MinMax,
+            CharCount::Exact,
+        )?)),
+        "bound" => Some(Check::Bound(parse_bound(index, name, params)?)),
+        "digits" => Some(Check::Digits(parse_digits(index, name, params)?)),
+        "allowed_values" => match params {
+            JsonValue::Array(values) if !values.is_empty() => {
+                Some(Check::AllowedValues(values.clone()))
+            }
+            _ => return Err(invalid_parameter(index, name, "expected a list of values")),
+        },
+        #[cfg(feature = "regex")]
+        "pattern" => match params {
+            JsonValue::String(pattern) => Some(Check::Pattern(Pattern(
+                Regex::new(pattern)
+                    .map_err(|error| invalid_parameter(index, name, error.to_string()))?,
+            ))),
+            _ => {
+                return Err(invalid_parameter(
+                    index,
+                    name,
+                    "expected a regular expression",
+                ))
+            }
+        },
+        _ => {
+            return Err(LoadError::UnknownConstraint {
+                rule: index,
+                name: name.into(),
+            })
+        }
+    };
+    Ok(check)
+}
+
+fn invalid_parameter(index: usize, constraint: &str, message: impl Into<String>) -> LoadError {
+    LoadError::InvalidParameter {
+        rule: index,
+        constraint: constraint.into(),
+        message: message.into(),
+    }
+}
+
+fn parse_flag(
+    index: usize,
+    name: &str,
+    params: &JsonValue,
+    check: Check,
+) -> Result<Option<Check>, LoadError> {
+    match params {
+        JsonValue::Bool(true) => Ok(Some(check)),
+        JsonValue::Bool(false) => Ok(None),
+        _ => Err(invalid_parameter(index, name, "expected `true` or `false`")),
+    }
+}
+
+fn parse_params<'a>(
+    index: usize,
+    name: &str,
+    params: &'a JsonValue,
+    allowed: &[&str],
+) -> Result<&'a Map<String, JsonValue>, LoadError> {
+    let params = params
+        .as_object()
+        .ok_or_else(|| invalid_parameter(index, name, "expected an object of parameters"))?;
+    if let Some(unknown) = params.keys().find(|key| !allowed.contains(&key.as_str())) {
+        return Err(invalid_parameter(
+            index,
+            name,
+            format!("unknown parameter `{}`", unknown),
+        ));
+    }
+    Ok(params)
+}
+
+fn parse_count<T>(
+    index: usize,
+    name: &str,
+    params: &JsonValue,
+    min: impl FnOnce(usize) -> T,
+    max: impl FnOnce(usize) -> T,
+    min_max: impl FnOnce(usize, usize) -> T,
+    exact: impl FnOnce(usize) -> T,
+) -> Result<T, LoadError> {
+    let params = parse_params(index, name, params, &["min", "max", "exact"])?;
+    let count = |key: &str| {
+        params
+            .get(key)
+            .map(|value| {
+                value.as_u64().map(|value| value as usize).ok_or_else(|| {
+                    invalid_parameter(
+                        index,
+                        name,
+                        format!("`{}` must be a non-negative integer", key),
+                    )
+                })
+            })
+            .transpose()
+    };
+    match (count("min")?, count("max")?, count("exact")?) {
+        (None, None, Some(value)) => Ok(exact(value)),
+        (Some(min_value), None, None) => Ok(min(min_value)),
+        (None, Some(max_value), None) => Ok(max(max_value)),
+        (Some(min_value), Some(max_value), None) if min_value <= max_value => {
+            Ok(min_max(min_value, max_value))
+        }
+        (Some(_), Some(_), None) => Err(invalid_parameter(
+            index,
+            name,
+            "`min` must not be greater than `max`",
+        )),
+        _ => Err(invalid_parameter(
+            index,
+            name,
+            "expected `min` and/or `max` or only `exact`",
+        )),
+    }
+}
+
+fn parse_bound(index: usize, name: &str, params: &JsonValue) -> Result<NumberBound, LoadError> {
+    let params = parse_params(
+        index,
+        name,
+        params,
+        &["min", "max", "greater_than", "less_than", "exact"],
+    )?;
+    // the order of the keys determines the positions used by `build_bound`
+    let mut numbers = Vec::new();
+    for key in &["min", "max", "greater_than", "less_than", "exact"] {
+        let number = match params.get(*key) {
+            None => None,
+            Some(JsonValue::Number(number)) => Some(number),
+            Some(_) => {
+                return Err(invalid_parameter(
+                    index,
+                    name,
+                    format!("`{}` must be a number", key),
+                ))
+            }
+        };
+        numbers.push(number);
+    }
+    let float = build_bound(index, name, &numbers, Number::as_f64)?;
+    let integer = if numbers.iter().flatten().all(|number| number.is_i64()) {
+        Some(build_bound(index, name, &numbers, Number::as_i64)?)
+    } else {
+        None
+    };
+    Ok(NumberBound { integer, float })
+}
+
+fn build_bound<T>(
+    index: usize,
+    name: &str,
+    numbers: &[Option<&Number>],
+    convert: impl Fn(&Number) -> Option<T>,
+) -> Result<Bound<T>, LoadError>
+where
+    T: PartialOrd,
+{
+    let value = |position: usize| numbers[position].and_then(&convert);
+    let bound = match (value(0), value(2), value(1), value(3), value(4)) {
+        (None, None, None, None, Some(exact)) => Bound::Exact(exact),
+        (Some(min), None, Some(max), None, None) if min <= max => Bound::ClosedRange(min, max),
+        (Some(min), None, None, Some(max), None) if min < max => Bound::ClosedOpenRange(min, max),
+        (None, Some(min), Some(max), None, None) if min < max => Bound::OpenClosedRange(min, max),
+        (None, Some(min), None, Some(max), None) if min < max => Bound::OpenRange(min, max),
+        (Some(min), None, None, None, None) => Bound::Min(min),
+        (None, None, Some(max), None, None) => Bound::Max(max),
+        (None, Some(min), None, None, None) => Bound::GreaterThan(min),
+        (None, None, None, Some(max), None) => Bound::LessThan(max),
+        _ => {
+            return Err(invalid_parameter(
+                index,
+                name,
+                "expected at most one lower and one upper limit or only `exact`, \
+                 where the lower limit must be less than the upper limit",
+            ))
+        }
+    };
+    Ok(bound)
+}
+
+fn parse_digits(index: usize, name: &str, params: &JsonValue) -> Result<Digits, LoadError> {
+    let params = parse_params(index, name, params, &["integer", "fraction"])?;
+    let digits = |key: &str| {
+        params.get(key).and_then(JsonValue::as_u64).ok_or_else(|| {
+            invalid_parameter(
+                index,
+                name,
+                format!("`{}` must be given as non-negative integer", key),
+            )
+        })
+    };
+    Ok(Digits {
+        integer: digits("integer")?,
+        fraction: digits("fraction")?,
+    })
+}
+
+fn lookup<'a>(document: &'a JsonValue, path: &str) -> Option<&'a JsonValue> {
+    path.split('.')
+        .try_fold(document, |value, key| match value {
+            JsonValue::Array(elements) => key.parse::<usize>().ok().and_then(|i| elements.get(i)),
+            _ => value.get(key),
+        })
+        .filter(|value| !value.is_null())
+}
+
+fn to_value(value: &JsonValue) -> Value {
+    match value {
+        JsonValue::Bool(value) => Value::Boolean(*value),
+        // the same variants as inferred by the wire format
+        JsonValue::Number(number) => match (number.as_i64(), number.as_f64()) {
+            (Some(value), _) => i32::try_from(value).map_or(Value::Long(value), Value::Integer),
+            (None, Some(value)) => Value::Double(value),
+            (None, None) => Value::String(number.to_string()),
+        },
+        JsonValue::String(value) => Value::String(value.clone()),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn invalid_type(name: &str, value: &JsonValue, expected: &str) -> Vec<ConstraintViolation> {
+    vec![invalid_optional_value(
+        INVALID_TYPE,
+        name.to_string(),
+        Some(to_value(value)),
+        Some(Value::String(expected.into())),
+    )]
+}
+
+fn check_field(name: &str, value: Option<&JsonValue>, check: &Check) -> Vec<ConstraintViolation> {
+    let name = name.to_string();
+    let value = match (value, check) {
+        (None, Check::Required) => {
+            return vec![invalid_optional_value(INVALID_REQUIRED, name, None, None)]
+        }
+        (None, _) => return vec![],
+        (Some(value), _) => value,
+    };
+    match (check, value) {
+        (Check::Required, _) => vec![],
+        (Check::NotEmpty, JsonValue::String(value)) => value.validate_ref(name, &NotEmpty),
+        (Check::NotEmpty, JsonValue::Array(value)) => value.validate_ref(name, &NotEmpty),
+        (Check::NotEmpty, JsonValue::Object(value)) if value.is_empty() => {
+            vec![invalid_optional_value(INVALID_NOT_EMPTY, name, None, None)]
+        }
+        (Check::NotEmpty, JsonValue::Object(_)) => vec![],
+        (Check::NotEmpty, value) => invalid_type(&name, value, "string, array or object"),
+        (Check::Length(length), JsonValue::String(value)) => value.validate_ref(name, length),
+        (Check::Length(length), JsonValue::Array(value)) => value.validate_ref(name, length),
+        (Check::Length(_), value) => invalid_type(&name, value, "string or array"),
+        (Check::CharCount(char_count), JsonValue::String(value)) => {
+            value.validate_ref(name, char_count)
+        }
+        (Check::CharCount(_), value) => invalid_type(&name, value, "string"),
+        (Check::Bound(bound), JsonValue::Number(number)) => {
+            match (number.as_i64(), &bound.integer) {
+                (Some(value), Some(integer)) => value.validate_ref(name, integer),
+                _ => number
+                    .as_f64()
+                    .map(|value| value.validate_ref(name.clone(), &bound.float))
+                    .unwrap_or_else(|| invalid_type(&name, value, "number")),
+            }
+        }
+        (Check::Bound(_), value) => invalid_type(&name, value, "number"),
+        (Check::Digits(digits), JsonValue::Number(number)) => {
+            // the literal keeps trailing zeros if `arbitrary_precision` is enabled
+            let literal = number.to_string();
+            if !literal.contains(['e', 'E']) {
+                literal.validate_ref(name, digits)
+            } else if let Some(value) = number.as_i64() {
+                value.validate_ref(name, digits)
+            } else {
+                number
+                    .as_f64()
+                    .map(|value| value.validate_ref(name.clone(), digits))
+                    .unwrap_or_else(|| invalid_type(&name, value, "number"))
+            }
+        }
+        (Check::Digits(digits), JsonValue::String(value)) => value.validate_ref(name, digits),
+        (Check::Digits(_), value) => invalid_type(&name, value, "number or string"),
+        (Check::AllowedValues(allowed), value) => {
+            if allowed.contains(value) {
+                vec![]
+            } else {
+                vec![invalid_optional_value(
+                    INVALID_ALLOWED_VALUES,
+                    name,
+                    Some(to_value(value)),
+                    None,
+                )]
+            }
+        }
+        #[cfg(feature = "regex")]
+        (Check::Pattern(pattern), JsonValue::String(value)) => value.validate_ref(name, pattern),
+        #[cfg(feature = "regex")]
+        (Check::Pattern(_), value) => invalid_type(&name, value, "string"),
+        (Check::MustMatch, _) => vec![],
+    }
+}
+
+fn check_relation(
+    name1: &str,
+    value1: Option<&JsonValue>,
+    name2: &str,
+    value2: Option<&JsonValue>,
+    check: &Check,
+) -> Vec<ConstraintViolation> {
+    match (check, value1, value2) {
+        (Check::MustMatch, Some(value1), Some(value2)) => (to_value(value1), to_value(value2))
+            .validate_ref(
+                RelatedFields(name1.to_string().into(), name2.to_string().into()),
+                &MustMatch,
+            ),
+        (Check::MustMatch, None, None) => vec![],
+        (Check::MustMatch, value1, value2) => vec![invalid_optional_relation(
+            INVALID_MUST_MATCH,
+            name1.to_string(),
+            value1.map(to_value),
+            name2.to_string(),
+            value2.map(to_value),
+        )],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,410 @@
+use super::*;
+use crate::constraint::{
+    INVALID_BOUND_CLOSED_MAX, INVALID_BOUND_OPEN_MIN, INVALID_CHAR_COUNT_MIN,
+    INVALID_DIGITS_FRACTION, INVALID_LENGTH_MAX, INVALID_MUST_MATCH,
+};
+use crate::{invalid_optional_relation, invalid_relation, invalid_value, ValidationError};
+use serde_json::json;
+
+mod load {
+    use super::*;
+
+    #[test]
+    fn load_a_rule_set_from_json() {
+        let rule_set = RuleSet::from_json(
+            r#"{
+                "rules": [
+                    { "field": "name", "required": true, "length": { "min": 2, "max": 20 } },
+                    { "field": "age", "bound": { "min": 13, "less_than": 120.5 } },
+                    { "field": "price", "digits": { "integer": 4, "fraction": 2 } },
+                    { "field": "role", "allowed_values": ["admin", "member"] },
+                    { "fields": ["password", "password2"], "must_match": true }
+                ]
+            }"#,
+        );
+
+        assert_eq!(rule_set.unwrap().rules.len(), 5);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn load_a_rule_set_from_toml() {
+        let rule_set = RuleSet::from_toml(
+            r#"
+                [[rules]]
+                field = "name"
+                char_count = { max = 20 }
+
+                [[rules]]
+                fields = ["password", "password2"]
+                must_match = true
+            "#,
+        );
+
+        assert_eq!(rule_set.unwrap().rules.len(), 2);
+    }
+
+    #[test]
+    fn a_malformed_source_is_a_syntax_error() {
+        let result = RuleSet::from_json(r#"{ "rules": [ "#);
+
+        assert!(matches!(result, Err(LoadError::Syntax(_))));
+    }
+
+    #[test]
+    fn a_source_without_rules_is_a_structure_error() {
+        let result = RuleSet::from_json(r#"{ "field": "name" }"#);
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::Structure("expected a list of `rules`".into())
+        );
+    }
+
+    #[test]
+    fn a_rule_without_field_is_a_structure_error() {
+        let result = RuleSet::from_json(r#"{ "rules": [ { "not_empty": true } ] }"#);
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::Structure("rule 0 must name either a `field` or a pair of `fields`".into())
+        );
+    }
+
+    #[test]
+    fn an_unknown_constraint_is_a_load_error() {
+        let result = RuleSet::from_json(
+            r#"{ "rules": [
+                { "field": "name", "not_empty": true },
+                { "field": "name", "lenght": { "max": 20 } }
+            ] }"#,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::UnknownConstraint {
+                rule: 1,
+                name: "lenght".into()
+            }
+        );
+    }
+
+    #[test]
+    fn an_unknown_parameter_is_a_load_error() {
+        let result = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "name", "length": { "maximum": 20 } } ] }"#,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::InvalidParameter {
+                rule: 0,
+                constraint: "length".into(),
+                message: "unknown parameter `maximum`".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn a_negative_length_is_a_load_error() {
+        let result =
+            RuleSet::from_json(r#"{ "rules": [ { "field": "name", "length": { "max": -1 } } ] }"#);
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::InvalidParameter {
+                rule: 0,
+                constraint: "length".into(),
+                message: "`max` must be a non-negative integer".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn a_length_with_min_greater_than_max_is_a_load_error() {
+        let result = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "name", "length": { "min": 5, "max": 4 } } ] }"#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(LoadError::InvalidParameter { rule: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn a_bound_with_two_lower_limits_is_a_load_error() {
+        let result = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "age", "bound": { "min": 5, "greater_than": 4 } } ] }"#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(LoadError::InvalidParameter { rule: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn a_relation_constraint_on_a_single_field_is_a_load_error() {
+        let result =
+            RuleSet::from_json(r#"{ "rules": [ { "field": "password", "must_match": true } ] }"#);
+
+        assert_eq!(
+            result.unwrap_err(),
+            LoadError::InvalidParameter {
+                rule: 0,
+                constraint: "must_match".into(),
+                message: "expected a pair of `fields`".into(),
+            }
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn an_invalid_regular_expression_is_a_load_error() {
+        let result =
+            RuleSet::from_json(r#"{ "rules": [ { "field": "code", "pattern": "[a-z" } ] }"#);
+
+        assert!(matches!(
+            result,
+            Err(LoadError::InvalidParameter { rule: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn display_format_a_load_error() {
+        let error = LoadError::UnknownConstraint {
+            rule: 3,
+            name: "lenght".into(),
+        };
+
+        assert_eq!(error.to_string(), "rule 3: unknown constraint `lenght`");
+    }
+}
+
+mod validate {
+    use super::*;
+
+    fn rule_set() -> RuleSet {
+        RuleSet::from_json(
+            r#"{
+                "rules": [
+                    { "field": "name", "required": true, "char_count": { "min": 2 } },
+                    { "field": "tags", "length": { "max": 2 } },
+                    { "field": "age", "bound": { "greater_than": 12, "max": 120 } },
+                    { "field": "price", "digits": { "integer": 4, "fraction": 2 } },
+                    { "field": "role", "allowed_values": ["admin", "member"] },
+                    { "field": "address.zip_code", "required": true },
+                    { "fields": ["password", "password2"], "must_match": true }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn a_valid_document_is_returned_as_validated_value() {
+        let document = json!({
+            "name": "Jane",
+            "tags": ["a", "b"],
+            "age": 42,
+            "price": 12.5,
+            "role": "admin",
+            "address": { "zip_code": "12345" },
+            "password": "s3cr3t",
+            "password2": "s3cr3t"
+        });
+
+        let result = rule_set().validate(document.clone()).result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), document);
+    }
+
+    #[test]
+    fn optional_fields_that_are_missing_or_null_are_not_validated() {
+        let document = json!({
+            "name": "Jane",
+            "age": null,
+            "address": { "zip_code": "12345" }
+        });
+
+        let violations = rule_set().validate_ref(&document);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn an_invalid_document_results_in_a_validation_error_with_all_violations() {
+        let document = json!({
+            "name": "J",
+            "tags": ["a", "b", "c"],
+            "age": 121,
+            "price": 12.505,
+            "role": "guest",
+            "address": {},
+            "password": "s3cr3t",
+            "password2": "s3crEt"
+        });
+
+        let result = rule_set().validate(document).result_with_warnings();
+
+        assert_eq!(
+            result,
+            Err(ValidationError {
+                message: None,
+                violations: vec![
+                    invalid_value(INVALID_CHAR_COUNT_MIN, "name", 1, 2),
+                    invalid_value(INVALID_LENGTH_MAX, "tags", 3, 2),
+                    invalid_value(INVALID_BOUND_CLOSED_MAX, "age", 121_i64, 120_i64),
+                    invalid_value(INVALID_DIGITS_FRACTION, "price", 3_u64, 2_u64),
+                    invalid_optional_value(
+                        INVALID_ALLOWED_VALUES,
+                        "role",
+                        Some(Value::String("guest".into())),
+                        None
+                    ),
+                    invalid_optional_value(INVALID_REQUIRED, "address.zip_code", None, None),
+                    invalid_relation(
+                        INVALID_MUST_MATCH,
+                        "password",
+                        "s3cr3t".to_string(),
+                        "password2",
+                        "s3crEt".to_string()
+                    ),
+                ],
+                truncated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn a_bound_with_float_limits_is_applied_to_integer_values() {
+        let rule_set = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "age", "bound": { "greater_than": 12.5 } } ] }"#,
+        )
+        .unwrap();
+
+        let violations = rule_set.validate_ref(&json!({ "age": 12 }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(INVALID_BOUND_OPEN_MIN, "age", 12., 12.5)]
+        );
+    }
+
+    #[test]
+    fn a_value_of_wrong_type_is_reported_as_invalid_type() {
+        let violations = rule_set().validate_ref(&json!({
+            "name": 42,
+            "address": { "zip_code": "12345" }
+        }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(
+                INVALID_TYPE,
+                "name",
+                42,
+                "string".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn must_match_is_violated_if_only_one_of_the_fields_is_present() {
+        let violations = rule_set().validate_ref(&json!({
+            "name": "Jane",
+            "address": { "zip_code": "12345" },
+            "password": "s3cr3t"
+        }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_optional_relation(
+                INVALID_MUST_MATCH,
+                "password",
+                Some(Value::String("s3cr3t".into())),
+                "password2",
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn numbers_are_reported_as_the_variant_inferred_by_the_wire_format() {
+        let rule_set = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "level", "allowed_values": [1, 2] } ] }"#,
+        )
+        .unwrap();
+
+        let violations = rule_set.validate_ref(&json!({ "level": 3 }));
+        let actual = match &violations[..] {
+            [ConstraintViolation::Field(invalid_value)] => invalid_value.field.actual.clone(),
+            _ => None,
+        };
+        assert_eq!(actual.as_ref().map(Value::type_name), Some("integer"));
+
+        let violations = rule_set.validate_ref(&json!({ "level": 3_000_000_000_i64 }));
+        let actual = match &violations[..] {
+            [ConstraintViolation::Field(invalid_value)] => invalid_value.field.actual.clone(),
+            _ => None,
+        };
+        assert_eq!(actual.as_ref().map(Value::type_name), Some("long"));
+    }
+
+    #[test]
+    fn digits_of_numbers_in_exponent_notation_are_counted() {
+        let rule_set = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "rate", "digits": { "integer": 1, "fraction": 6 } } ] }"#,
+        )
+        .unwrap();
+
+        let violations = rule_set.validate_ref(&json!({ "rate": 1e-7 }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(INVALID_DIGITS_FRACTION, "rate", 7_u64, 6_u64)]
+        );
+    }
+
+    #[test]
+    fn digits_of_decimal_strings_are_counted_as_written() {
+        let violations = rule_set().validate_ref(&json!({
+            "name": "Jane",
+            "price": "12.500",
+            "address": { "zip_code": "12345" }
+        }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(
+                INVALID_DIGITS_FRACTION,
+                "price",
+                3_u64,
+                2_u64
+            )]
+        );
+    }
+
+    #[test]
+    fn array_elements_are_addressed_by_index() {
+        let rule_set = RuleSet::from_json(
+            r#"{ "rules": [ { "field": "items.1.name", "not_empty": true } ] }"#,
+        )
+        .unwrap();
+
+        let violations = rule_set.validate_ref(&json!({
+            "items": [ { "name": "first" }, { "name": "" } ]
+        }));
+
+        assert_eq!(
+            violations,
+            vec![invalid_optional_value(
+                INVALID_NOT_EMPTY,
+                "items.1.name",
+                None,
+                None
+            )]
+        );
+    }
+}
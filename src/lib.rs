@@ -69,19 +69,28 @@
 //! the validation function that enables us to implement more complex
 //! validations and add additional parameters to the returned error.
 //!
-//! The context can be one of 3 types, where each type corresponds to one of the
-//! 3 categories mentioned above:
+//! The context can be one of 5 types, where each type corresponds to one of the
+//! 3 categories mentioned above. The relation between fields may be defined for
+//! a pair of fields or for any number of fields. Field level constraints that
+//! need some state information to be validated use the combined field state
+//! context:
 //!
 //! * [`FieldName`] - provides a name of the field that is validated
 //! * [`RelatedFields`] - provides the names of two related fields
+//! * [`RelatedFieldSet`] - provides the names of any number of related fields
 //! * [`State<S>`] - provides some generic state information
+//! * [`FieldState<S>`] - provides the name of the field that is validated
+//!   together with some generic state information
 //!
 //! For the second aspect the [`ValidationError`] struct as defined by this
 //! crate contains a list of [`ConstraintViolation`]s. A constraint violation is
-//! an enum with 3 variants, one for each of the 3 categories we talked about:
+//! an enum with 4 variants, one for each category of context types we talked
+//! about. Validations in the [`FieldName`] and in the [`FieldState<S>`] context
+//! both report violations of field level constraints:
 //!
 //! * `ConstraintViolation::Field(InvalidaValue)`
 //! * `ConstraintViolation::Relation(InvalidRelation)`
+//! * `ConstraintViolation::FieldSet(InvalidFieldSet)`
 //! * `ConstraintViolation::State(InvalidState)`
 //!
 //!
@@ -117,11 +126,12 @@
 //!
 //! let text = String::from("the answer is 42");
 //!
-//! let result = text.validate("text", &CharCount::MinMax(2, 16)).result();
+//! let result = text.validate("text", &CharCount::MinMax(2, 16)).result_with_warnings();
 //!
-//! let validated = result.expect("successful validation");
+//! let (validated, warnings) = result.expect("successful validation");
 //!
 //! assert_eq!(validated.unwrap(), String::from("the answer is 42"));
+//! assert!(warnings.is_empty());
 //! ```
 //!
 //! Validating a pair of related values:
@@ -133,13 +143,32 @@
 //! let password = "s3cr3t".to_string();
 //! let repeated = "s3cr3t".to_string();
 //!
-//! let result = (password, repeated).validate(("password", "repeated"), &MustMatch).result();
+//! let result = (password, repeated)
+//!     .validate(("password", "repeated"), &MustMatch)
+//!     .result_with_warnings();
 //!
-//! let validated = result.expect("successful validation");
+//! let (validated, _) = result.expect("successful validation");
 //!
 //! assert_eq!(validated.unwrap(), ("s3cr3t".to_string(), "s3cr3t".to_string()));
 //! ```
 //!
+//! User input can be normalized before the constraint is checked by composing
+//! the constraint with the transforms of the [`normalize`] module:
+//!
+//! ```
+//! use valid::Validate;
+//! use valid::constraint::Length;
+//! use valid::normalize::{Lowercase, Normalized, Trim};
+//!
+//! let email = String::from(" Jane@Example.COM ");
+//!
+//! let result = email
+//!     .validate("email", &Normalized((Trim, Lowercase), Length::Max(16)))
+//!     .result_with_warnings();
+//!
+//! assert_eq!(result.unwrap().0.unwrap(), "jane@example.com");
+//! ```
+//!
 //!
 //! # Validation errors
 //!
@@ -153,24 +182,28 @@
 //! Here is an example for a validation that is failing with a message:
 //!
 //! ```
-//! use valid::{Validate, ValidationError, InvalidValue, Field, Value};
+//! use valid::{Validate, ValidationError, InvalidValue, Field, Severity, Value};
 //! use valid::constraint::CharCount;
 //!
 //! let text = String::from("the answer is 42");
 //!
-//! let result = text.validate("text", &CharCount::MinMax(2, 15)).with_message("validating `text`");
+//! let result = text
+//!     .validate("text", &CharCount::MinMax(2, 15))
+//!     .result_with_warnings()
+//!     .map_err(|error| error.with_message("validating `text`"));
 //!
-//! assert_eq!(result, Err(ValidationError {
-//!     message: Some("validating `text`".into()),
-//!     violations: vec![InvalidValue {
+//! assert_eq!(result, Err(ValidationError::new(
+//!     Some("validating `text`".into()),
+//!     vec![InvalidValue {
 //!         code: "invalid-char-count-max".into(),
+//!         severity: Severity::Error,
 //!         field: Field {
 //!             name: "text".into(),
 //!             actual: Some(Value::Integer(16)),
 //!             expected: Some(Value::Integer(15)),
 //!         }
 //!     }.into()],
-//! }));
+//! )));
 //!
 //! let error = result.unwrap_err();
 //!
@@ -178,17 +211,36 @@
 //! assert_eq!(error.to_string(), "validating `text`: [ invalid-char-count-max of text which is 16, expected to be 15 ]");
 //!
 //! // ValidationError can be converted into `failure::Error`
+//! # #[cfg(feature = "std")]
 //! let error: failure::Error = error.into();
 //! ```
 //!
 //! [`ValidationError`] implements the `Display` and `std::error::Error` trait
 //! from std-lib. It also can be converted into a `failure::Error` from the
-//! [`failure`] crate.
+//! [`failure`] crate. The `std::error::Error` trait is only implemented with
+//! the crate feature "std" enabled, which is enabled by default.
+//!
+//! # Usage without std
+//!
+//! The crate can be used in `no_std` environments that provide an allocator
+//! by disabling the default crate feature "std". In this case the
+//! `std::error::Error` trait is not implemented and the property traits are
+//! not implemented for `HashMap` and `HashSet`. The optional crate features
+//! "serde1", "dynamic", "toml", "schemars" and "proptest" require std and
+//! enable the "std" feature.
 //!
 //! With the optional crate feature "serde1" enabled the `ValidationError`
 //! implements `Serialize` and `Deserialize` from the [`serde`] crate. This
 //! enables us to send errors to the client of an application via the network
-//! or store them in a database.
+//! or store them in a database. The [`wire`] module provides a flat and
+//! versioned representation of validation errors that is meant to be
+//! exchanged with other applications.
+//!
+//! Each constraint violation has a [`Severity`]. Only violations of severity
+//! `Error` make a validation fail. A validation that found only violations of
+//! severity `Warning` or `Info` is successful and returns those violations as
+//! warnings from [`Validation::result_with_warnings`], which is the method
+//! to finish a validation with.
 //!
 //!
 //! # Composite validation functions
@@ -221,10 +273,10 @@
 //! #     password2: String,
 //! #     age: i32,
 //! # }
-//! use valid::{State, Validate, Validation, ValidationResult};
+//! use valid::{State, Validate, Validation};
 //! use valid::constraint::{Bound, CharCount, MustMatch};
 //!
-//! fn validate_register_user_cmd(command: RegisterUser) -> ValidationResult<(), RegisterUser> {
+//! fn validate_register_user_cmd(command: RegisterUser) -> Validation<(), RegisterUser> {
 //!     let RegisterUser {
 //!         username,
 //!         password,
@@ -247,7 +299,6 @@
 //!             password2,
 //!             age,
 //!         })
-//!         .with_message("validating register user command")
 //! }
 //!
 //! let register_user = RegisterUser {
@@ -258,8 +309,10 @@
 //! };
 //! let original = register_user.clone();
 //!
-//! let result = validate_register_user_cmd(register_user);
-//! let validated = result.unwrap();
+//! let result = validate_register_user_cmd(register_user)
+//!     .result_with_warnings()
+//!     .map_err(|error| error.with_message("validating register user command"));
+//! let (validated, _) = result.unwrap();
 //!
 //! assert_eq!(validated.unwrap(), original);
 //! ```
@@ -317,9 +370,10 @@
 //!
 //! let result = register_user
 //!     .validate((), &RegistrationForm)
-//!     .with_message("validating register user command");
+//!     .result_with_warnings()
+//!     .map_err(|error| error.with_message("validating register user command"));
 //!
-//! let validated = result.unwrap();
+//! let (validated, _) = result.unwrap();
 //!
 //! assert_eq!(validated.unwrap(), original);
 //! ```
@@ -432,16 +486,19 @@
 //! #         }
 //! #     }
 //! # }
-//! let validated = Weekday::Monday.validate("day of release", &Workday::ExclSaturday).result()
+//! let (validated, _) = Weekday::Monday.validate("day of release", &Workday::ExclSaturday)
+//!     .result_with_warnings()
 //!     .expect("a valid workday");
 //!
 //! assert_eq!(validated.unwrap(), Weekday::Monday);
 //!
-//! let result = Weekday::Saturday.validate("day of release", &Workday::ExclSaturday).result();
+//! let result = Weekday::Saturday.validate("day of release", &Workday::ExclSaturday)
+//!     .result_with_warnings();
 //!
 //! assert!(result.is_err());
 //!
-//! let result = Weekday::Saturday.validate("day of release", &Workday::InclSaturday).result();
+//! let result = Weekday::Saturday.validate("day of release", &Workday::InclSaturday)
+//!     .result_with_warnings();
 //!
 //! assert!(result.is_ok());
 //! ```
@@ -541,27 +598,36 @@
 //! };
 //! let original_cmd = revert_reservation.clone();
 //!
-//! let result = revert_reservation.validate(&reservation_list, &IsNotReverted).result();
-//! let validated = result.expect("validating revert reservation command");
+//! let result = revert_reservation
+//!     .validate(&reservation_list, &IsNotReverted)
+//!     .result_with_warnings();
+//! let (validated, _) = result.expect("validating revert reservation command");
 //!
 //! assert_eq!(validated.unwrap(), original_cmd);
 //! ```
 //!
 //! [`constraint`]: constraint/index.html
 //! [`property`]: property/index.html
+//! [`normalize`]: normalize/index.html
 //! [`ConstraintViolation`]: enum.ConstraintViolation.html
 //! [`FieldName`]: struct.FieldName.html
 //! [`RelatedFields`]: struct.RelatedFields.html
+//! [`RelatedFieldSet`]: struct.RelatedFieldSet.html
 //! [`State`]: struct.State.html
 //! [`State<S>`]: struct.State.html
+//! [`FieldState<S>`]: struct.FieldState.html
 //! [`Validate`]: trait.Validate.html
 //! [`Validation::and`]: struct.Validation.html#method.and
 //! [`Validation::and_then`]: struct.Validation.html#method.and_then
 //! [`ValidationError`]: struct.ValidationError.html
 //! [`failure`]: https://crates.io/crates/failure
 //! [`serde`]: https://crates.io/crates/serde
+//! [`wire`]: wire/index.html
+//! [`Severity`]: enum.Severity.html
+//! [`Validation::result_with_warnings`]: struct.Validation.html#method.result_with_warnings
 
 #![doc(html_root_url = "https://docs.rs/valid/0.3.1")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(unsafe_code, unstable_features)]
 #![warn(
     bare_trait_objects,
@@ -578,25 +644,54 @@
     variant_size_differences
 )]
 
+extern crate alloc;
+
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+pub mod catalog;
 pub mod constraint;
 mod core;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+mod newtype;
+pub mod normalize;
 #[cfg(feature = "num-traits")]
 mod num;
+#[cfg(feature = "serde1")]
+pub mod problem;
 pub mod property;
+#[cfg(feature = "schemars")]
+pub mod schema;
 mod std_types;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod testing;
+#[cfg(feature = "serde1")]
+pub mod wire;
 
 // re-export the core API
 pub use crate::core::{
-    invalid_optional_value, invalid_relation, invalid_state, invalid_value, param,
-    ConstraintViolation, Field, FieldName, InvalidRelation, InvalidState, InvalidValue, Parameter,
-    RelatedFields, State, Validate, Validated, Validation, ValidationError, ValidationResult,
-    Value,
+    invalid_field_set, invalid_optional_relation, invalid_optional_value, invalid_relation,
+    invalid_state, invalid_value, param, ConstraintViolation, Field, FieldName, FieldState,
+    IntoValidation, InvalidFieldSet, InvalidRelation, InvalidState, InvalidValue, Parameter,
+    RelatedFieldSet, RelatedFields, Severity, State, Validate, ValidateRef, Validated, Validation,
+    ValidationError, ValidationMode, ValidationResult, Validator, Value,
 };
 
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "serde1")]
+    pub use serde;
+}
+
 #[cfg(test)]
 mod tests {
     use failure as _;
+    #[cfg(not(feature = "serde1"))]
+    use serde_json as _;
     use version_sync as _;
 }
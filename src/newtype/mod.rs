@@ -0,0 +1,214 @@
+//! A macro for defining validated newtypes
+
+/// Defines a newtype around a [`Validated`] value.
+///
+/// Domain primitives like a username or an SKU are often represented by a
+/// newtype that can only be constructed from a value that complies to some
+/// constraints. This macro generates such a newtype wrapping a
+/// `Validated<C, T>`, where `C` is the constraint or the tuple of constraints
+/// given to the macro and `T` is the inner type.
+///
+/// For a newtype `Name` with the inner type `T` the macro generates:
+///
+/// * the constructor `Name::new(value: impl Into<T>) -> Result<Name, ValidationError>`
+///   which validates the value against all given constraints in the
+///   [`FieldName`] context with the given field name
+/// * the constructor `Name::new_with_warnings(value: impl Into<T>) -> Result<(Name, Vec<ConstraintViolation>), ValidationError>`
+///   which returns the violations of severity `Warning` or `Info` as well
+/// * the methods `Name::validated(&self) -> &Validated<C, T>` and
+///   `Name::into_inner(self) -> T`
+/// * `TryFrom<T>` and `FromStr` with `ValidationError` as error type
+/// * `From<Validated<C, T>>`
+/// * `Display`, delegating to the inner value
+/// * `AsRef<U>` for any `U` the inner type implements `AsRef<U>` for, for
+///   example `AsRef<str>` for an inner `String`
+/// * `Serialize` and `Deserialize` (only with the crate feature `serde1`),
+///   where deserialization validates the value
+///
+/// The inner type must implement `Display` and `From<String>` and it must
+/// implement [`ValidateRef`] for each of the constraints in the `FieldName`
+/// context. Each constraint is given as its type and the expression that
+/// constructs the constraint separated by `=`.
+///
+/// The attributes given to the newtype, like doc comments and derives, are
+/// applied to the generated struct.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use valid::{invalid_value, validated_newtype, ValidationError};
+/// use valid::constraint::{CharCount, NotEmpty, INVALID_CHAR_COUNT_MIN};
+///
+/// validated_newtype! {
+///     /// The login name of a user
+///     #[derive(Debug, Clone, PartialEq, Eq)]
+///     pub struct Username(String) {
+///         field: "username",
+///         constraints: {
+///             NotEmpty = NotEmpty,
+///             CharCount = CharCount::MinMax(3, 20),
+///         }
+///     }
+/// }
+///
+/// let username = Username::new("jane.doe").unwrap();
+///
+/// assert_eq!(username.to_string(), "jane.doe");
+/// assert_eq!(username.as_ref() as &str, "jane.doe");
+/// assert_eq!("jane.doe".parse::<Username>(), Ok(username));
+///
+/// assert_eq!(
+///     Username::try_from(String::from("jd")),
+///     Err(ValidationError::new(
+///         None,
+///         vec![invalid_value(INVALID_CHAR_COUNT_MIN, "username", 2, 3)],
+///     ))
+/// );
+/// ```
+///
+/// [`Validated`]: struct.Validated.html
+/// [`ValidateRef`]: trait.ValidateRef.html
+/// [`FieldName`]: struct.FieldName.html
+#[macro_export]
+macro_rules! validated_newtype {
+    (@constraints $constraint:ty) => {
+        $constraint
+    };
+    (@constraints $($constraint:ty),+) => {
+        ($($constraint),+)
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($inner:ty) {
+            field: $field:literal,
+            constraints: {
+                $($constraint:ty = $constraint_value:expr),+ $(,)?
+            } $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name(
+            $crate::Validated<$crate::validated_newtype!(@constraints $($constraint),+), $inner>,
+        );
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Validates the given value and wraps it if it is valid.
+            ///
+            /// Violations of severity `Warning` or `Info` do not prevent the
+            /// construction and are not returned. Use `new_with_warnings` to
+            /// get them.
+            $vis fn new(value: impl ::core::convert::Into<$inner>) -> ::core::result::Result<Self, $crate::ValidationError> {
+                $name::new_with_warnings(value).map(|(newtype, _)| newtype)
+            }
+
+            /// Validates the given value and wraps it if it is valid, together
+            /// with the violations of severity `Warning` or `Info`.
+            #[allow(clippy::type_complexity)]
+            $vis fn new_with_warnings(
+                value: impl ::core::convert::Into<$inner>,
+            ) -> ::core::result::Result<(Self, $crate::__private::Vec<$crate::ConstraintViolation>), $crate::ValidationError> {
+                let value: $inner = value.into();
+                let mut violations = $crate::__private::Vec::new();
+                $(
+                    violations.extend(<$inner as $crate::ValidateRef<$constraint, $crate::FieldName>>::validate_ref(
+                        &value,
+                        $field,
+                        &$constraint_value,
+                    ));
+                )+
+                $crate::Validation::checked(value, violations)
+                    .result_with_warnings()
+                    .map(|(validated, warnings)| ($name(validated), warnings))
+            }
+
+            /// Returns a reference to the validated value.
+            $vis fn validated(&self) -> &$crate::Validated<$crate::validated_newtype!(@constraints $($constraint),+), $inner> {
+                &self.0
+            }
+
+            /// Unwraps the inner value.
+            $vis fn into_inner(self) -> $inner {
+                self.0.unwrap()
+            }
+        }
+
+        impl ::core::convert::From<$crate::Validated<$crate::validated_newtype!(@constraints $($constraint),+), $inner>> for $name {
+            fn from(validated: $crate::Validated<$crate::validated_newtype!(@constraints $($constraint),+), $inner>) -> Self {
+                $name(validated)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$inner> for $name {
+            type Error = $crate::ValidationError;
+
+            fn try_from(value: $inner) -> ::core::result::Result<Self, Self::Error> {
+                $name::new(value)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ValidationError;
+
+            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                $name::new($crate::__private::String::from(value))
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&*self.0, f)
+            }
+        }
+
+        impl<U: ?Sized> ::core::convert::AsRef<U> for $name
+        where
+            $inner: ::core::convert::AsRef<U>,
+        {
+            fn as_ref(&self) -> &U {
+                (*self.0).as_ref()
+            }
+        }
+
+        $crate::__validated_newtype_serde!($name, $inner);
+    };
+}
+
+#[cfg(feature = "serde1")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validated_newtype_serde {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&*self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let value = <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(
+                    deserializer,
+                )?;
+                $name::new(value).map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde1"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validated_newtype_serde {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[cfg(test)]
+mod tests;
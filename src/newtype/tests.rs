@@ -0,0 +1,193 @@
+use crate::constraint::{
+    CharCount, Length, NotEmpty, INVALID_CHAR_COUNT_MAX, INVALID_LENGTH_MAX, INVALID_NOT_EMPTY,
+};
+use crate::{
+    invalid_optional_value, invalid_value, ConstraintViolation, FieldName, Severity, ValidateRef,
+    ValidationError,
+};
+use std::convert::TryFrom;
+
+validated_newtype! {
+    /// A stock keeping unit
+    #[derive(Debug, Clone, PartialEq)]
+    struct Sku(String) {
+        field: "sku",
+        constraints: {
+            Length = Length::Max(8),
+        }
+    }
+}
+
+validated_newtype! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct Username(String) {
+        field: "username",
+        constraints: {
+            NotEmpty = NotEmpty,
+            CharCount = CharCount::Max(10),
+        },
+    }
+}
+
+struct NoUppercase;
+
+impl ValidateRef<NoUppercase, FieldName> for String {
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        _constraint: &NoUppercase,
+    ) -> Vec<ConstraintViolation> {
+        if self.chars().any(char::is_uppercase) {
+            vec![invalid_value(
+                "invalid-no-uppercase",
+                name,
+                self.clone(),
+                self.to_lowercase(),
+            )
+            .with_severity(Severity::Warning)]
+        } else {
+            vec![]
+        }
+    }
+}
+
+validated_newtype! {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Nickname(String) {
+        field: "nickname",
+        constraints: {
+            NoUppercase = NoUppercase,
+        }
+    }
+}
+
+#[test]
+fn new_with_warnings_returns_the_warnings_of_a_valid_value() {
+    let (nickname, warnings) = Nickname::new_with_warnings("JaneD").unwrap();
+
+    assert_eq!(nickname.into_inner(), "JaneD");
+    assert_eq!(
+        warnings,
+        vec![invalid_value(
+            "invalid-no-uppercase",
+            "nickname",
+            "JaneD".to_string(),
+            "janed".to_string()
+        )
+        .with_severity(Severity::Warning)]
+    );
+    assert_eq!(
+        Nickname::new("JaneD").map(Nickname::into_inner),
+        Ok("JaneD".to_string())
+    );
+}
+
+#[test]
+fn new_accepts_a_valid_value() {
+    let sku = Sku::new("AB-1234").unwrap();
+
+    assert_eq!(sku.into_inner(), "AB-1234");
+}
+
+#[test]
+fn new_rejects_an_invalid_value() {
+    let result = Sku::new("AB-1234-5678");
+
+    assert_eq!(
+        result,
+        Err(ValidationError::new(
+            None,
+            vec![invalid_value(INVALID_LENGTH_MAX, "sku", 12, 8)]
+        ))
+    );
+}
+
+#[test]
+fn all_constraints_are_validated() {
+    assert_eq!(
+        Username::new(""),
+        Err(ValidationError::new(
+            None,
+            vec![invalid_optional_value(
+                INVALID_NOT_EMPTY,
+                "username",
+                None,
+                None
+            )]
+        ))
+    );
+    assert_eq!(
+        Username::new("the.quick.brown.fox"),
+        Err(ValidationError::new(
+            None,
+            vec![invalid_value(INVALID_CHAR_COUNT_MAX, "username", 19, 10)]
+        ))
+    );
+}
+
+#[test]
+fn try_from_the_inner_type() {
+    assert_eq!(
+        Username::try_from(String::from("jane")).map(Username::into_inner),
+        Ok(String::from("jane"))
+    );
+    assert!(Username::try_from(String::new()).is_err());
+}
+
+#[test]
+fn parse_from_str() {
+    assert_eq!("AB-1".parse::<Sku>(), Sku::new("AB-1"));
+    assert!("".parse::<Username>().is_err());
+}
+
+#[test]
+fn display_the_inner_value() {
+    assert_eq!(Username::new("jane").unwrap().to_string(), "jane");
+}
+
+#[test]
+fn as_ref_of_what_the_inner_type_can_be_referenced_as() {
+    let username = Username::new("jane").unwrap();
+
+    let as_str: &str = username.as_ref();
+    let as_bytes: &[u8] = username.as_ref();
+
+    assert_eq!(as_str, "jane");
+    assert_eq!(as_bytes, b"jane");
+}
+
+#[test]
+fn validated_value_can_be_accessed_and_converted() {
+    let username = Username::new("jane").unwrap();
+
+    let validated = username.validated().clone();
+
+    assert_eq!(Username::from(validated), username);
+}
+
+#[cfg(feature = "serde1")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn serialize_as_the_inner_value() {
+        let username = Username::new("jane").unwrap();
+
+        assert_eq!(serde_json::to_string(&username).unwrap(), r#""jane""#);
+    }
+
+    #[test]
+    fn deserialize_a_valid_value() {
+        let username: Username = serde_json::from_str(r#""jane""#).unwrap();
+
+        assert_eq!(username, Username::new("jane").unwrap());
+    }
+
+    #[test]
+    fn deserialize_an_invalid_value_fails() {
+        let result = serde_json::from_str::<Username>(r#""""#);
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("invalid-not-empty"), "{}", message);
+    }
+}
@@ -0,0 +1,274 @@
+//! Normalization of values before validation
+//!
+//! User input often differs only in details that are not relevant for the
+//! application, like leading whitespace or upper case letters in an email
+//! address. A [`Normalize`] transform brings a value into a canonical form,
+//! so that constraints are checked consistently.
+//!
+//! The transforms in this module are implemented for `String`:
+//!
+//! | transform              | normalization                                        |
+//! |------------------------|------------------------------------------------------|
+//! | [`Trim`]               | removes leading and trailing whitespace              |
+//! | [`CollapseWhitespace`] | replaces each sequence of whitespace by a single space |
+//! | [`Lowercase`]          | converts all characters to lowercase                 |
+//! | [`StripControl`]       | removes all control characters                       |
+//! | [`Nfc`]                | Unicode Normalization Form C                         |
+//! | [`Nfkc`]               | Unicode Normalization Form KC                        |
+//!
+//! Transforms are combined into a pipeline by putting them into a tuple. The
+//! transforms of a tuple are applied from left to right.
+//!
+//! A transform is composed with a constraint using the [`Normalized`]
+//! constraint. It normalizes the value and validates the normalized value
+//! against the inner constraint. A successful validation returns the
+//! normalized value. A violation reports the original input as its actual
+//! value, so that the error message shows the value as it was entered by the
+//! user. This also applies to constraints that report a measure of the value,
+//! like the length or the number of characters, as actual value, since the
+//! measure of the normalized value may not match the input.
+//!
+//! The transforms [`Nfc`] and [`Nfkc`] are only available with the optional
+//! crate feature `unicode-normalization`.
+//!
+//! # Examples
+//!
+//! ```
+//! use valid::constraint::{CharCount, INVALID_CHAR_COUNT_MAX};
+//! use valid::normalize::{CollapseWhitespace, Lowercase, Normalized, Trim};
+//! use valid::{invalid_value, Validate};
+//!
+//! let constraint = Normalized((Trim, CollapseWhitespace, Lowercase), CharCount::Max(10));
+//!
+//! let result = String::from("  Jane   DOE ")
+//!     .validate("username", &constraint)
+//!     .result_with_warnings();
+//!
+//! assert_eq!(result.unwrap().0.unwrap(), "jane doe");
+//!
+//! let result = String::from(" Jane  Doe-Smith ")
+//!     .validate("username", &constraint)
+//!     .result_with_warnings();
+//!
+//! assert_eq!(
+//!     result.unwrap_err().violations,
+//!     vec![invalid_value(
+//!         INVALID_CHAR_COUNT_MAX,
+//!         "username",
+//!         " Jane  Doe-Smith ".to_string(),
+//!         10
+//!     )]
+//! );
+//! ```
+//!
+//! [`Normalize`]: trait.Normalize.html
+//! [`Normalized`]: struct.Normalized.html
+//! [`Trim`]: struct.Trim.html
+//! [`CollapseWhitespace`]: struct.CollapseWhitespace.html
+//! [`Lowercase`]: struct.Lowercase.html
+//! [`StripControl`]: struct.StripControl.html
+//! [`Nfc`]: struct.Nfc.html
+//! [`Nfkc`]: struct.Nfkc.html
+
+use crate::{ConstraintViolation, FieldName, Validate, ValidateRef, Validation, Value};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+/// A transformation that brings a value of type `T` into a canonical form.
+pub trait Normalize<T> {
+    /// Returns the normalized form of the given value.
+    fn normalize(&self, value: T) -> T;
+}
+
+/// Removes leading and trailing whitespace.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Trim;
+
+/// Replaces each sequence of whitespace characters by a single space.
+///
+/// Leading and trailing whitespace is collapsed as well but not removed. To
+/// remove it combine this transform with [`Trim`].
+///
+/// [`Trim`]: struct.Trim.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CollapseWhitespace;
+
+/// Converts all characters to lowercase.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Lowercase;
+
+/// Removes all control characters, including tabs and line breaks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StripControl;
+
+/// Converts a string into the Unicode Normalization Form C (canonical
+/// composition).
+///
+/// This transform is only available with the optional crate feature
+/// `unicode-normalization`.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Nfc;
+
+/// Converts a string into the Unicode Normalization Form KC (compatibility
+/// composition).
+///
+/// This transform is only available with the optional crate feature
+/// `unicode-normalization`.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Nfkc;
+
+macro_rules! impl_string_normalize {
+    ( $( $transform:ty => $normalize:expr ),+ $(,)? ) => {
+        $(
+            impl Normalize<String> for $transform {
+                fn normalize(&self, value: String) -> String {
+                    let normalize: fn(String) -> String = $normalize;
+                    normalize(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_string_normalize! {
+    Trim => |value| {
+        let trimmed = value.trim();
+        if trimmed.len() == value.len() {
+            value
+        } else {
+            trimmed.into()
+        }
+    },
+    CollapseWhitespace => |value| {
+        let mut collapsed = String::with_capacity(value.len());
+        let mut previous_whitespace = false;
+        for character in value.chars() {
+            let whitespace = character.is_whitespace();
+            if !whitespace {
+                collapsed.push(character);
+            } else if !previous_whitespace {
+                collapsed.push(' ');
+            }
+            previous_whitespace = whitespace;
+        }
+        collapsed
+    },
+    Lowercase => |value| value.to_lowercase(),
+    StripControl => |value| {
+        if value.chars().any(char::is_control) {
+            value.chars().filter(|character| !character.is_control()).collect()
+        } else {
+            value
+        }
+    },
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl_string_normalize! {
+    Nfc => |value| value.nfc().collect(),
+    Nfkc => |value| value.nfkc().collect(),
+}
+
+macro_rules! impl_tuple_normalize {
+    ( $( ( $( $transform:ident ),+ ) ),+ ) => {
+        $(
+            impl<T, $( $transform ),+> Normalize<T> for ( $( $transform, )+ )
+            where
+                $( $transform: Normalize<T>, )+
+            {
+                #[allow(non_snake_case)]
+                fn normalize(&self, value: T) -> T {
+                    let ( $( $transform, )+ ) = self;
+                    $( let value = $transform.normalize(value); )+
+                    value
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple_normalize! {
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F)
+}
+
+/// The value is normalized by the transform `N` and the normalized value must
+/// comply to the constraint `C`.
+///
+/// The transform can be a single transform or a tuple of transforms (see the
+/// [module level documentation]). The validation function can be applied in
+/// the [`FieldName`] context. It is implemented for all types `T` that can be
+/// normalized by `N` and that implement the `ValidateRef` trait for the
+/// constraint `C`.
+///
+/// The value of a successful validation is the normalized value. Violations
+/// of the inner constraint report the original value as their actual value.
+///
+/// [module level documentation]: index.html
+/// [`FieldName`]: ../core/struct.FieldName.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Normalized<N, C>(pub N, pub C);
+
+impl<N, C, T> ValidateRef<Normalized<N, C>, FieldName> for T
+where
+    N: Normalize<T>,
+    T: ValidateRef<C, FieldName> + Clone + Into<Value>,
+{
+    fn validate_ref(
+        &self,
+        name: impl Into<FieldName>,
+        constraint: &Normalized<N, C>,
+    ) -> Vec<ConstraintViolation> {
+        let normalized = constraint.0.normalize(self.clone());
+        let violations = normalized.validate_ref(name, &constraint.1);
+        with_original_actual(violations, self)
+    }
+}
+
+impl<N, C, T> Validate<Normalized<N, C>, FieldName> for T
+where
+    N: Normalize<T>,
+    T: ValidateRef<C, FieldName> + Clone + Into<Value>,
+{
+    fn validate(
+        self,
+        name: impl Into<FieldName>,
+        constraint: &Normalized<N, C>,
+    ) -> Validation<Normalized<N, C>, Self> {
+        let normalized = constraint.0.normalize(self.clone());
+        let violations = normalized.validate_ref(name, &constraint.1);
+        let violations = with_original_actual(violations, &self);
+        Validation::checked(normalized, violations)
+    }
+}
+
+/// Reports the original value as the actual value of the field violations.
+fn with_original_actual<T>(
+    mut violations: Vec<ConstraintViolation>,
+    original: &T,
+) -> Vec<ConstraintViolation>
+where
+    T: Clone + Into<Value>,
+{
+    if violations.is_empty() {
+        return violations;
+    }
+    let original = original.clone().into();
+    for violation in &mut violations {
+        if let ConstraintViolation::Field(invalid_value) = violation {
+            invalid_value.field.actual = Some(original.clone());
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests;
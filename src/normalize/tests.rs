@@ -0,0 +1,147 @@
+use super::*;
+use crate::constraint::{
+    Contains, Length, NotEmpty, INVALID_CONTAINS_ELEMENT, INVALID_LENGTH_MAX, INVALID_NOT_EMPTY,
+};
+use crate::{invalid_optional_value, invalid_value};
+
+fn normalize(transform: impl Normalize<String>, value: &str) -> String {
+    transform.normalize(value.to_string())
+}
+
+mod transforms {
+    use super::*;
+
+    #[test]
+    fn trim_removes_leading_and_trailing_whitespace() {
+        assert_eq!(normalize(Trim, " \t Jane Doe \n"), "Jane Doe");
+        assert_eq!(normalize(Trim, "Jane Doe"), "Jane Doe");
+    }
+
+    #[test]
+    fn collapse_whitespace_replaces_sequences_of_whitespace_by_a_single_space() {
+        assert_eq!(
+            normalize(CollapseWhitespace, "  Jane \t\n Doe "),
+            " Jane Doe "
+        );
+        assert_eq!(normalize(CollapseWhitespace, "Jane\u{3000}Doe"), "Jane Doe");
+    }
+
+    #[test]
+    fn lowercase_converts_all_characters_to_lowercase() {
+        assert_eq!(normalize(Lowercase, "Jane@Example.COM"), "jane@example.com");
+        assert_eq!(normalize(Lowercase, "STRAẞE"), "straße");
+    }
+
+    #[test]
+    fn strip_control_removes_control_characters() {
+        assert_eq!(
+            normalize(StripControl, "Jane\u{0}\u{7f} Doe\r\n"),
+            "Jane Doe"
+        );
+        assert_eq!(normalize(StripControl, "Jane Doe"), "Jane Doe");
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn nfc_composes_characters() {
+        assert_eq!(normalize(Nfc, "Jose\u{301}"), "Jos\u{e9}");
+        assert_eq!(normalize(Nfc, "\u{fb01}"), "\u{fb01}");
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn nfkc_composes_characters_and_replaces_compatibility_characters() {
+        assert_eq!(normalize(Nfkc, "Jose\u{301}"), "Jos\u{e9}");
+        assert_eq!(normalize(Nfkc, "\u{fb01}le"), "file");
+    }
+
+    #[test]
+    fn tuple_of_transforms_applies_the_transforms_from_left_to_right() {
+        assert_eq!(
+            normalize(
+                (StripControl, Trim, CollapseWhitespace),
+                " Jane\u{0} \u{0}Doe "
+            ),
+            "Jane Doe"
+        );
+        assert_eq!(
+            normalize((CollapseWhitespace, Trim), " Jane\u{0} \u{0}Doe "),
+            "Jane\u{0} \u{0}Doe"
+        );
+    }
+}
+
+mod normalized {
+    use super::*;
+
+    #[test]
+    fn validated_value_holds_the_normalized_value() {
+        let result = String::from("  Jane   DOE ")
+            .validate(
+                "username",
+                &Normalized((Trim, CollapseWhitespace, Lowercase), Length::Max(8)),
+            )
+            .result_with_warnings();
+
+        assert_eq!(result.unwrap().0.unwrap(), "jane doe");
+    }
+
+    #[test]
+    fn constraint_is_checked_against_the_normalized_value() {
+        let result = String::from(" \t ")
+            .validate("username", &Normalized(Trim, NotEmpty))
+            .result_with_warnings();
+
+        assert_eq!(
+            result.unwrap_err().violations,
+            vec![invalid_optional_value(
+                INVALID_NOT_EMPTY,
+                "username",
+                Some(Value::from(" \t ".to_string())),
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn violations_report_the_original_value_as_actual_value() {
+        let at = String::from("@");
+
+        let violations = String::from(" Jane.Example.COM ")
+            .validate_ref("email", &Normalized((Trim, Lowercase), Contains(&at)));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(
+                INVALID_CONTAINS_ELEMENT,
+                "email",
+                " Jane.Example.COM ".to_string(),
+                "@".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn violations_of_the_length_report_the_original_value_as_actual_value() {
+        let violations = String::from("  abcdefghijkl  ")
+            .validate_ref("username", &Normalized(Trim, Length::Max(5)));
+
+        assert_eq!(
+            violations,
+            vec![invalid_value(
+                INVALID_LENGTH_MAX,
+                "username",
+                "  abcdefghijkl  ".to_string(),
+                5
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_ref_of_a_valid_value_returns_no_violations() {
+        let violations = String::from(" Jane ")
+            .validate_ref("username", &Normalized((Trim, Lowercase), Length::Max(4)));
+
+        assert!(violations.is_empty());
+    }
+}
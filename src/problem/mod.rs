@@ -0,0 +1,295 @@
+//! Rendering of validation errors as problem details
+//!
+//! This module provides [`ProblemDetails`], a representation of a
+//! [`ValidationError`] as "problem details for HTTP APIs" as specified in
+//! [RFC 7807]. Serialized as JSON it can be sent as response body with the
+//! media type `application/problem+json` (see [`CONTENT_TYPE`]).
+//!
+//! The constraint violations are flattened into the extension member `errors`.
+//! Each entry holds the error code, the severity, a [JSON Pointer] to the
+//! invalid field and the actual and expected values if available. A violation of a constraint
+//! on related fields results in one entry per field. A violation of a
+//! constraint validated in the [`State`] context points to the whole document
+//! and holds its parameters in `params`. The extension member `truncated`
+//! tells whether further violations were omitted, for example because the
+//! validation stopped at the first error.
+//!
+//! `ProblemDetails` does not depend on any HTTP framework. It implements
+//! `serde::Serialize` and can be serialized into any format supported by
+//! [`serde`].
+//!
+//! This module is only available with the optional crate feature `serde1`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_json::json;
+//! use valid::{invalid_state, invalid_value, param, ValidationError};
+//! use valid::problem::ProblemDetails;
+//!
+//! let error = ValidationError::new(
+//!     Some("invalid registration".into()),
+//!     vec![
+//!         invalid_value("invalid-length-max", "address.zip_code", 6, 5),
+//!         invalid_state(
+//!             "invalid-unique-username",
+//!             vec![param("username", "jane.doe".to_string())],
+//!         ),
+//!     ],
+//! );
+//!
+//! let problem = ProblemDetails::from(&error).with_instance("/users");
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&problem).unwrap(),
+//!     json!({
+//!         "type": "about:blank",
+//!         "title": "Unprocessable Entity",
+//!         "status": 422,
+//!         "detail": "invalid registration",
+//!         "instance": "/users",
+//!         "errors": [
+//!             {
+//!                 "code": "invalid-length-max",
+//!                 "severity": "error",
+//!                 "pointer": "/address/zip_code",
+//!                 "actual": 6,
+//!                 "expected": 5
+//!             },
+//!             {
+//!                 "code": "invalid-unique-username",
+//!                 "severity": "error",
+//!                 "pointer": "",
+//!                 "params": { "username": "jane.doe" }
+//!             }
+//!         ],
+//!         "truncated": false
+//!     })
+//! );
+//! ```
+//!
+//! [RFC 7807]: https://tools.ietf.org/html/rfc7807
+//! [JSON Pointer]: https://tools.ietf.org/html/rfc6901
+//! [`ProblemDetails`]: struct.ProblemDetails.html
+//! [`CONTENT_TYPE`]: constant.CONTENT_TYPE.html
+//! [`ValidationError`]: ../struct.ValidationError.html
+//! [`State`]: ../struct.State.html
+//! [`serde`]: https://crates.io/crates/serde
+
+use crate::wire::{serialize_params, PlainValue};
+use crate::{ConstraintViolation, Field, Parameter, Severity, ValidationError, Value};
+use serde::{Serialize, Serializer};
+use std::borrow::Cow;
+
+/// The media type of a problem details document in JSON format
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// The default HTTP status code of a validation problem (422 Unprocessable
+/// Entity)
+pub const DEFAULT_STATUS: u16 = 422;
+
+/// The problem type used if no specific type is set
+pub const DEFAULT_TYPE: &str = "about:blank";
+
+/// The title used if no specific title is set, which is the reason phrase of
+/// the default status code
+pub const DEFAULT_TITLE: &str = "Unprocessable Entity";
+
+/// A validation error rendered as problem details according to RFC 7807.
+///
+/// It is constructed from a [`ValidationError`] using the `From` trait. The
+/// members `type`, `title`, `status` and `instance` can be set using the
+/// builder methods. The `detail` and `truncated` members are taken from the
+/// validation error.
+///
+/// See the [module level documentation] for an example.
+///
+/// [`ValidationError`]: ../struct.ValidationError.html
+/// [module level documentation]: index.html
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProblemDetails {
+    /// A URI reference that identifies the problem type
+    #[serde(rename = "type")]
+    pub problem_type: Cow<'static, str>,
+
+    /// A short, human-readable summary of the problem type
+    pub title: Cow<'static, str>,
+
+    /// The HTTP status code
+    pub status: u16,
+
+    /// A human-readable explanation specific to this occurrence of the
+    /// problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<Cow<'static, str>>,
+
+    /// A URI reference that identifies the specific occurrence of the problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<Cow<'static, str>>,
+
+    /// The flattened list of constraint violations
+    pub errors: Vec<ProblemError>,
+
+    /// Whether further constraint violations were omitted
+    pub truncated: bool,
+}
+
+/// An entry in the `errors` member of a [`ProblemDetails`] document.
+///
+/// [`ProblemDetails`]: struct.ProblemDetails.html
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProblemError {
+    /// The error code of the constraint violation
+    pub code: Cow<'static, str>,
+
+    /// The severity of the constraint violation
+    pub severity: Severity,
+
+    /// A JSON Pointer to the invalid field, which is the empty string for the
+    /// whole document
+    pub pointer: String,
+
+    /// The actual value of the field
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_value"
+    )]
+    pub actual: Option<Value>,
+
+    /// The expected value
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_value"
+    )]
+    pub expected: Option<Value>,
+
+    /// The parameters of the constraint violation
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_params"
+    )]
+    pub params: Vec<Parameter>,
+}
+
+impl ProblemDetails {
+    /// Sets the URI reference that identifies the problem type.
+    pub fn with_type(mut self, problem_type: impl Into<Cow<'static, str>>) -> Self {
+        self.problem_type = problem_type.into();
+        self
+    }
+
+    /// Sets the title of the problem type.
+    pub fn with_title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the HTTP status code.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the URI reference that identifies the specific occurrence of the
+    /// problem.
+    pub fn with_instance(mut self, instance: impl Into<Cow<'static, str>>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+}
+
+impl From<&ValidationError> for ProblemDetails {
+    fn from(error: &ValidationError) -> Self {
+        ProblemDetails {
+            problem_type: DEFAULT_TYPE.into(),
+            title: DEFAULT_TITLE.into(),
+            status: DEFAULT_STATUS,
+            detail: error.message.clone(),
+            instance: None,
+            errors: error.violations.iter().flat_map(flatten).collect(),
+            truncated: error.truncated,
+        }
+    }
+}
+
+impl From<ValidationError> for ProblemDetails {
+    fn from(error: ValidationError) -> Self {
+        ProblemDetails::from(&error)
+    }
+}
+
+fn flatten(violation: &ConstraintViolation) -> Vec<ProblemError> {
+    let severity = violation.severity();
+    match violation {
+        ConstraintViolation::Field(invalid_value) => {
+            vec![field_error(
+                invalid_value.code.clone(),
+                severity,
+                &invalid_value.field,
+            )]
+        }
+        ConstraintViolation::Relation(invalid_relation) => vec![
+            field_error(
+                invalid_relation.code.clone(),
+                severity,
+                &invalid_relation.field1,
+            ),
+            field_error(
+                invalid_relation.code.clone(),
+                severity,
+                &invalid_relation.field2,
+            ),
+        ],
+        ConstraintViolation::FieldSet(invalid_field_set) => invalid_field_set
+            .fields
+            .iter()
+            .map(|field| field_error(invalid_field_set.code.clone(), severity, field))
+            .collect(),
+        ConstraintViolation::State(invalid_state) => vec![ProblemError {
+            code: invalid_state.code.clone(),
+            severity,
+            pointer: String::new(),
+            actual: None,
+            expected: None,
+            params: invalid_state.params.clone(),
+        }],
+    }
+}
+
+fn field_error(code: Cow<'static, str>, severity: Severity, field: &Field) -> ProblemError {
+    ProblemError {
+        code,
+        severity,
+        pointer: json_pointer(&field.name),
+        actual: field.actual.clone(),
+        expected: field.expected.clone(),
+        params: Vec::new(),
+    }
+}
+
+/// Converts a field name into a JSON Pointer.
+///
+/// Nested fields separated by dots, like `address.zip_code`, and indexed
+/// elements, like `items[1]`, are converted into separate reference tokens.
+fn json_pointer(field_name: &str) -> String {
+    let mut pointer = String::with_capacity(field_name.len() + 1);
+    for token in field_name
+        .split(['.', '['])
+        .map(|token| token.strip_suffix(']').unwrap_or(token))
+        .filter(|token| !token.is_empty())
+    {
+        pointer.push('/');
+        pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+    }
+    pointer
+}
+
+fn serialize_optional_value<S>(value: &Option<Value>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    value.as_ref().map(PlainValue).serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests;
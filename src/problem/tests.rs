@@ -0,0 +1,277 @@
+use super::*;
+use crate::{
+    invalid_field_set, invalid_optional_value, invalid_relation, invalid_state, invalid_value,
+    param, Severity,
+};
+use serde_json::json;
+
+mod problem_details {
+    use super::*;
+
+    #[test]
+    fn problem_details_of_a_validation_error_with_defaults() {
+        let error = ValidationError::new(None, vec![]);
+
+        let problem = ProblemDetails::from(error);
+
+        assert_eq!(
+            problem,
+            ProblemDetails {
+                problem_type: "about:blank".into(),
+                title: "Unprocessable Entity".into(),
+                status: 422,
+                detail: None,
+                instance: None,
+                errors: vec![],
+                truncated: false,
+            }
+        );
+    }
+
+    #[test]
+    fn detail_is_taken_from_the_message_of_the_validation_error() {
+        let error = ValidationError::new(Some("invalid registration".into()), vec![]);
+
+        let problem = ProblemDetails::from(&error);
+
+        assert_eq!(problem.detail, Some("invalid registration".into()));
+    }
+
+    #[test]
+    fn builder_methods_set_the_members() {
+        let problem = ProblemDetails::from(ValidationError::new(None, vec![]))
+            .with_type("https://example.com/problems/validation")
+            .with_title("Your request parameters didn't validate")
+            .with_status(400)
+            .with_instance("/users/42");
+
+        assert_eq!(
+            serde_json::to_value(&problem).unwrap(),
+            json!({
+                "type": "https://example.com/problems/validation",
+                "title": "Your request parameters didn't validate",
+                "status": 400,
+                "instance": "/users/42",
+                "errors": [],
+                "truncated": false
+            })
+        );
+    }
+
+    #[test]
+    fn truncated_is_taken_from_the_validation_error() {
+        let error = ValidationError::new(
+            None,
+            vec![invalid_value("invalid-length-min", "name", 1, 2)],
+        )
+        .with_truncated(true);
+
+        let problem = ProblemDetails::from(&error);
+
+        assert!(problem.truncated);
+    }
+}
+
+mod errors {
+    use super::*;
+
+    fn errors_of(violations: Vec<ConstraintViolation>) -> serde_json::Value {
+        let problem = ProblemDetails::from(ValidationError::new(None, violations));
+        serde_json::to_value(&problem).unwrap()["errors"].clone()
+    }
+
+    #[test]
+    fn invalid_value_is_rendered_as_one_entry() {
+        let errors = errors_of(vec![invalid_value(
+            "invalid-bound-closed-max",
+            "age",
+            121,
+            120,
+        )]);
+
+        assert_eq!(
+            errors,
+            json!([{
+                "code": "invalid-bound-closed-max",
+                "severity": "error",
+                "pointer": "/age",
+                "actual": 121,
+                "expected": 120
+            }])
+        );
+    }
+
+    #[test]
+    fn missing_actual_and_expected_values_are_omitted() {
+        let errors = errors_of(vec![invalid_optional_value(
+            "invalid-not-empty",
+            "name",
+            None,
+            None,
+        )]);
+
+        assert_eq!(
+            errors,
+            json!([{
+                "code": "invalid-not-empty",
+                "severity": "error",
+                "pointer": "/name"
+            }])
+        );
+    }
+
+    #[test]
+    fn invalid_relation_is_rendered_as_one_entry_per_field() {
+        let errors = errors_of(vec![invalid_relation(
+            "invalid-must-match",
+            "password",
+            "s3cr3t".to_string(),
+            "password2",
+            "s3crEt".to_string(),
+        )]);
+
+        assert_eq!(
+            errors,
+            json!([
+                {
+                    "code": "invalid-must-match",
+                    "severity": "error",
+                    "pointer": "/password",
+                    "actual": "s3cr3t"
+                },
+                {
+                    "code": "invalid-must-match",
+                    "severity": "error",
+                    "pointer": "/password2",
+                    "actual": "s3crEt"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_field_set_is_rendered_as_one_entry_per_field() {
+        let errors = errors_of(vec![invalid_field_set(
+            "invalid-at-most-one-of",
+            vec![
+                ("phone".into(), Some(Value::from("0123".to_string()))),
+                (
+                    "email".into(),
+                    Some(Value::from("jane@example.com".to_string())),
+                ),
+            ],
+        )]);
+
+        assert_eq!(
+            errors,
+            json!([
+                {
+                    "code": "invalid-at-most-one-of",
+                    "severity": "error",
+                    "pointer": "/phone",
+                    "actual": "0123"
+                },
+                {
+                    "code": "invalid-at-most-one-of",
+                    "severity": "error",
+                    "pointer": "/email",
+                    "actual": "jane@example.com"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_state_is_rendered_with_params_pointing_to_the_whole_document() {
+        let errors = errors_of(vec![invalid_state(
+            "invalid-unique-username",
+            vec![
+                param("username", "jane.doe".to_string()),
+                param("attempts", 3),
+                param("blocked", false),
+            ],
+        )]);
+
+        assert_eq!(
+            errors,
+            json!([{
+                "code": "invalid-unique-username",
+                "severity": "error",
+                "pointer": "",
+                "params": { "username": "jane.doe", "attempts": 3, "blocked": false }
+            }])
+        );
+    }
+
+    #[test]
+    fn entries_have_the_severity_of_the_violation() {
+        let errors = errors_of(vec![
+            invalid_value("invalid-length-min", "name", 1, 2).with_severity(Severity::Warning),
+            invalid_relation(
+                "invalid-must-match",
+                "password",
+                "s3cr3t".to_string(),
+                "password2",
+                "s3crEt".to_string(),
+            )
+            .with_severity(Severity::Warning),
+        ]);
+
+        let severities = errors
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| error["severity"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(severities, vec!["warning", "warning", "warning"]);
+    }
+
+    #[test]
+    fn entries_keep_the_order_of_the_violations() {
+        let errors = errors_of(vec![
+            invalid_value("invalid-length-min", "name", 1, 2),
+            invalid_state("invalid-maintenance", vec![]),
+            invalid_value("invalid-bound-open-min", "price", 0., 0.),
+        ]);
+
+        let codes = errors
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| error["code"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                "invalid-length-min",
+                "invalid-maintenance",
+                "invalid-bound-open-min"
+            ]
+        );
+    }
+}
+
+mod pointer {
+    use super::*;
+
+    #[test]
+    fn pointer_of_a_simple_field_name() {
+        assert_eq!(json_pointer("name"), "/name");
+    }
+
+    #[test]
+    fn pointer_of_a_nested_field_name() {
+        assert_eq!(json_pointer("address.zip_code"), "/address/zip_code");
+    }
+
+    #[test]
+    fn pointer_of_an_indexed_field_name() {
+        assert_eq!(json_pointer("items[1].name"), "/items/1/name");
+        assert_eq!(json_pointer("items.1.name"), "/items/1/name");
+    }
+
+    #[test]
+    fn pointer_escapes_tilde_and_slash() {
+        assert_eq!(json_pointer("a/b~c"), "/a~1b~0c");
+    }
+}
@@ -13,6 +13,9 @@
 //! [`HasDecimalDigits`]: trait.HasDecimalDigits.html
 //! [`Validate`]: ../trait.Validate.html
 
+use crate::Value;
+use alloc::vec::Vec;
+
 /// The checked property of a type.
 ///
 /// This can be property of enums with 2 variants that have a similar meaning to
@@ -70,6 +73,15 @@ pub trait HasDecimalDigits {
     ///
     /// These are the digits to the right of the decimal point
     fn fraction_digits(&self) -> u64;
+
+    /// Returns whether this value represents a well-formed decimal number
+    ///
+    /// The default implementation returns always true. Types that can hold
+    /// values that are not a decimal number, such as strings or non-finite
+    /// floating point numbers, should override this method.
+    fn is_decimal_number(&self) -> bool {
+        true
+    }
 }
 
 /// Determines whether the given element is part of a value or member of
@@ -82,3 +94,13 @@ pub trait HasMember<A> {
     /// it
     fn has_member(&self, element: &A) -> bool;
 }
+
+/// The values of a set of optional fields.
+///
+/// This is usually a property of tuples of `Option<T>` values or of a
+/// `Vec<Option<T>>`.
+pub trait HasOptionalValues {
+    /// Returns the values of the fields in the order of the fields. The
+    /// returned list contains `None` for each field that has no value.
+    fn optional_values(&self) -> Vec<Option<Value>>;
+}
@@ -0,0 +1,463 @@
+//! JSON Schema generation from constraints
+//!
+//! This module implements the `JsonSchema` trait of the [`schemars`] crate for
+//! [`Validated<C, T>`]. The schema of the inner type `T` is extended by the
+//! keywords that express the constraint `C`. This way the generated schema of
+//! an API matches the rules that are enforced by the validation.
+//!
+//! The constraints of this crate are translated into JSON Schema keywords as
+//! follows:
+//!
+//! | constraint                                         | keywords                                                                          |
+//! |----------------------------------------------------|-----------------------------------------------------------------------------------|
+//! | `AssertTrue`                                       | `const: true`                                                                     |
+//! | `AssertFalse`                                      | `const: false`                                                                    |
+//! | `NotEmpty`                                         | `minLength: 1`, `minItems: 1` or `minProperties: 1`                               |
+//! | `Length`, `MinLen`, `MaxLen`, `MinMaxLen`          | `minLength`/`maxLength`, `minItems`/`maxItems` or `minProperties`/`maxProperties` |
+//! | `CharCount`, `MinChars`, `MaxChars`, `MinMaxChars` | `minLength`/`maxLength`                                                           |
+//! | `Bound`, `Min`, `Max`, `ClosedRange`               | `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `const`             |
+//! | `Digits`, `MaxDigits`                              | `multipleOf`                                                                      |
+//! | `Pattern`                                          | `pattern`                                                                         |
+//!
+//! The keywords are chosen by the type of the schema that is updated. The
+//! keywords for lengths are generated for strings, arrays and objects, the
+//! keywords for numbers only for numbers and `pattern` only for strings. A
+//! constraint generates no keywords for a schema of another type or for a
+//! schema without a `type`, like a reference to another schema. A schema
+//! that allows several types, like `["string", "null"]` for an `Option`,
+//! gets the keywords of each of its types.
+//!
+//! JSON Schema counts the length of strings in characters. This is the same
+//! as the `CharCount` constraint does, while the `Length` constraint counts
+//! the bytes of a string. Thus the keywords generated for `Length` on strings
+//! are exact for ASCII strings only.
+//!
+//! The schema of a `Validated<C, T>` can only be generated if the constraint
+//! is known at compile time. This is expressed by the [`StaticConstraint`]
+//! trait. It is implemented for the constraints without parameters, for the
+//! constraints with const generic parameters, like `MaxLen<20>` as the
+//! equivalent of `Length::Max(20)`, and for tuples of such constraints.
+//! Constraints with parameters given at runtime, like `Length::Max(20)`, can
+//! be applied to a schema by calling [`ConstraintSchema::update_schema`]
+//! directly.
+//!
+//! A `Pattern` can not be given as const generic parameter. A custom
+//! constraint that validates a fixed pattern implements [`StaticConstraint`]
+//! and [`ConstraintSchema`] itself:
+//!
+//! ```
+//! # #[cfg(feature = "regex")]
+//! # {
+//! use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+//! use valid::constraint::Pattern;
+//! use valid::schema::{ConstraintSchema, StaticConstraint};
+//! use valid::Validated;
+//!
+//! const ZIP_CODE: &str = "^[0-9]{5}$";
+//!
+//! struct ZipCode;
+//!
+//! impl StaticConstraint for ZipCode {
+//!     const CONSTRAINT: Self = ZipCode;
+//! }
+//!
+//! impl ConstraintSchema for ZipCode {
+//!     fn update_schema(&self, schema: &mut Schema) {
+//!         Pattern(regex::Regex::new(ZIP_CODE).unwrap()).update_schema(schema);
+//!     }
+//! }
+//!
+//! let schema = <Validated<ZipCode, String>>::json_schema(&mut SchemaGenerator::default());
+//! assert_eq!(schema, json_schema!({ "type": "string", "pattern": "^[0-9]{5}$" }));
+//! # }
+//! ```
+//!
+//! This module is only available with the optional crate feature `schemars`.
+//!
+//! # Examples
+//!
+//! ```
+//! use schemars::{json_schema, JsonSchema, SchemaGenerator};
+//! use valid::constraint::NotEmpty;
+//! use valid::schema::ConstraintSchema;
+//! use valid::Validated;
+//! use valid::constraint::Length;
+//!
+//! let mut generator = SchemaGenerator::default();
+//!
+//! let mut schema = <Validated<NotEmpty, String>>::json_schema(&mut generator);
+//! assert_eq!(schema, json_schema!({ "type": "string", "minLength": 1 }));
+//!
+//! Length::Max(20).update_schema(&mut schema);
+//! assert_eq!(
+//!     schema,
+//!     json_schema!({ "type": "string", "minLength": 1, "maxLength": 20 })
+//! );
+//! ```
+//!
+//! [`schemars`]: https://crates.io/crates/schemars
+//! [`Validated<C, T>`]: ../struct.Validated.html
+//! [`StaticConstraint`]: trait.StaticConstraint.html
+//! [`ConstraintSchema`]: trait.ConstraintSchema.html
+//! [`ConstraintSchema::update_schema`]: trait.ConstraintSchema.html#tymethod.update_schema
+
+#[cfg(feature = "regex")]
+use crate::constraint::Pattern;
+use crate::constraint::{
+    AssertFalse, AssertTrue, Bound, CharCount, ClosedRange, Digits, Length, Max, MaxChars,
+    MaxDigits, MaxLen, Min, MinChars, MinLen, MinMaxChars, MinMaxLen, NotEmpty,
+};
+use crate::{Validated, Value};
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::Value as JsonValue;
+use std::any::type_name;
+use std::borrow::Cow;
+
+/// Translates a constraint into JSON Schema keywords.
+pub trait ConstraintSchema {
+    /// Adds the keywords that express this constraint to the given schema of
+    /// the constrained type.
+    fn update_schema(&self, schema: &mut Schema);
+}
+
+/// A constraint that is fully known at compile time.
+///
+/// This is the case for constraints without parameters, like `NotEmpty`.
+pub trait StaticConstraint: Sized {
+    /// The constraint
+    const CONSTRAINT: Self;
+}
+
+impl<C, T> JsonSchema for Validated<C, T>
+where
+    C: ConstraintSchema + StaticConstraint,
+    T: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("valid::Validated<{}, {}>", type_name::<C>(), T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = T::json_schema(generator);
+        C::CONSTRAINT.update_schema(&mut schema);
+        schema
+    }
+}
+
+fn has_type(schema: &Schema, name: &str) -> bool {
+    match schema.get("type") {
+        Some(JsonValue::String(value)) => value == name,
+        Some(JsonValue::Array(values)) => values.iter().any(|value| value == name),
+        _ => false,
+    }
+}
+
+fn is_number(schema: &Schema) -> bool {
+    has_type(schema, "number") || has_type(schema, "integer")
+}
+
+fn insert(schema: &mut Schema, keyword: &str, value: impl Into<JsonValue>) {
+    schema.insert(keyword.into(), value.into());
+}
+
+/// The keywords for the minimum and maximum length of each type of values
+/// that have a length
+const COUNT_KEYWORDS: [(&str, &str, &str); 3] = [
+    ("string", "minLength", "maxLength"),
+    ("array", "minItems", "maxItems"),
+    ("object", "minProperties", "maxProperties"),
+];
+
+/// Adds the keywords for the minimum and maximum length for each of the
+/// given types the schema allows.
+fn update_count_schema(
+    schema: &mut Schema,
+    types: &[&str],
+    min: Option<usize>,
+    max: Option<usize>,
+) {
+    for (type_name, min_keyword, max_keyword) in COUNT_KEYWORDS.iter() {
+        if !types.contains(type_name) || !has_type(schema, type_name) {
+            continue;
+        }
+        if let Some(min) = min {
+            insert(schema, min_keyword, min);
+        }
+        if let Some(max) = max {
+            insert(schema, max_keyword, max);
+        }
+    }
+}
+
+/// All types of values that have a length
+const COUNTED_TYPES: [&str; 3] = ["string", "array", "object"];
+
+fn to_number(value: Value) -> Option<JsonValue> {
+    match value {
+        Value::Integer(value) => Some(value.into()),
+        Value::Long(value) => Some(value.into()),
+        Value::Float(value) => Some(value.into()),
+        Value::Double(value) => Some(value.into()),
+        _ => None,
+    }
+}
+
+impl StaticConstraint for AssertTrue {
+    const CONSTRAINT: Self = AssertTrue;
+}
+
+impl ConstraintSchema for AssertTrue {
+    fn update_schema(&self, schema: &mut Schema) {
+        if has_type(schema, "boolean") {
+            insert(schema, "const", true);
+        }
+    }
+}
+
+impl StaticConstraint for AssertFalse {
+    const CONSTRAINT: Self = AssertFalse;
+}
+
+impl ConstraintSchema for AssertFalse {
+    fn update_schema(&self, schema: &mut Schema) {
+        if has_type(schema, "boolean") {
+            insert(schema, "const", false);
+        }
+    }
+}
+
+impl StaticConstraint for NotEmpty {
+    const CONSTRAINT: Self = NotEmpty;
+}
+
+impl ConstraintSchema for NotEmpty {
+    fn update_schema(&self, schema: &mut Schema) {
+        update_count_schema(schema, &COUNTED_TYPES, Some(1), None);
+    }
+}
+
+impl ConstraintSchema for Length {
+    fn update_schema(&self, schema: &mut Schema) {
+        let (min, max) = match *self {
+            Length::Max(max) => (None, Some(max)),
+            Length::Min(min) => (Some(min), None),
+            Length::MinMax(min, max) => (Some(min), Some(max)),
+            Length::Exact(exact) => (Some(exact), Some(exact)),
+        };
+        update_count_schema(schema, &COUNTED_TYPES, min, max);
+    }
+}
+
+impl ConstraintSchema for CharCount {
+    fn update_schema(&self, schema: &mut Schema) {
+        let (min, max) = match *self {
+            CharCount::Max(max) => (None, Some(max)),
+            CharCount::Min(min) => (Some(min), None),
+            CharCount::MinMax(min, max) => (Some(min), Some(max)),
+            CharCount::Exact(exact) => (Some(exact), Some(exact)),
+        };
+        update_count_schema(schema, &["string"], min, max);
+    }
+}
+
+impl<T> ConstraintSchema for Bound<T>
+where
+    T: Clone + Into<Value>,
+{
+    fn update_schema(&self, schema: &mut Schema) {
+        if !is_number(schema) {
+            return;
+        }
+        let (lower, upper) = match self {
+            Bound::ClosedRange(min, max) => (Some(("minimum", min)), Some(("maximum", max))),
+            Bound::ClosedOpenRange(min, max) => {
+                (Some(("minimum", min)), Some(("exclusiveMaximum", max)))
+            }
+            Bound::OpenClosedRange(min, max) => {
+                (Some(("exclusiveMinimum", min)), Some(("maximum", max)))
+            }
+            Bound::OpenRange(min, max) => (
+                Some(("exclusiveMinimum", min)),
+                Some(("exclusiveMaximum", max)),
+            ),
+            Bound::Exact(value) => (Some(("const", value)), None),
+            Bound::Min(min) => (Some(("minimum", min)), None),
+            Bound::Max(max) => (None, Some(("maximum", max))),
+            Bound::GreaterThan(min) => (Some(("exclusiveMinimum", min)), None),
+            Bound::LessThan(max) => (None, Some(("exclusiveMaximum", max))),
+        };
+        for (keyword, limit) in lower.into_iter().chain(upper) {
+            if let Some(number) = to_number(limit.clone().into()) {
+                insert(schema, keyword, number);
+            }
+        }
+    }
+}
+
+/// The greatest number of fraction digits for which `multipleOf` is generated.
+///
+/// Powers of ten with a higher negative exponent can not be represented
+/// precisely enough as floating point numbers.
+const MAX_REPRESENTABLE_FRACTION_DIGITS: u64 = 15;
+
+impl ConstraintSchema for Digits {
+    /// Generates `multipleOf` for numbers if the number of fraction digits is
+    /// representable. The number of integer digits has no equivalent keyword.
+    fn update_schema(&self, schema: &mut Schema) {
+        if !is_number(schema) || self.fraction > MAX_REPRESENTABLE_FRACTION_DIGITS {
+            return;
+        }
+        if self.fraction == 0 {
+            insert(schema, "multipleOf", 1);
+        } else {
+            let multiple_of = format!("1e-{}", self.fraction)
+                .parse::<f64>()
+                .expect("valid float literal");
+            insert(schema, "multipleOf", multiple_of);
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl ConstraintSchema for Pattern {
+    fn update_schema(&self, schema: &mut Schema) {
+        if has_type(schema, "string") {
+            insert(schema, "pattern", self.0.as_str());
+        }
+    }
+}
+
+impl<const N: usize> StaticConstraint for MaxLen<N> {
+    const CONSTRAINT: Self = MaxLen;
+}
+
+impl<const N: usize> ConstraintSchema for MaxLen<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Length::from(*self).update_schema(schema);
+    }
+}
+
+impl<const N: usize> StaticConstraint for MinLen<N> {
+    const CONSTRAINT: Self = MinLen;
+}
+
+impl<const N: usize> ConstraintSchema for MinLen<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Length::from(*self).update_schema(schema);
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> StaticConstraint for MinMaxLen<MIN, MAX> {
+    const CONSTRAINT: Self = MinMaxLen;
+}
+
+impl<const MIN: usize, const MAX: usize> ConstraintSchema for MinMaxLen<MIN, MAX> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Length::from(*self).update_schema(schema);
+    }
+}
+
+impl<const N: usize> StaticConstraint for MaxChars<N> {
+    const CONSTRAINT: Self = MaxChars;
+}
+
+impl<const N: usize> ConstraintSchema for MaxChars<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        CharCount::from(*self).update_schema(schema);
+    }
+}
+
+impl<const N: usize> StaticConstraint for MinChars<N> {
+    const CONSTRAINT: Self = MinChars;
+}
+
+impl<const N: usize> ConstraintSchema for MinChars<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        CharCount::from(*self).update_schema(schema);
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> StaticConstraint for MinMaxChars<MIN, MAX> {
+    const CONSTRAINT: Self = MinMaxChars;
+}
+
+impl<const MIN: usize, const MAX: usize> ConstraintSchema for MinMaxChars<MIN, MAX> {
+    fn update_schema(&self, schema: &mut Schema) {
+        CharCount::from(*self).update_schema(schema);
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> StaticConstraint for ClosedRange<MIN, MAX> {
+    const CONSTRAINT: Self = ClosedRange;
+}
+
+impl<const MIN: i64, const MAX: i64> ConstraintSchema for ClosedRange<MIN, MAX> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Bound::from(*self).update_schema(schema);
+    }
+}
+
+impl<const N: i64> StaticConstraint for Min<N> {
+    const CONSTRAINT: Self = Min;
+}
+
+impl<const N: i64> ConstraintSchema for Min<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Bound::from(*self).update_schema(schema);
+    }
+}
+
+impl<const N: i64> StaticConstraint for Max<N> {
+    const CONSTRAINT: Self = Max;
+}
+
+impl<const N: i64> ConstraintSchema for Max<N> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Bound::from(*self).update_schema(schema);
+    }
+}
+
+impl<const INTEGER: u64, const FRACTION: u64> StaticConstraint for MaxDigits<INTEGER, FRACTION> {
+    const CONSTRAINT: Self = MaxDigits;
+}
+
+impl<const INTEGER: u64, const FRACTION: u64> ConstraintSchema for MaxDigits<INTEGER, FRACTION> {
+    fn update_schema(&self, schema: &mut Schema) {
+        Digits::from(*self).update_schema(schema);
+    }
+}
+
+macro_rules! impl_constraint_schema_for_tuple {
+    ( $( $name:ident : $index:tt ),+ ) => {
+        impl<$( $name ),+> StaticConstraint for ( $( $name, )+ )
+        where
+            $( $name: StaticConstraint, )+
+        {
+            const CONSTRAINT: Self = ( $( $name::CONSTRAINT, )+ );
+        }
+
+        impl<$( $name ),+> ConstraintSchema for ( $( $name, )+ )
+        where
+            $( $name: ConstraintSchema, )+
+        {
+            fn update_schema(&self, schema: &mut Schema) {
+                $( self.$index.update_schema(schema); )+
+            }
+        }
+    };
+}
+
+impl_constraint_schema_for_tuple!(A: 0, B: 1);
+impl_constraint_schema_for_tuple!(A: 0, B: 1, C: 2);
+impl_constraint_schema_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+#[cfg(test)]
+mod tests;
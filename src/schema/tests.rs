@@ -0,0 +1,348 @@
+use super::*;
+use schemars::json_schema;
+
+fn schema_of<T: JsonSchema>() -> Schema {
+    T::json_schema(&mut SchemaGenerator::default())
+}
+
+fn updated(mut schema: Schema, constraint: impl ConstraintSchema) -> Schema {
+    constraint.update_schema(&mut schema);
+    schema
+}
+
+mod validated {
+    use super::*;
+
+    #[test]
+    fn schema_of_validated_string_not_empty() {
+        assert_eq!(
+            schema_of::<Validated<NotEmpty, String>>(),
+            json_schema!({ "type": "string", "minLength": 1 })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_vec_not_empty() {
+        assert_eq!(
+            schema_of::<Validated<NotEmpty, Vec<i32>>>(),
+            json_schema!({
+                "type": "array",
+                "items": { "type": "integer", "format": "int32" },
+                "minItems": 1
+            })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_bool_assert_true() {
+        assert_eq!(
+            schema_of::<Validated<AssertTrue, bool>>(),
+            json_schema!({ "type": "boolean", "const": true })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_with_a_tuple_of_constraints() {
+        assert_eq!(
+            schema_of::<Validated<(AssertFalse, NotEmpty), bool>>(),
+            json_schema!({ "type": "boolean", "const": false })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_string_with_const_generic_constraints() {
+        assert_eq!(
+            schema_of::<Validated<(NotEmpty, MaxChars<20>), String>>(),
+            json_schema!({ "type": "string", "minLength": 1, "maxLength": 20 })
+        );
+        assert_eq!(
+            schema_of::<Validated<MinMaxLen<3, 8>, Vec<bool>>>(),
+            json_schema!({
+                "type": "array",
+                "items": { "type": "boolean" },
+                "minItems": 3,
+                "maxItems": 8
+            })
+        );
+        assert_eq!(
+            schema_of::<Validated<MaxLen<5>, String>>(),
+            json_schema!({ "type": "string", "maxLength": 5 })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_integer_with_closed_range() {
+        assert_eq!(
+            schema_of::<Validated<ClosedRange<1, 120>, u8>>(),
+            json_schema!({
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 1,
+                "maximum": 120
+            })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_with_const_generic_equivalents_of_parameterized_constraints() {
+        assert_eq!(
+            schema_of::<Validated<MinLen<2>, Vec<bool>>>(),
+            json_schema!({ "type": "array", "items": { "type": "boolean" }, "minItems": 2 })
+        );
+        assert_eq!(
+            schema_of::<Validated<(MinChars<2>, MinMaxChars<2, 5>), String>>(),
+            json_schema!({ "type": "string", "minLength": 2, "maxLength": 5 })
+        );
+        assert_eq!(
+            schema_of::<Validated<(Min<-10>, Max<10>), i32>>(),
+            json_schema!({
+                "type": "integer",
+                "format": "int32",
+                "minimum": -10,
+                "maximum": 10
+            })
+        );
+        assert_eq!(
+            schema_of::<Validated<MaxDigits<4, 2>, f64>>(),
+            json_schema!({ "type": "number", "format": "double", "multipleOf": 0.01 })
+        );
+    }
+
+    #[test]
+    fn schema_of_validated_field_in_a_derived_schema() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct RegisterUser {
+            username: Validated<NotEmpty, String>,
+        }
+
+        let schema = schema_of::<RegisterUser>();
+
+        assert_eq!(
+            schema.get("properties"),
+            Some(&serde_json::json!({
+                "username": { "type": "string", "minLength": 1 }
+            }))
+        );
+    }
+}
+
+mod length {
+    use super::*;
+
+    #[test]
+    fn length_on_a_string_generates_min_length_and_max_length() {
+        assert_eq!(
+            updated(schema_of::<String>(), Length::MinMax(3, 20)),
+            json_schema!({ "type": "string", "minLength": 3, "maxLength": 20 })
+        );
+    }
+
+    #[test]
+    fn length_on_an_array_generates_min_items_and_max_items() {
+        assert_eq!(
+            updated(schema_of::<Vec<String>>(), Length::Exact(2)),
+            json_schema!({
+                "type": "array",
+                "items": { "type": "string" },
+                "minItems": 2,
+                "maxItems": 2
+            })
+        );
+    }
+
+    #[test]
+    fn length_on_an_optional_array_generates_min_items() {
+        assert_eq!(
+            updated(schema_of::<Option<Vec<String>>>(), Length::Min(1)),
+            json_schema!({
+                "type": ["array", "null"],
+                "items": { "type": "string" },
+                "minItems": 1
+            })
+        );
+    }
+
+    #[test]
+    fn length_on_a_map_generates_min_properties_and_max_properties() {
+        assert_eq!(
+            updated(
+                schema_of::<std::collections::HashMap<String, i32>>(),
+                Length::MinMax(1, 3)
+            ),
+            json_schema!({
+                "type": "object",
+                "additionalProperties": { "type": "integer", "format": "int32" },
+                "minProperties": 1,
+                "maxProperties": 3
+            })
+        );
+    }
+
+    #[test]
+    fn length_and_not_empty_on_other_types_generate_nothing() {
+        assert_eq!(
+            updated(schema_of::<i32>(), Length::Max(5)),
+            json_schema!({ "type": "integer", "format": "int32" })
+        );
+        assert_eq!(
+            updated(schema_of::<bool>(), NotEmpty),
+            json_schema!({ "type": "boolean" })
+        );
+    }
+
+    #[test]
+    fn char_count_on_an_array_generates_nothing() {
+        assert_eq!(
+            updated(schema_of::<Vec<bool>>(), CharCount::Max(5)),
+            json_schema!({ "type": "array", "items": { "type": "boolean" } })
+        );
+    }
+
+    #[test]
+    fn char_count_generates_max_length() {
+        assert_eq!(
+            updated(schema_of::<String>(), CharCount::Max(15)),
+            json_schema!({ "type": "string", "maxLength": 15 })
+        );
+    }
+}
+
+mod bound {
+    use super::*;
+
+    #[test]
+    fn closed_range_generates_minimum_and_maximum() {
+        assert_eq!(
+            updated(schema_of::<u8>(), Bound::ClosedRange(1, 9)),
+            json_schema!({
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 1,
+                "maximum": 9
+            })
+        );
+    }
+
+    #[test]
+    fn open_range_generates_exclusive_minimum_and_exclusive_maximum() {
+        assert_eq!(
+            updated(schema_of::<f64>(), Bound::OpenRange(0., 1.)),
+            json_schema!({
+                "type": "number",
+                "format": "double",
+                "exclusiveMinimum": 0.0,
+                "exclusiveMaximum": 1.0
+            })
+        );
+    }
+
+    #[test]
+    fn greater_than_generates_exclusive_minimum() {
+        assert_eq!(
+            updated(schema_of::<i64>(), Bound::GreaterThan(0_i64)),
+            json_schema!({ "type": "integer", "format": "int64", "exclusiveMinimum": 0 })
+        );
+    }
+
+    #[test]
+    fn bound_on_a_string_generates_nothing() {
+        assert_eq!(
+            updated(schema_of::<String>(), Bound::Max(5)),
+            json_schema!({ "type": "string" })
+        );
+    }
+
+    #[test]
+    fn exact_generates_const() {
+        assert_eq!(
+            updated(schema_of::<i32>(), Bound::Exact(42)),
+            json_schema!({ "type": "integer", "format": "int32", "const": 42 })
+        );
+    }
+}
+
+mod digits {
+    use super::*;
+
+    #[test]
+    fn digits_on_a_number_generates_multiple_of() {
+        assert_eq!(
+            updated(
+                schema_of::<f64>(),
+                Digits {
+                    integer: 4,
+                    fraction: 2
+                }
+            ),
+            json_schema!({ "type": "number", "format": "double", "multipleOf": 0.01 })
+        );
+    }
+
+    #[test]
+    fn digits_without_fraction_generates_multiple_of_one() {
+        assert_eq!(
+            updated(
+                schema_of::<f64>(),
+                Digits {
+                    integer: 4,
+                    fraction: 0
+                }
+            ),
+            json_schema!({ "type": "number", "format": "double", "multipleOf": 1 })
+        );
+    }
+
+    #[test]
+    fn digits_with_unrepresentable_fraction_generates_nothing() {
+        assert_eq!(
+            updated(
+                schema_of::<f64>(),
+                Digits {
+                    integer: 4,
+                    fraction: 20
+                }
+            ),
+            json_schema!({ "type": "number", "format": "double" })
+        );
+    }
+
+    #[test]
+    fn digits_on_a_string_generates_nothing() {
+        assert_eq!(
+            updated(
+                schema_of::<String>(),
+                Digits {
+                    integer: 4,
+                    fraction: 2
+                }
+            ),
+            json_schema!({ "type": "string" })
+        );
+    }
+}
+
+#[cfg(feature = "regex")]
+mod pattern {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn pattern_generates_pattern() {
+        assert_eq!(
+            updated(
+                schema_of::<String>(),
+                Pattern(Regex::new("^[a-z]+$").unwrap())
+            ),
+            json_schema!({ "type": "string", "pattern": "^[a-z]+$" })
+        );
+    }
+
+    #[test]
+    fn pattern_on_a_number_generates_nothing() {
+        assert_eq!(
+            updated(schema_of::<i32>(), Pattern(Regex::new("^[0-9]+$").unwrap())),
+            json_schema!({ "type": "integer", "format": "int32" })
+        );
+    }
+}
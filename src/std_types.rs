@@ -1,5 +1,15 @@
-use crate::property::{HasCharCount, HasCheckedValue, HasEmptyValue, HasLength, HasMember};
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use crate::property::{
+    HasCharCount, HasCheckedValue, HasDecimalDigits, HasEmptyValue, HasLength, HasMember,
+    HasOptionalValues,
+};
+use crate::Value;
+use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
 use std::hash::{BuildHasher, Hash};
 
 impl HasCheckedValue for bool {
@@ -44,12 +54,14 @@ impl<T> HasEmptyValue for LinkedList<T> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<T, S> HasEmptyValue for HashSet<T, S> {
     fn is_empty_value(&self) -> bool {
         self.is_empty()
     }
 }
 
+#[cfg(feature = "std")]
 impl<K, V, S> HasEmptyValue for HashMap<K, V, S> {
     fn is_empty_value(&self) -> bool {
         self.is_empty()
@@ -176,6 +188,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<T, S> HasMember<T> for HashSet<T, S>
 where
     T: Eq + Hash,
@@ -186,6 +199,7 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<K, V, S> HasMember<K> for HashMap<K, V, S>
 where
     K: Eq + Hash,
@@ -214,6 +228,160 @@ where
     }
 }
 
+macro_rules! impl_has_decimal_digits {
+    (@decimal_literal $( $type:ty ),+) => {
+        $(
+            impl HasDecimalDigits for $type {
+                fn integer_digits(&self) -> u64 {
+                    decimal_digits(self).map_or(0, |(integer, _)| integer)
+                }
+
+                fn fraction_digits(&self) -> u64 {
+                    decimal_digits(self).map_or(0, |(_, fraction)| fraction)
+                }
+
+                fn is_decimal_number(&self) -> bool {
+                    decimal_digits(self).is_some()
+                }
+            }
+        )+
+    };
+    (@signed $( $type:ty ),+) => {
+        $(
+            impl HasDecimalDigits for $type {
+                // the cast is trivial for `i128` and `u128`
+                #[allow(trivial_numeric_casts)]
+                fn integer_digits(&self) -> u64 {
+                    count_integer_digits(self.unsigned_abs() as u128)
+                }
+
+                fn fraction_digits(&self) -> u64 {
+                    0
+                }
+            }
+        )+
+    };
+    (@unsigned $( $type:ty ),+) => {
+        $(
+            impl HasDecimalDigits for $type {
+                // the cast is trivial for `i128` and `u128`
+                #[allow(trivial_numeric_casts)]
+                fn integer_digits(&self) -> u64 {
+                    count_integer_digits(*self as u128)
+                }
+
+                fn fraction_digits(&self) -> u64 {
+                    0
+                }
+            }
+        )+
+    };
+    (@float $( $type:ty ),+) => {
+        $(
+            impl HasDecimalDigits for $type {
+                fn integer_digits(&self) -> u64 {
+                    decimal_digits(&self.to_string()).map_or(0, |(integer, _)| integer)
+                }
+
+                fn fraction_digits(&self) -> u64 {
+                    decimal_digits(&self.to_string()).map_or(0, |(_, fraction)| fraction)
+                }
+
+                fn is_decimal_number(&self) -> bool {
+                    self.is_finite()
+                }
+            }
+        )+
+    };
+}
+
+impl_has_decimal_digits!(@decimal_literal String, &str);
+impl_has_decimal_digits!(@signed i8, i16, i32, i64, i128, isize);
+impl_has_decimal_digits!(@unsigned u8, u16, u32, u64, u128, usize);
+impl_has_decimal_digits!(@float f32, f64);
+
+impl<T> HasOptionalValues for Vec<Option<T>>
+where
+    T: Clone + Into<Value>,
+{
+    fn optional_values(&self) -> Vec<Option<Value>> {
+        self.iter()
+            .map(|value| value.as_ref().map(|value| value.clone().into()))
+            .collect()
+    }
+}
+
+macro_rules! impl_has_optional_values_for_tuple {
+    ($($type:ident : $index:tt),+) => {
+        impl<$($type),+> HasOptionalValues for ($(Option<$type>,)+)
+        where
+            $($type: Clone + Into<Value>,)+
+        {
+            fn optional_values(&self) -> Vec<Option<Value>> {
+                vec![$(self.$index.as_ref().map(|value| value.clone().into())),+]
+            }
+        }
+    };
+}
+
+impl_has_optional_values_for_tuple!(A: 0, B: 1);
+impl_has_optional_values_for_tuple!(A: 0, B: 1, C: 2);
+impl_has_optional_values_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_has_optional_values_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_has_optional_values_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Counts the number of decimal digits of an integer.
+fn count_integer_digits(magnitude: u128) -> u64 {
+    let mut digits = 1;
+    let mut remainder = magnitude / 10;
+    while remainder > 0 {
+        digits += 1;
+        remainder /= 10;
+    }
+    digits
+}
+
+/// Determines the number of integer digits and fraction digits of a plain
+/// decimal literal like `-1234.50`.
+///
+/// The literal consists of an optional sign, at least one digit and an
+/// optional decimal point followed by at least one digit. Exponents are not
+/// supported. Returns `None` if the given string is not a plain decimal
+/// literal.
+///
+/// The digits are counted the same way as for `BigDecimal`: trailing zeros of
+/// the fraction are significant, leading zeros are not.
+fn decimal_digits(literal: &str) -> Option<(u64, u64)> {
+    let unsigned = if literal.starts_with('+') || literal.starts_with('-') {
+        &literal[1..]
+    } else {
+        literal
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(position) => {
+            let fraction = &unsigned[position + 1..];
+            if fraction.is_empty() {
+                return None;
+            }
+            (&unsigned[..position], fraction)
+        }
+        None => (unsigned, ""),
+    };
+    if integer.is_empty()
+        || !integer.bytes().all(|byte| byte.is_ascii_digit())
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+    let scale = fraction.len() as u64;
+    let precision = integer
+        .bytes()
+        .chain(fraction.bytes())
+        .skip_while(|digit| *digit == b'0')
+        .count() as u64;
+    Some((precision.max(1).saturating_sub(scale), scale))
+}
+
 #[cfg(not(feature = "num-traits"))]
 mod without_num_traits {
     use crate::property::HasZeroValue;
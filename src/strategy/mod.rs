@@ -0,0 +1,320 @@
+//! Generation of valid and invalid values for property based testing
+//!
+//! This module adds methods to some constraints of this crate that return
+//! [`proptest`] strategies. The strategies generate values that comply to the
+//! constraint or that violate it. With these strategies we can test our own
+//! validators and the handlers of our APIs against exactly the boundaries
+//! declared by the constraints.
+//!
+//! | constraint  | valid values                                  | invalid values                                      |
+//! |-------------|-----------------------------------------------|-----------------------------------------------------|
+//! | `Length`    | `valid_lengths`, `valid_strings`, `valid_vecs` | `invalid_lengths`, `invalid_strings`, `invalid_vecs` |
+//! | `CharCount` | `valid`                                       | `invalid`                                           |
+//! | `Bound<T>`  | `valid_values`                                | `invalid_values`                                    |
+//!
+//! The strategies for the `Bound` constraint are available for the primitive
+//! integer types, see [`BoundedValue`].
+//!
+//! The strings generated for the `Length` constraint consist of printable
+//! ASCII characters only, so that their length in bytes is equal to the number
+//! of characters. The strings generated for the `CharCount` constraint may
+//! contain any unicode character.
+//!
+//! Constraints on lengths or numbers of characters without an upper limit,
+//! like `Length::Min(3)`, generate valid values with up to
+//! [`UNBOUNDED_SPREAD`] elements more than the lower limit. The same spread
+//! applies to invalid values that exceed an upper limit.
+//!
+//! A strategy can not be created for a constraint that has no valid or
+//! respectively no invalid values, for example the invalid values of
+//! `Length::Min(0)`. The methods panic in this case.
+//!
+//! This module is only available with the optional crate feature `proptest`.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use valid::constraint::{Bound, CharCount, Length};
+//! use valid::ValidateRef;
+//!
+//! proptest! {
+//!     fn username_of_valid_length_is_accepted(username in Length::MinMax(3, 20).valid_strings()) {
+//!         prop_assert!(username.validate_ref("username", &Length::MinMax(3, 20)).is_empty());
+//!     }
+//!
+//!     fn rating_out_of_range_is_rejected(rating in Bound::ClosedRange(1, 9).invalid_values()) {
+//!         prop_assert!(!rating.validate_ref("rating", &Bound::ClosedRange(1, 9)).is_empty());
+//!     }
+//!
+//!     fn short_names_are_accepted(name in CharCount::Max(5).valid()) {
+//!         prop_assert!(name.chars().count() <= 5);
+//!     }
+//! }
+//!
+//! username_of_valid_length_is_accepted();
+//! rating_out_of_range_is_rejected();
+//! short_names_are_accepted();
+//! ```
+//!
+//! [`proptest`]: https://crates.io/crates/proptest
+//! [`UNBOUNDED_SPREAD`]: constant.UNBOUNDED_SPREAD.html
+//! [`BoundedValue`]: trait.BoundedValue.html
+
+use crate::constraint::{Bound, CharCount, Length};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use std::fmt::Debug;
+
+/// The number of elements beyond the lower limit up to which values are
+/// generated if there is no upper limit.
+pub const UNBOUNDED_SPREAD: usize = 32;
+
+impl Length {
+    /// Returns a strategy that generates lengths that comply to this
+    /// constraint.
+    pub fn valid_lengths(&self) -> BoxedStrategy<usize> {
+        let (min, max) = self.limits();
+        valid_counts(min, max)
+    }
+
+    /// Returns a strategy that generates lengths that violate this
+    /// constraint.
+    pub fn invalid_lengths(&self) -> BoxedStrategy<usize> {
+        let (min, max) = self.limits();
+        invalid_counts(min, max)
+    }
+
+    /// Returns a strategy that generates strings of printable ASCII
+    /// characters whose length complies to this constraint.
+    pub fn valid_strings(&self) -> BoxedStrategy<String> {
+        ascii_strings(self.valid_lengths())
+    }
+
+    /// Returns a strategy that generates strings of printable ASCII
+    /// characters whose length violates this constraint.
+    pub fn invalid_strings(&self) -> BoxedStrategy<String> {
+        ascii_strings(self.invalid_lengths())
+    }
+
+    /// Returns a strategy that generates vectors of elements generated by the
+    /// given strategy whose length complies to this constraint.
+    pub fn valid_vecs<S>(&self, element: S) -> BoxedStrategy<Vec<S::Value>>
+    where
+        S: Strategy + Clone + 'static,
+        S::Value: Debug,
+    {
+        vecs(self.valid_lengths(), element)
+    }
+
+    /// Returns a strategy that generates vectors of elements generated by the
+    /// given strategy whose length violates this constraint.
+    pub fn invalid_vecs<S>(&self, element: S) -> BoxedStrategy<Vec<S::Value>>
+    where
+        S: Strategy + Clone + 'static,
+        S::Value: Debug,
+    {
+        vecs(self.invalid_lengths(), element)
+    }
+
+    fn limits(&self) -> (usize, Option<usize>) {
+        match *self {
+            Length::Max(max) => (0, Some(max)),
+            Length::Min(min) => (min, None),
+            Length::MinMax(min, max) => (min, Some(max)),
+            Length::Exact(exact) => (exact, Some(exact)),
+        }
+    }
+}
+
+impl CharCount {
+    /// Returns a strategy that generates strings whose number of characters
+    /// complies to this constraint.
+    pub fn valid(&self) -> BoxedStrategy<String> {
+        let (min, max) = self.limits();
+        char_strings(valid_counts(min, max))
+    }
+
+    /// Returns a strategy that generates strings whose number of characters
+    /// violates this constraint.
+    pub fn invalid(&self) -> BoxedStrategy<String> {
+        let (min, max) = self.limits();
+        char_strings(invalid_counts(min, max))
+    }
+
+    fn limits(&self) -> (usize, Option<usize>) {
+        match *self {
+            CharCount::Max(max) => (0, Some(max)),
+            CharCount::Min(min) => (min, None),
+            CharCount::MinMax(min, max) => (min, Some(max)),
+            CharCount::Exact(exact) => (exact, Some(exact)),
+        }
+    }
+}
+
+fn valid_counts(min: usize, max: Option<usize>) -> BoxedStrategy<usize> {
+    let max = max.unwrap_or_else(|| min.saturating_add(UNBOUNDED_SPREAD));
+    assert!(min <= max, "the constraint has no valid values");
+    (min..=max).boxed()
+}
+
+fn invalid_counts(min: usize, max: Option<usize>) -> BoxedStrategy<usize> {
+    let mut ranges = Vec::with_capacity(2);
+    if min > 0 {
+        ranges.push((0..min).boxed());
+    }
+    if let Some(max) = max.filter(|max| *max < usize::MAX) {
+        ranges.push((max + 1..=max.saturating_add(UNBOUNDED_SPREAD)).boxed());
+    }
+    assert!(!ranges.is_empty(), "the constraint has no invalid values");
+    Union::new(ranges).boxed()
+}
+
+fn ascii_strings(lengths: BoxedStrategy<usize>) -> BoxedStrategy<String> {
+    char_strings_of(lengths, proptest::char::range(' ', '~'))
+}
+
+fn char_strings(counts: BoxedStrategy<usize>) -> BoxedStrategy<String> {
+    char_strings_of(counts, any::<char>())
+}
+
+fn char_strings_of(
+    counts: BoxedStrategy<usize>,
+    chars: impl Strategy<Value = char> + Clone + 'static,
+) -> BoxedStrategy<String> {
+    counts
+        .prop_flat_map(move |count| vec(chars.clone(), count))
+        .prop_map(|chars| chars.into_iter().collect())
+        .boxed()
+}
+
+fn vecs<S>(lengths: BoxedStrategy<usize>, element: S) -> BoxedStrategy<Vec<S::Value>>
+where
+    S: Strategy + Clone + 'static,
+    S::Value: Debug,
+{
+    lengths
+        .prop_flat_map(move |length| vec(element.clone(), length))
+        .boxed()
+}
+
+/// A type of values for which strategies can be created from a `Bound`
+/// constraint.
+///
+/// It is implemented for the primitive integer types.
+pub trait BoundedValue: PartialOrd + Copy + Debug + Sized + 'static {
+    /// The smallest value of the type
+    const MIN: Self;
+
+    /// The greatest value of the type
+    const MAX: Self;
+
+    /// Returns a strategy that generates values between `min` and `max`
+    /// (inclusive).
+    fn range(min: Self, max: Self) -> BoxedStrategy<Self>;
+
+    /// Returns the next smaller value or `None` if this is the smallest value.
+    fn predecessor(self) -> Option<Self>;
+
+    /// Returns the next greater value or `None` if this is the greatest value.
+    fn successor(self) -> Option<Self>;
+}
+
+macro_rules! impl_bounded_value {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl BoundedValue for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+
+                fn range(min: Self, max: Self) -> BoxedStrategy<Self> {
+                    (min..=max).boxed()
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )+
+    };
+}
+
+impl_bounded_value!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// A limit of a `Bound` constraint
+#[derive(Clone, Copy)]
+enum Limit<T> {
+    Inclusive(T),
+    Exclusive(T),
+    Unlimited,
+}
+
+impl<T> Bound<T>
+where
+    T: BoundedValue,
+{
+    /// Returns a strategy that generates values that comply to this
+    /// constraint.
+    pub fn valid_values(&self) -> BoxedStrategy<T> {
+        let (lower, upper) = self.limits();
+        let min = match lower {
+            Limit::Inclusive(min) => Some(min),
+            Limit::Exclusive(min) => min.successor(),
+            Limit::Unlimited => Some(T::MIN),
+        };
+        let max = match upper {
+            Limit::Inclusive(max) => Some(max),
+            Limit::Exclusive(max) => max.predecessor(),
+            Limit::Unlimited => Some(T::MAX),
+        };
+        match (min, max) {
+            (Some(min), Some(max)) if min <= max => T::range(min, max),
+            _ => panic!("the constraint has no valid values"),
+        }
+    }
+
+    /// Returns a strategy that generates values that violate this
+    /// constraint.
+    pub fn invalid_values(&self) -> BoxedStrategy<T> {
+        let (lower, upper) = self.limits();
+        let below = match lower {
+            Limit::Inclusive(min) => min.predecessor(),
+            Limit::Exclusive(min) => Some(min),
+            Limit::Unlimited => None,
+        };
+        let above = match upper {
+            Limit::Inclusive(max) => max.successor(),
+            Limit::Exclusive(max) => Some(max),
+            Limit::Unlimited => None,
+        };
+        let ranges = below
+            .map(|below| T::range(T::MIN, below))
+            .into_iter()
+            .chain(above.map(|above| T::range(above, T::MAX)))
+            .collect::<Vec<_>>();
+        assert!(!ranges.is_empty(), "the constraint has no invalid values");
+        Union::new(ranges).boxed()
+    }
+
+    fn limits(&self) -> (Limit<T>, Limit<T>) {
+        match *self {
+            Bound::ClosedRange(min, max) => (Limit::Inclusive(min), Limit::Inclusive(max)),
+            Bound::ClosedOpenRange(min, max) => (Limit::Inclusive(min), Limit::Exclusive(max)),
+            Bound::OpenClosedRange(min, max) => (Limit::Exclusive(min), Limit::Inclusive(max)),
+            Bound::OpenRange(min, max) => (Limit::Exclusive(min), Limit::Exclusive(max)),
+            Bound::Exact(value) => (Limit::Inclusive(value), Limit::Inclusive(value)),
+            Bound::Min(min) => (Limit::Inclusive(min), Limit::Unlimited),
+            Bound::Max(max) => (Limit::Unlimited, Limit::Inclusive(max)),
+            Bound::GreaterThan(min) => (Limit::Exclusive(min), Limit::Unlimited),
+            Bound::LessThan(max) => (Limit::Unlimited, Limit::Exclusive(max)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use super::*;
+use crate::constraint::{
+    INVALID_BOUND_CLOSED_MAX, INVALID_BOUND_CLOSED_MIN, INVALID_BOUND_OPEN_MAX,
+    INVALID_BOUND_OPEN_MIN, INVALID_CHAR_COUNT_MAX, INVALID_LENGTH_MAX, INVALID_LENGTH_MIN,
+};
+use crate::ValidateRef;
+
+fn codes(violations: &[crate::ConstraintViolation]) -> Vec<&str> {
+    violations
+        .iter()
+        .map(|violation| violation.code())
+        .collect()
+}
+
+mod length {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn valid_strings_comply_to_length_min_max(value in Length::MinMax(3, 20).valid_strings()) {
+            prop_assert!(value.validate_ref("name", &Length::MinMax(3, 20)).is_empty());
+        }
+
+        #[test]
+        fn invalid_strings_violate_length_min_max(value in Length::MinMax(3, 20).invalid_strings()) {
+            let violations = value.validate_ref("name", &Length::MinMax(3, 20));
+
+            prop_assert!(
+                codes(&violations) == [INVALID_LENGTH_MIN] || codes(&violations) == [INVALID_LENGTH_MAX]
+            );
+        }
+
+        #[test]
+        fn valid_strings_of_length_min_are_limited_by_the_spread(value in Length::Min(5).valid_strings()) {
+            prop_assert!(value.len() >= 5 && value.len() <= 5 + UNBOUNDED_SPREAD);
+        }
+
+        #[test]
+        fn invalid_vecs_violate_length_max(value in Length::Max(2).invalid_vecs(any::<u8>())) {
+            let violations = value.validate_ref("items", &Length::Max(2));
+
+            prop_assert_eq!(codes(&violations), vec![INVALID_LENGTH_MAX]);
+        }
+
+        #[test]
+        fn valid_vecs_have_the_exact_length(value in Length::Exact(4).valid_vecs(any::<bool>())) {
+            prop_assert_eq!(value.len(), 4);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the constraint has no invalid values")]
+    fn length_min_zero_has_no_invalid_values() {
+        let _ = Length::Min(0).invalid_lengths();
+    }
+
+    #[test]
+    #[should_panic(expected = "the constraint has no valid values")]
+    fn length_min_max_with_min_greater_max_has_no_valid_values() {
+        let _ = Length::MinMax(3, 2).valid_lengths();
+    }
+}
+
+mod char_count {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn valid_strings_comply_to_char_count_max(value in CharCount::Max(5).valid()) {
+            prop_assert!(value.validate_ref("name", &CharCount::Max(5)).is_empty());
+        }
+
+        #[test]
+        fn invalid_strings_violate_char_count_max(value in CharCount::Max(5).invalid()) {
+            let violations = value.validate_ref("name", &CharCount::Max(5));
+
+            prop_assert_eq!(codes(&violations), vec![INVALID_CHAR_COUNT_MAX]);
+        }
+    }
+}
+
+mod bound {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn valid_values_comply_to_bound_closed_range(value in Bound::ClosedRange(1, 9).valid_values()) {
+            prop_assert!(value.validate_ref("rating", &Bound::ClosedRange(1, 9)).is_empty());
+        }
+
+        #[test]
+        fn invalid_values_violate_bound_closed_range(value in Bound::ClosedRange(1, 9).invalid_values()) {
+            let violations = value.validate_ref("rating", &Bound::ClosedRange(1, 9));
+
+            prop_assert!(
+                codes(&violations) == [INVALID_BOUND_CLOSED_MIN]
+                    || codes(&violations) == [INVALID_BOUND_CLOSED_MAX]
+            );
+        }
+
+        #[test]
+        fn valid_values_comply_to_bound_open_range(value in Bound::OpenRange(0u8, 3).valid_values()) {
+            prop_assert!(value == 1 || value == 2);
+        }
+
+        #[test]
+        fn invalid_values_violate_bound_open_range(value in Bound::OpenRange(-10i64, 10).invalid_values()) {
+            let violations = value.validate_ref("offset", &Bound::OpenRange(-10, 10));
+
+            prop_assert!(
+                codes(&violations) == [INVALID_BOUND_OPEN_MIN]
+                    || codes(&violations) == [INVALID_BOUND_OPEN_MAX]
+            );
+        }
+
+        #[test]
+        fn invalid_values_of_bound_exact_differ_from_the_value(value in Bound::Exact(42u16).invalid_values()) {
+            prop_assert_ne!(value, 42);
+        }
+
+        #[test]
+        fn invalid_values_of_bound_min_at_the_lower_end_are_below_the_minimum(
+            value in Bound::Min(1u32).invalid_values()
+        ) {
+            prop_assert_eq!(value, 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the constraint has no invalid values")]
+    fn bound_min_of_the_smallest_value_has_no_invalid_values() {
+        let _ = Bound::Min(i8::MIN).invalid_values();
+    }
+
+    #[test]
+    #[should_panic(expected = "the constraint has no valid values")]
+    fn bound_open_range_of_adjacent_values_has_no_valid_values() {
+        let _ = Bound::OpenRange(1, 2).valid_values();
+    }
+}
@@ -0,0 +1,425 @@
+//! Assertions for validation results in tests
+//!
+//! Asserting the exact `ValidationError` returned by a validation leads to
+//! deeply nested struct literals in our tests. Most of the time we only want
+//! to know whether a value is valid or that a certain constraint is violated
+//! for some field. This module provides matchers and the following macros for
+//! this purpose:
+//!
+//! * [`assert_valid!`] - asserts that there are no violations and returns the
+//!   valid value
+//! * [`assert_invalid!`] - asserts that there is at least one violation and
+//!   returns the violations
+//! * [`assert_violation!`] - asserts that there is a violation with the given
+//!   properties, like the error code and the field name
+//! * [`assert_codes!`] - asserts that the error codes of the violations are
+//!   exactly the given set of codes
+//!
+//! The macros accept any type implementing [`ValidationOutcome`], which are a
+//! `Validation`, any `Result` with a `ValidationError` as error, a
+//! `ValidationError` and the list of constraint violations returned by
+//! `ValidateRef::validate_ref`. The violations of a `Validation` include its
+//! warnings. A `Result` is checked by its error only, so pass the
+//! `Validation` itself to check its warnings as well. If an assertion fails
+//! the message lists all violations one per line, so that we can see at a
+//! glance what went wrong.
+//!
+//! # Examples
+//!
+//! ```
+//! use valid::constraint::{CharCount, Length, NotEmpty, INVALID_LENGTH_MAX, INVALID_NOT_EMPTY};
+//! use valid::{assert_codes, assert_valid, assert_violation, Validate, ValidateRef};
+//!
+//! let validated = assert_valid!(String::from("jane").validate("name", &NotEmpty));
+//! assert_eq!(validated.unwrap(), "jane");
+//!
+//! let validation = String::from("Jane Doe").validate("name", &Length::Max(5));
+//! assert_violation!(validation, code = INVALID_LENGTH_MAX, field = "name", actual = 8);
+//!
+//! let name = String::new();
+//! let mut violations = name.validate_ref("name", &NotEmpty);
+//! violations.extend(name.validate_ref("name", &CharCount::Min(2)));
+//! assert_codes!(violations, [INVALID_NOT_EMPTY, "invalid-char-count-min"]);
+//! ```
+//!
+//! [`assert_valid!`]: ../macro.assert_valid.html
+//! [`assert_invalid!`]: ../macro.assert_invalid.html
+//! [`assert_violation!`]: ../macro.assert_violation.html
+//! [`assert_codes!`]: ../macro.assert_codes.html
+//! [`ValidationOutcome`]: trait.ValidationOutcome.html
+
+use crate::{ConstraintViolation, Severity, Validated, Validation, ValidationError, Value};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// The outcome of a validation that can be checked by the assertion macros.
+pub trait ValidationOutcome {
+    /// The type of the value returned if the outcome is valid
+    type Valid;
+
+    /// Converts this outcome into the valid value or the list of constraint
+    /// violations.
+    fn into_outcome(self) -> Result<Self::Valid, Vec<ConstraintViolation>>;
+}
+
+/// A validation is only valid if it has no constraint violations at all.
+/// Violations of severity `Warning` or `Info` are checked like errors.
+impl<C, T> ValidationOutcome for Validation<C, T> {
+    type Valid = Validated<C, T>;
+
+    fn into_outcome(self) -> Result<Self::Valid, Vec<ConstraintViolation>> {
+        match self.result_with_warnings() {
+            Ok((validated, warnings)) => {
+                if warnings.is_empty() {
+                    Ok(validated)
+                } else {
+                    Err(warnings)
+                }
+            }
+            Err(error) => Err(error.violations),
+        }
+    }
+}
+
+impl<T> ValidationOutcome for Result<T, ValidationError> {
+    type Valid = T;
+
+    fn into_outcome(self) -> Result<Self::Valid, Vec<ConstraintViolation>> {
+        self.map_err(|error| error.violations)
+    }
+}
+
+impl ValidationOutcome for ValidationError {
+    type Valid = ();
+
+    fn into_outcome(self) -> Result<Self::Valid, Vec<ConstraintViolation>> {
+        self.violations.into_outcome()
+    }
+}
+
+impl ValidationOutcome for Vec<ConstraintViolation> {
+    type Valid = ();
+
+    fn into_outcome(self) -> Result<Self::Valid, Vec<ConstraintViolation>> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// Matches constraint violations by some of their properties.
+///
+/// Properties that are not set match any value. The actual and expected
+/// values only match violations of constraints validated in the `FieldName`
+/// context.
+///
+/// # Examples
+///
+/// ```
+/// use valid::invalid_value;
+/// use valid::testing::ViolationMatcher;
+///
+/// let matcher = ViolationMatcher::new().code("invalid-length-max").field("name");
+///
+/// assert!(matcher.matches(&invalid_value("invalid-length-max", "name", 8, 5)));
+/// assert!(!matcher.matches(&invalid_value("invalid-length-max", "email", 42, 40)));
+/// assert_eq!(matcher.to_string(), r#"code = "invalid-length-max", field = "name""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ViolationMatcher {
+    code: Option<Cow<'static, str>>,
+    field: Option<Cow<'static, str>>,
+    severity: Option<Severity>,
+    actual: Option<Value>,
+    expected: Option<Value>,
+}
+
+impl ViolationMatcher {
+    /// Constructs a matcher that matches any constraint violation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches violations with the given error code.
+    pub fn code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Matches violations that refer to the given field.
+    pub fn field(mut self, field: impl Into<Cow<'static, str>>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Matches violations of the given severity.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Matches field violations with the given actual value.
+    pub fn actual(mut self, actual: impl Into<Value>) -> Self {
+        self.actual = Some(actual.into());
+        self
+    }
+
+    /// Matches field violations with the given expected value.
+    pub fn expected(mut self, expected: impl Into<Value>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Returns whether the given constraint violation matches all properties
+    /// of this matcher.
+    pub fn matches(&self, violation: &ConstraintViolation) -> bool {
+        self.code
+            .as_ref()
+            .map_or(true, |code| violation.code() == code)
+            && self
+                .field
+                .as_ref()
+                .map_or(true, |field| violation.is_for_field(field))
+            && self
+                .severity
+                .map_or(true, |severity| violation.severity() == severity)
+            && self.matches_values(violation)
+    }
+
+    fn matches_values(&self, violation: &ConstraintViolation) -> bool {
+        if self.actual.is_none() && self.expected.is_none() {
+            return true;
+        }
+        match violation {
+            ConstraintViolation::Field(invalid_value) => {
+                let field = &invalid_value.field;
+                (self.actual.is_none() || field.actual == self.actual)
+                    && (self.expected.is_none() || field.expected == self.expected)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for ViolationMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut properties = Vec::new();
+        if let Some(code) = &self.code {
+            properties.push(format!("code = {:?}", code));
+        }
+        if let Some(field) = &self.field {
+            properties.push(format!("field = {:?}", field));
+        }
+        if let Some(severity) = &self.severity {
+            properties.push(format!("severity = {}", severity));
+        }
+        if let Some(actual) = &self.actual {
+            properties.push(format!("actual = {}", actual));
+        }
+        if let Some(expected) = &self.expected {
+            properties.push(format!("expected = {}", expected));
+        }
+        if properties.is_empty() {
+            f.write_str("any violation")
+        } else {
+            f.write_str(&properties.join(", "))
+        }
+    }
+}
+
+/// The difference between the error codes of some constraint violations and
+/// an expected set of error codes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeSetDiff {
+    /// The expected codes that are not found in the violations
+    pub missing: Vec<String>,
+    /// The codes found in the violations that are not expected
+    pub unexpected: Vec<String>,
+}
+
+impl CodeSetDiff {
+    /// Compares the set of error codes of the given violations with the
+    /// expected set of codes.
+    ///
+    /// Duplicates and the order of the codes are ignored.
+    pub fn of<S>(violations: &[ConstraintViolation], expected: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let actual = violations
+            .iter()
+            .map(ConstraintViolation::code)
+            .collect::<BTreeSet<_>>();
+        let expected = expected.iter().map(AsRef::as_ref).collect::<BTreeSet<_>>();
+        CodeSetDiff {
+            missing: expected
+                .difference(&actual)
+                .map(|code| code.to_string())
+                .collect(),
+            unexpected: actual
+                .difference(&expected)
+                .map(|code| code.to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns true if the codes are equal to the expected codes.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+impl Display for CodeSetDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for code in &self.missing {
+            writeln!(f, "- {}", code)?;
+        }
+        for code in &self.unexpected {
+            writeln!(f, "+ {}", code)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the given violations one per line for the message of a failed
+/// assertion.
+pub fn describe_violations(violations: &[ConstraintViolation]) -> String {
+    if violations.is_empty() {
+        return "  (none)\n".to_string();
+    }
+    violations
+        .iter()
+        .enumerate()
+        .map(|(index, violation)| format!("  {}: {}\n", index, violation))
+        .collect()
+}
+
+/// Asserts that a validation outcome is valid and returns the valid value.
+///
+/// It accepts any type implementing [`ValidationOutcome`]. An optional
+/// message with format arguments can be given like for `assert!`.
+///
+/// See the [`testing`] module for an example.
+///
+/// [`ValidationOutcome`]: testing/trait.ValidationOutcome.html
+/// [`testing`]: testing/index.html
+#[macro_export]
+macro_rules! assert_valid {
+    (@message $outcome:expr, $message:expr) => {
+        match $crate::testing::ValidationOutcome::into_outcome($outcome) {
+            ::core::result::Result::Ok(valid) => valid,
+            ::core::result::Result::Err(violations) => panic!(
+                "assertion failed: `{}` is valid{}\nviolations:\n{}",
+                stringify!($outcome),
+                $message,
+                $crate::testing::describe_violations(&violations)
+            ),
+        }
+    };
+    ($outcome:expr $(,)?) => {
+        $crate::assert_valid!(@message $outcome, "")
+    };
+    ($outcome:expr, $($message:tt)+) => {
+        $crate::assert_valid!(@message $outcome, format_args!(": {}", format_args!($($message)+)))
+    };
+}
+
+/// Asserts that a validation outcome is invalid and returns the constraint
+/// violations.
+///
+/// It accepts any type implementing [`ValidationOutcome`]. An optional
+/// message with format arguments can be given like for `assert!`.
+///
+/// See the [`testing`] module for an example.
+///
+/// [`ValidationOutcome`]: testing/trait.ValidationOutcome.html
+/// [`testing`]: testing/index.html
+#[macro_export]
+macro_rules! assert_invalid {
+    (@message $outcome:expr, $message:expr) => {
+        match $crate::testing::ValidationOutcome::into_outcome($outcome) {
+            ::core::result::Result::Ok(_) => panic!(
+                "assertion failed: `{}` is invalid{}",
+                stringify!($outcome),
+                $message
+            ),
+            ::core::result::Result::Err(violations) => violations,
+        }
+    };
+    ($outcome:expr $(,)?) => {
+        $crate::assert_invalid!(@message $outcome, "")
+    };
+    ($outcome:expr, $($message:tt)+) => {
+        $crate::assert_invalid!(@message $outcome, format_args!(": {}", format_args!($($message)+)))
+    };
+}
+
+/// Asserts that a validation outcome contains a constraint violation with the
+/// given properties and returns all constraint violations.
+///
+/// The properties are given as `name = value` pairs, where the name is one of
+/// the methods of [`ViolationMatcher`]: `code`, `field`, `severity`, `actual`
+/// or `expected`.
+///
+/// See the [`testing`] module for an example.
+///
+/// [`ViolationMatcher`]: testing/struct.ViolationMatcher.html
+/// [`testing`]: testing/index.html
+#[macro_export]
+macro_rules! assert_violation {
+    ($outcome:expr, $($property:ident = $value:expr),+ $(,)?) => {{
+        let matcher = $crate::testing::ViolationMatcher::new()$(.$property($value))+;
+        let violations = $crate::assert_invalid!($outcome);
+        if !violations.iter().any(|violation| matcher.matches(violation)) {
+            panic!(
+                "assertion failed: `{}` has a violation with {}\nviolations:\n{}",
+                stringify!($outcome),
+                matcher,
+                $crate::testing::describe_violations(&violations)
+            );
+        }
+        violations
+    }};
+}
+
+/// Asserts that the error codes of the constraint violations of a validation
+/// outcome are exactly the given set of codes.
+///
+/// Duplicates and the order of the codes are ignored. If the assertion fails
+/// the message shows the missing codes prefixed by `-` and the unexpected
+/// codes prefixed by `+`. An empty set of codes asserts that the outcome is
+/// valid.
+///
+/// See the [`testing`] module for an example.
+///
+/// [`testing`]: testing/index.html
+#[macro_export]
+macro_rules! assert_codes {
+    ($outcome:expr, [$($code:expr),* $(,)?] $(,)?) => {{
+        let violations = match $crate::testing::ValidationOutcome::into_outcome($outcome) {
+            ::core::result::Result::Ok(_) => $crate::__private::Vec::new(),
+            ::core::result::Result::Err(violations) => violations,
+        };
+        let expected: &[&str] = &[$($code),*];
+        let diff = $crate::testing::CodeSetDiff::of(&violations, expected);
+        if !diff.is_empty() {
+            panic!(
+                "assertion failed: `{}` has exactly the codes {:?}\ndiff (- missing, + unexpected):\n{}violations:\n{}",
+                stringify!($outcome),
+                expected,
+                diff,
+                $crate::testing::describe_violations(&violations)
+            );
+        }
+        violations
+    }};
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,241 @@
+use super::*;
+use crate::constraint::{Length, NotEmpty, INVALID_LENGTH_MAX, INVALID_NOT_EMPTY};
+use crate::{invalid_relation, invalid_state, invalid_value, Validate};
+
+fn panic_message(assertion: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = std::panic::catch_unwind(assertion).expect_err("assertion should fail");
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_default(),
+    }
+}
+
+mod assert_valid {
+    use super::*;
+
+    #[test]
+    fn assert_valid_returns_the_validated_value() {
+        let validated = assert_valid!(String::from("jane").validate("name", &NotEmpty));
+
+        assert_eq!(validated.unwrap(), "jane");
+    }
+
+    #[test]
+    fn assert_valid_accepts_an_empty_list_of_violations() {
+        let violations: Vec<ConstraintViolation> = vec![];
+
+        assert_valid!(violations);
+    }
+
+    #[test]
+    fn assert_valid_lists_the_violations_on_failure() {
+        let message = panic_message(|| {
+            assert_valid!(String::new().validate("name", &NotEmpty), "for {}", "jane");
+        });
+
+        assert_eq!(
+            message,
+            "assertion failed: `String::new().validate(\"name\", &NotEmpty)` is valid: for jane\n\
+             violations:\n  \
+             0: invalid-not-empty of name which is (n.a.), expected to be (n.a.)\n"
+        );
+    }
+
+    #[test]
+    fn assert_invalid_returns_the_violations() {
+        let violations = assert_invalid!(String::new()
+            .validate("name", &NotEmpty)
+            .result_with_warnings());
+
+        assert_eq!(
+            violations,
+            vec![crate::invalid_optional_value(
+                INVALID_NOT_EMPTY,
+                "name",
+                None,
+                None
+            )]
+        );
+    }
+
+    #[test]
+    fn assert_invalid_fails_for_a_valid_outcome() {
+        let message = panic_message(|| {
+            assert_invalid!(Ok::<_, ValidationError>(42));
+        });
+
+        assert_eq!(
+            message,
+            "assertion failed: `Ok::<_, ValidationError>(42)` is invalid"
+        );
+    }
+}
+
+mod assert_violation {
+    use super::*;
+
+    #[test]
+    fn assert_violation_finds_a_violation_by_code_and_field() {
+        let result = String::from("Jane Doe")
+            .validate("name", &Length::Max(5))
+            .result_with_warnings();
+
+        let violations = assert_violation!(result, code = INVALID_LENGTH_MAX, field = "name");
+
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn assert_violation_matches_actual_and_expected_values() {
+        let error = ValidationError::new(
+            None,
+            vec![
+                invalid_value(INVALID_LENGTH_MAX, "name", 8, 5),
+                invalid_value(INVALID_LENGTH_MAX, "email", 50, 40),
+            ],
+        );
+
+        assert_violation!(error, field = "email", actual = 50, expected = 40);
+    }
+
+    #[test]
+    fn assert_violation_fails_if_no_violation_matches() {
+        let message = panic_message(|| {
+            let violations = vec![invalid_value(INVALID_LENGTH_MAX, "name", 8, 5)];
+            assert_violation!(violations, code = INVALID_LENGTH_MAX, field = "email");
+        });
+
+        assert_eq!(
+            message,
+            "assertion failed: `violations` has a violation with \
+             code = \"invalid-length-max\", field = \"email\"\n\
+             violations:\n  \
+             0: invalid-length-max of name which is 8, expected to be 5\n"
+        );
+    }
+
+    #[test]
+    fn assert_violation_finds_the_warnings_of_a_validation() {
+        let warning =
+            invalid_value(INVALID_LENGTH_MAX, "nickname", 19, 15).with_severity(Severity::Warning);
+        let validation: Validation<(), String> =
+            Validation::checked(String::from("the-quick-brown-fox"), vec![warning]);
+
+        assert_violation!(
+            validation,
+            code = INVALID_LENGTH_MAX,
+            severity = Severity::Warning
+        );
+    }
+
+    #[test]
+    fn assert_valid_fails_for_a_validation_with_warnings() {
+        let message = panic_message(|| {
+            let warning = invalid_value(INVALID_LENGTH_MAX, "nickname", 19, 15)
+                .with_severity(Severity::Warning);
+            let validation: Validation<(), String> =
+                Validation::checked(String::from("the-quick-brown-fox"), vec![warning]);
+            assert_valid!(validation);
+        });
+
+        assert_eq!(
+            message,
+            "assertion failed: `validation` is valid\n\
+             violations:\n  \
+             0: invalid-length-max of nickname which is 19, expected to be 15\n"
+        );
+    }
+
+    #[test]
+    fn violation_matcher_matches_relations_by_any_of_their_fields() {
+        let violation = invalid_relation(
+            "invalid-must-match",
+            "password",
+            "s3cr3t".to_string(),
+            "password2",
+            "s3crEt".to_string(),
+        );
+
+        assert!(ViolationMatcher::new()
+            .field("password2")
+            .matches(&violation));
+        assert!(!ViolationMatcher::new().actual(1).matches(&violation));
+    }
+
+    #[test]
+    fn violation_matcher_matches_the_severity() {
+        let violation =
+            invalid_state("invalid-maintenance", vec![]).with_severity(Severity::Warning);
+
+        assert!(ViolationMatcher::new()
+            .severity(Severity::Warning)
+            .matches(&violation));
+        assert!(!ViolationMatcher::new()
+            .severity(Severity::Error)
+            .matches(&violation));
+    }
+
+    #[test]
+    fn display_format_a_violation_matcher_without_properties() {
+        assert_eq!(ViolationMatcher::new().to_string(), "any violation");
+    }
+}
+
+mod assert_codes {
+    use super::*;
+
+    #[test]
+    fn assert_codes_ignores_order_and_duplicates() {
+        let error = ValidationError::new(
+            None,
+            vec![
+                invalid_value(INVALID_LENGTH_MAX, "name", 8, 5),
+                invalid_state("invalid-maintenance", vec![]),
+                invalid_value(INVALID_LENGTH_MAX, "email", 50, 40),
+            ],
+        );
+
+        assert_codes!(error, ["invalid-maintenance", INVALID_LENGTH_MAX]);
+    }
+
+    #[test]
+    fn assert_codes_with_an_empty_set_asserts_a_valid_outcome() {
+        assert_codes!(String::from("jane").validate("name", &NotEmpty), []);
+    }
+
+    #[test]
+    fn assert_codes_shows_a_diff_of_the_codes_on_failure() {
+        let message = panic_message(|| {
+            let violations = vec![
+                invalid_value(INVALID_LENGTH_MAX, "name", 8, 5),
+                invalid_state("invalid-maintenance", vec![]),
+            ];
+            assert_codes!(violations, [INVALID_LENGTH_MAX, INVALID_NOT_EMPTY]);
+        });
+
+        assert_eq!(
+            message,
+            "assertion failed: `violations` has exactly the codes \
+             [\"invalid-length-max\", \"invalid-not-empty\"]\n\
+             diff (- missing, + unexpected):\n\
+             - invalid-not-empty\n\
+             + invalid-maintenance\n\
+             violations:\n  \
+             0: invalid-length-max of name which is 8, expected to be 5\n  \
+             1: invalid-maintenance for parameters: []\n"
+        );
+    }
+
+    #[test]
+    fn code_set_diff_of_equal_code_sets_is_empty() {
+        let violations = vec![invalid_value(INVALID_LENGTH_MAX, "name", 8, 5)];
+
+        let diff = CodeSetDiff::of(&violations, &[INVALID_LENGTH_MAX]);
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+}
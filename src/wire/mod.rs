@@ -0,0 +1,577 @@
+//! A flat and stable wire format for validation errors
+//!
+//! The `Serialize` and `Deserialize` implementations derived for
+//! [`ValidationError`] and [`ConstraintViolation`] follow the structure of the
+//! Rust types. Each enum is externally tagged by the name of its variant, like
+//! `{"Field": {...}}` or `{"Integer": 25}`. This structure changes whenever a
+//! variant is added or a field is moved.
+//!
+//! This module provides an alternative representation that is meant to be
+//! exchanged with other applications. It is selected by wrapping a value in
+//! [`Wire`] or by annotating a field with `#[serde(with = "valid::wire")]`.
+//!
+//! A validation error is represented as a document with the members
+//! `version`, `message` (omitted if there is no message), `violations` and
+//! `truncated`. The `version` is the [`VERSION`] of the wire format.
+//!
+//! Each constraint violation is a flat object with the members `kind`, `code`
+//! and `severity` and further members depending on the kind:
+//!
+//! | kind        | further members                                           |
+//! |-------------|-----------------------------------------------------------|
+//! | `field`     | `field`, `actual` and `expected`                          |
+//! | `relation`  | `fields`, a list of exactly 2 fields                      |
+//! | `field_set` | `fields`, a list of fields                                |
+//! | `state`     | `params`, an object with the parameters (omitted if empty) |
+//!
+//! An entry of `fields` has the members `field`, `actual` and `expected`.
+//! The members `actual` and `expected` are omitted if the value is not
+//! available. A missing `severity` is deserialized as `error`.
+//!
+//! Values are written as plain JSON strings, numbers and booleans without the
+//! name of the variant. When deserialized the variant of [`Value`] is inferred
+//! from the plain value:
+//!
+//! | plain value                      | variant   |
+//! |----------------------------------|-----------|
+//! | string                           | `String`  |
+//! | boolean                          | `Boolean` |
+//! | integer in the range of `i32`    | `Integer` |
+//! | other integer in range of `i64`  | `Long`    |
+//! | any other number                 | `Double`  |
+//!
+//! Thus values of the variants `Float`, `Decimal`, `Date`, `DateTime` and
+//! `BigInteger` and values of the variant `Long` that are in the range of
+//! `i32` are deserialized as another variant.
+//!
+//! # Versioning
+//!
+//! The version is incremented only for changes that are not compatible.
+//! Members that are added in a compatible way do not change the version and
+//! are ignored by readers that do not know them. A document of a version
+//! greater than [`VERSION`] is rejected.
+//!
+//! This module is only available with the optional crate feature `serde1`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde_json::json;
+//! use valid::wire::Wire;
+//! use valid::{invalid_relation, invalid_value, ValidationError};
+//!
+//! let error = ValidationError::new(
+//!     Some("invalid registration".into()),
+//!     vec![
+//!         invalid_value("invalid-bound-closed-min", "age", 12, 13),
+//!         invalid_relation(
+//!             "invalid-must-match",
+//!             "password",
+//!             "s3cr3t".to_string(),
+//!             "password2",
+//!             "s3crEt".to_string(),
+//!         ),
+//!     ],
+//! );
+//!
+//! let document = json!({
+//!     "version": 1,
+//!     "message": "invalid registration",
+//!     "violations": [
+//!         {
+//!             "kind": "field",
+//!             "code": "invalid-bound-closed-min",
+//!             "severity": "error",
+//!             "field": "age",
+//!             "actual": 12,
+//!             "expected": 13
+//!         },
+//!         {
+//!             "kind": "relation",
+//!             "code": "invalid-must-match",
+//!             "severity": "error",
+//!             "fields": [
+//!                 { "field": "password", "actual": "s3cr3t" },
+//!                 { "field": "password2", "actual": "s3crEt" }
+//!             ]
+//!         }
+//!     ],
+//!     "truncated": false
+//! });
+//!
+//! assert_eq!(serde_json::to_value(Wire(&error)).unwrap(), document);
+//!
+//! let Wire(deserialized) = serde_json::from_value::<Wire<ValidationError>>(document).unwrap();
+//! assert_eq!(deserialized, error);
+//! ```
+//!
+//! [`ValidationError`]: ../struct.ValidationError.html
+//! [`ConstraintViolation`]: ../enum.ConstraintViolation.html
+//! [`Value`]: ../enum.Value.html
+//! [`Wire`]: struct.Wire.html
+//! [`VERSION`]: constant.VERSION.html
+
+use crate::{
+    ConstraintViolation, Field, InvalidFieldSet, InvalidRelation, InvalidState, InvalidValue,
+    Parameter, Severity, ValidationError, Value,
+};
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The version of the wire format written by this crate
+pub const VERSION: u32 = 1;
+
+/// Wraps a validation error, a constraint violation or a value to serialize
+/// and deserialize it in the wire format.
+///
+/// `Serialize` is implemented for the wrapped types and for references to
+/// them. `Deserialize` is implemented for the wrapped types.
+///
+/// See the [module level documentation] for a description of the format.
+///
+/// [module level documentation]: index.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wire<T>(pub T);
+
+/// Serializes a value in the wire format.
+///
+/// This function and [`deserialize`] make this module usable with the
+/// attribute `#[serde(with = "valid::wire")]`.
+///
+/// [`deserialize`]: fn.deserialize.html
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    for<'a> Wire<&'a T>: Serialize,
+    S: Serializer,
+{
+    Wire(value).serialize(serializer)
+}
+
+/// Deserializes a value from the wire format.
+///
+/// See [`serialize`] for usage.
+///
+/// [`serialize`]: fn.serialize.html
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    Wire<T>: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Wire::<T>::deserialize(deserializer).map(|Wire(value)| value)
+}
+
+macro_rules! serialize_owned_by_ref {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl Serialize for Wire<$type> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    Wire(&self.0).serialize(serializer)
+                }
+            }
+        )+
+    };
+}
+
+serialize_owned_by_ref!(ValidationError, ConstraintViolation, Value);
+
+#[derive(Serialize)]
+struct ErrorRef<'a> {
+    version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+    #[serde(serialize_with = "serialize_violations")]
+    violations: &'a [ConstraintViolation],
+    truncated: bool,
+}
+
+#[derive(Deserialize)]
+struct ErrorData {
+    version: u32,
+    message: Option<Cow<'static, str>>,
+    violations: Vec<Wire<ConstraintViolation>>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+impl Serialize for Wire<&ValidationError> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ErrorRef {
+            version: VERSION,
+            message: self.0.message.as_deref(),
+            violations: &self.0.violations,
+            truncated: self.0.truncated,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wire<ValidationError> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = ErrorData::deserialize(deserializer)?;
+        if data.version == 0 || data.version > VERSION {
+            return Err(de::Error::custom(format_args!(
+                "unsupported wire format version {}, expected a version up to {}",
+                data.version, VERSION
+            )));
+        }
+        Ok(Wire(ValidationError {
+            message: data.message,
+            violations: data.violations.into_iter().map(|Wire(v)| v).collect(),
+            truncated: data.truncated,
+        }))
+    }
+}
+
+fn serialize_violations<S>(
+    violations: &[ConstraintViolation],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(violations.iter().map(Wire))
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ViolationRef<'a> {
+    Field {
+        code: &'a str,
+        severity: Severity,
+        field: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        actual: Option<PlainValue<'a>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expected: Option<PlainValue<'a>>,
+    },
+    Relation {
+        code: &'a str,
+        severity: Severity,
+        fields: [FieldRef<'a>; 2],
+    },
+    FieldSet {
+        code: &'a str,
+        severity: Severity,
+        fields: Vec<FieldRef<'a>>,
+    },
+    State {
+        code: &'a str,
+        severity: Severity,
+        #[serde(
+            skip_serializing_if = "<[_]>::is_empty",
+            serialize_with = "serialize_params"
+        )]
+        params: &'a [Parameter],
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ViolationData {
+    Field {
+        code: Cow<'static, str>,
+        #[serde(default)]
+        severity: Severity,
+        field: Cow<'static, str>,
+        actual: Option<Wire<Value>>,
+        expected: Option<Wire<Value>>,
+    },
+    Relation {
+        code: Cow<'static, str>,
+        #[serde(default)]
+        severity: Severity,
+        fields: Vec<FieldData>,
+    },
+    FieldSet {
+        code: Cow<'static, str>,
+        #[serde(default)]
+        severity: Severity,
+        fields: Vec<FieldData>,
+    },
+    State {
+        code: Cow<'static, str>,
+        #[serde(default)]
+        severity: Severity,
+        #[serde(default)]
+        params: Params,
+    },
+}
+
+impl Serialize for Wire<&ConstraintViolation> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            ConstraintViolation::Field(invalid_value) => ViolationRef::Field {
+                code: &invalid_value.code,
+                severity: invalid_value.severity,
+                field: &invalid_value.field.name,
+                actual: invalid_value.field.actual.as_ref().map(PlainValue),
+                expected: invalid_value.field.expected.as_ref().map(PlainValue),
+            },
+            ConstraintViolation::Relation(invalid_relation) => ViolationRef::Relation {
+                code: &invalid_relation.code,
+                severity: invalid_relation.severity,
+                fields: [
+                    FieldRef::from(&invalid_relation.field1),
+                    FieldRef::from(&invalid_relation.field2),
+                ],
+            },
+            ConstraintViolation::FieldSet(invalid_field_set) => ViolationRef::FieldSet {
+                code: &invalid_field_set.code,
+                severity: invalid_field_set.severity,
+                fields: invalid_field_set
+                    .fields
+                    .iter()
+                    .map(FieldRef::from)
+                    .collect(),
+            },
+            ConstraintViolation::State(invalid_state) => ViolationRef::State {
+                code: &invalid_state.code,
+                severity: invalid_state.severity,
+                params: &invalid_state.params,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wire<ConstraintViolation> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let violation = match ViolationData::deserialize(deserializer)? {
+            ViolationData::Field {
+                code,
+                severity,
+                field,
+                actual,
+                expected,
+            } => ConstraintViolation::Field(InvalidValue {
+                code,
+                severity,
+                field: Field {
+                    name: field,
+                    actual: actual.map(|Wire(value)| value),
+                    expected: expected.map(|Wire(value)| value),
+                },
+            }),
+            ViolationData::Relation {
+                code,
+                severity,
+                fields,
+            } => {
+                let [field1, field2] = <[FieldData; 2]>::try_from(fields).map_err(|fields| {
+                    de::Error::invalid_length(fields.len(), &"a relation of 2 fields")
+                })?;
+                ConstraintViolation::Relation(InvalidRelation {
+                    code,
+                    severity,
+                    field1: field1.into(),
+                    field2: field2.into(),
+                })
+            }
+            ViolationData::FieldSet {
+                code,
+                severity,
+                fields,
+            } => ConstraintViolation::FieldSet(InvalidFieldSet {
+                code,
+                severity,
+                fields: fields.into_iter().map(Field::from).collect(),
+            }),
+            ViolationData::State {
+                code,
+                severity,
+                params,
+            } => ConstraintViolation::State(InvalidState {
+                code,
+                severity,
+                params: params.0,
+            }),
+        };
+        Ok(Wire(violation))
+    }
+}
+
+#[derive(Serialize)]
+struct FieldRef<'a> {
+    field: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<PlainValue<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<PlainValue<'a>>,
+}
+
+impl<'a> From<&'a Field> for FieldRef<'a> {
+    fn from(field: &'a Field) -> Self {
+        FieldRef {
+            field: &field.name,
+            actual: field.actual.as_ref().map(PlainValue),
+            expected: field.expected.as_ref().map(PlainValue),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct FieldData {
+    field: Cow<'static, str>,
+    actual: Option<Wire<Value>>,
+    expected: Option<Wire<Value>>,
+}
+
+impl From<FieldData> for Field {
+    fn from(field: FieldData) -> Self {
+        Field {
+            name: field.field,
+            actual: field.actual.map(|Wire(value)| value),
+            expected: field.expected.map(|Wire(value)| value),
+        }
+    }
+}
+
+pub(crate) fn serialize_params<S>(params: &[Parameter], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(params.len()))?;
+    for param in params {
+        map.serialize_entry(&param.name, &PlainValue(&param.value))?;
+    }
+    map.end()
+}
+
+/// The parameters of a constraint violation in the order of the document
+#[derive(Default)]
+struct Params(Vec<Parameter>);
+
+impl<'de> Deserialize<'de> for Params {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ParamsVisitor;
+
+        impl<'de> Visitor<'de> for ParamsVisitor {
+            type Value = Params;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of parameters")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut params = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some((name, Wire(value))) = map.next_entry::<String, Wire<Value>>()? {
+                    params.push(Parameter::new(name, value));
+                }
+                Ok(Params(params))
+            }
+        }
+
+        deserializer.deserialize_map(ParamsVisitor)
+    }
+}
+
+impl Serialize for Wire<&Value> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PlainValue(self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wire<Value> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueVisitor;
+
+        impl Visitor<'_> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string, a number or a boolean")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(Value::Boolean(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(i32::try_from(value).map_or(Value::Long(value), Value::Integer))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(
+                    i64::try_from(value).map_or(Value::Double(value as f64), |value| {
+                        i32::try_from(value).map_or(Value::Long(value), Value::Integer)
+                    }),
+                )
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(Value::Double(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(Value::String(value.into()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+                Ok(Value::String(value))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor).map(Wire)
+    }
+}
+
+/// Serializes a `Value` as plain value without the name of the variant.
+pub(crate) struct PlainValue<'a>(pub(crate) &'a Value);
+
+impl Serialize for PlainValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Value::String(value) => serializer.serialize_str(value),
+            Value::Integer(value) => serializer.serialize_i32(*value),
+            Value::Long(value) => serializer.serialize_i64(*value),
+            Value::Float(value) => serializer.serialize_f32(*value),
+            Value::Double(value) => serializer.serialize_f64(*value),
+            Value::Boolean(value) => serializer.serialize_bool(*value),
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(value) => serializer.collect_str(value),
+            #[cfg(feature = "chrono")]
+            Value::Date(value) => value.serialize(serializer),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(value) => value.serialize(serializer),
+            #[cfg(feature = "num-bigint")]
+            Value::BigInteger(value) => serializer.collect_str(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,359 @@
+use super::*;
+use crate::{
+    invalid_field_set, invalid_optional_value, invalid_relation, invalid_state, invalid_value,
+    param,
+};
+use serde_json::json;
+
+fn to_json(violation: &ConstraintViolation) -> serde_json::Value {
+    serde_json::to_value(Wire(violation)).unwrap()
+}
+
+fn from_json(document: serde_json::Value) -> ConstraintViolation {
+    serde_json::from_value::<Wire<ConstraintViolation>>(document)
+        .unwrap()
+        .0
+}
+
+mod violation {
+    use super::*;
+
+    #[test]
+    fn invalid_value_is_serialized_as_flat_object() {
+        let violation = invalid_value("invalid-bound-closed-max", "age", 25, 20);
+
+        assert_eq!(
+            to_json(&violation),
+            json!({
+                "kind": "field",
+                "code": "invalid-bound-closed-max",
+                "severity": "error",
+                "field": "age",
+                "actual": 25,
+                "expected": 20
+            })
+        );
+    }
+
+    #[test]
+    fn missing_actual_and_expected_values_are_omitted() {
+        let violation = invalid_optional_value("invalid-not-empty", "name", None, None);
+
+        assert_eq!(
+            to_json(&violation),
+            json!({
+                "kind": "field",
+                "code": "invalid-not-empty",
+                "severity": "error",
+                "field": "name"
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_relation_is_serialized_with_a_list_of_2_fields() {
+        let violation =
+            invalid_relation("invalid-must-define-range-inclusive", "from", 4, "until", 2)
+                .with_severity(Severity::Warning);
+
+        assert_eq!(
+            to_json(&violation),
+            json!({
+                "kind": "relation",
+                "code": "invalid-must-define-range-inclusive",
+                "severity": "warning",
+                "fields": [
+                    { "field": "from", "actual": 4 },
+                    { "field": "until", "actual": 2 }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_field_set_is_serialized_with_a_list_of_fields() {
+        let violation = invalid_field_set(
+            "invalid-at-most-one-of",
+            vec![
+                (
+                    "email".into(),
+                    Some(Value::String("jane@example.com".into())),
+                ),
+                ("phone".into(), None),
+            ],
+        );
+
+        assert_eq!(
+            to_json(&violation),
+            json!({
+                "kind": "field_set",
+                "code": "invalid-at-most-one-of",
+                "severity": "error",
+                "fields": [
+                    { "field": "email", "actual": "jane@example.com" },
+                    { "field": "phone" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_state_is_serialized_with_params_as_object() {
+        let violation = invalid_state(
+            "invalid-unique-username",
+            vec![
+                param("username", "jane.doe".to_string()),
+                param("attempts", 3),
+            ],
+        );
+
+        assert_eq!(
+            to_json(&violation),
+            json!({
+                "kind": "state",
+                "code": "invalid-unique-username",
+                "severity": "error",
+                "params": { "username": "jane.doe", "attempts": 3 }
+            })
+        );
+    }
+
+    #[test]
+    fn empty_params_are_omitted() {
+        let violation = invalid_state("invalid-maintenance", vec![]);
+
+        assert_eq!(
+            to_json(&violation),
+            json!({ "kind": "state", "code": "invalid-maintenance", "severity": "error" })
+        );
+    }
+
+    #[test]
+    fn each_kind_of_violation_round_trips() {
+        let violations = vec![
+            invalid_value("invalid-length-max", "name", 8, 5),
+            invalid_optional_value("invalid-not-empty", "email", None, None)
+                .with_severity(Severity::Info),
+            invalid_relation("invalid-must-match", "password", true, "password2", false),
+            invalid_field_set("invalid-all-or-none", vec![]),
+            invalid_state(
+                "invalid-unique-username",
+                vec![
+                    param("username", "jane.doe".to_string()),
+                    param("attempts", 3),
+                    param("blocked", false),
+                ],
+            ),
+        ];
+
+        for violation in violations {
+            let json = serde_json::to_string(&Wire(&violation)).unwrap();
+            let deserialized: Wire<ConstraintViolation> = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(deserialized.0, violation);
+        }
+    }
+
+    #[test]
+    fn missing_severity_is_deserialized_as_error() {
+        let violation = from_json(json!({
+            "kind": "field",
+            "code": "invalid-length-max",
+            "field": "name",
+            "actual": 8,
+            "expected": 5
+        }));
+
+        assert_eq!(violation.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn unknown_members_are_ignored() {
+        let violation = from_json(json!({
+            "kind": "state",
+            "code": "invalid-maintenance",
+            "severity": "info",
+            "hint": "try again later"
+        }));
+
+        assert_eq!(
+            violation,
+            invalid_state("invalid-maintenance", vec![]).with_severity(Severity::Info)
+        );
+    }
+
+    #[test]
+    fn relation_with_other_than_2_fields_is_rejected() {
+        let result = serde_json::from_value::<Wire<ConstraintViolation>>(json!({
+            "kind": "relation",
+            "code": "invalid-must-match",
+            "fields": [{ "field": "password" }]
+        }));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid length 1, expected a relation of 2 fields"
+        );
+    }
+
+    #[test]
+    fn unknown_kind_is_rejected() {
+        let result = serde_json::from_value::<Wire<ConstraintViolation>>(json!({
+            "kind": "document",
+            "code": "invalid-schema"
+        }));
+
+        assert!(result.is_err());
+    }
+}
+
+mod value {
+    use super::*;
+
+    fn value_of(json: serde_json::Value) -> Value {
+        serde_json::from_value::<Wire<Value>>(json).unwrap().0
+    }
+
+    #[test]
+    fn values_are_serialized_without_the_name_of_the_variant() {
+        assert_eq!(
+            serde_json::to_value(Wire(Value::Long(-7))).unwrap(),
+            json!(-7)
+        );
+        assert_eq!(
+            serde_json::to_value(Wire(&Value::String("jane".into()))).unwrap(),
+            json!("jane")
+        );
+    }
+
+    #[test]
+    fn variant_is_inferred_from_the_plain_value() {
+        assert_eq!(value_of(json!("jane")), Value::String("jane".into()));
+        assert_eq!(value_of(json!(true)), Value::Boolean(true));
+        assert_eq!(value_of(json!(25)), Value::Integer(25));
+        assert_eq!(value_of(json!(-25)), Value::Integer(-25));
+        assert_eq!(
+            value_of(json!(3_000_000_000u64)),
+            Value::Long(3_000_000_000)
+        );
+        assert_eq!(value_of(json!(i64::MIN)), Value::Long(i64::MIN));
+        assert_eq!(value_of(json!(u64::MAX)), Value::Double(u64::MAX as f64));
+        assert_eq!(value_of(json!(0.5)), Value::Double(0.5));
+    }
+
+    #[test]
+    fn null_and_structured_values_are_rejected() {
+        assert!(serde_json::from_value::<Wire<Value>>(json!(null)).is_err());
+        assert!(serde_json::from_value::<Wire<Value>>(json!([1])).is_err());
+        assert!(serde_json::from_value::<Wire<Value>>(json!({ "Integer": 1 })).is_err());
+    }
+}
+
+mod validation_error {
+    use super::*;
+
+    #[test]
+    fn validation_error_is_serialized_with_the_version() {
+        let error = ValidationError {
+            message: None,
+            violations: vec![invalid_value("invalid-length-max", "name", 8, 5)],
+            truncated: true,
+        };
+
+        assert_eq!(
+            serde_json::to_value(Wire(error)).unwrap(),
+            json!({
+                "version": 1,
+                "violations": [
+                    {
+                        "kind": "field",
+                        "code": "invalid-length-max",
+                        "severity": "error",
+                        "field": "name",
+                        "actual": 8,
+                        "expected": 5
+                    }
+                ],
+                "truncated": true
+            })
+        );
+    }
+
+    #[test]
+    fn validation_error_round_trips() {
+        let error = ValidationError {
+            message: Some("invalid registration".into()),
+            violations: vec![
+                invalid_value("invalid-length-max", "name", 8, 5),
+                invalid_state("invalid-maintenance", vec![]).with_severity(Severity::Warning),
+            ],
+            truncated: false,
+        };
+
+        let json = serde_json::to_string(&Wire(&error)).unwrap();
+        let deserialized: Wire<ValidationError> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.0, error);
+    }
+
+    #[test]
+    fn missing_truncated_is_deserialized_as_false() {
+        let error: Wire<ValidationError> =
+            serde_json::from_value(json!({ "version": 1, "violations": [] })).unwrap();
+
+        assert!(!error.0.truncated);
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let result = serde_json::from_value::<Wire<ValidationError>>(json!({
+            "version": 2,
+            "violations": []
+        }));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unsupported wire format version 2, expected a version up to 1"
+        );
+    }
+
+    #[test]
+    fn missing_version_is_rejected() {
+        let result = serde_json::from_value::<Wire<ValidationError>>(json!({ "violations": [] }));
+
+        assert_eq!(result.unwrap_err().to_string(), "missing field `version`");
+    }
+
+    #[test]
+    fn fields_can_be_annotated_to_use_the_wire_format() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Response {
+            #[serde(with = "crate::wire")]
+            error: ValidationError,
+        }
+
+        let response = Response {
+            error: ValidationError {
+                message: None,
+                violations: vec![invalid_state("invalid-maintenance", vec![])],
+                truncated: false,
+            },
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "error": {
+                    "version": 1,
+                    "violations": [
+                        { "kind": "state", "code": "invalid-maintenance", "severity": "error" }
+                    ],
+                    "truncated": false
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<Response>(json).unwrap(), response);
+    }
+}